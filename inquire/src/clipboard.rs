@@ -0,0 +1,96 @@
+/// Copies the text to the system clipboard, returning whether it was copied.
+///
+/// With the `clipboard` feature, the text is handed over to the clipboard
+/// utility of the platform: `pbcopy` on macOS, `clip` on Windows, and
+/// `wl-copy`, `xclip` or `xsel` on other systems. Without the feature, or
+/// when none of these utilities is available, nothing is copied.
+#[cfg(not(test))]
+pub fn copy(text: &str) -> bool {
+    #[cfg(feature = "clipboard")]
+    return system::copy(text);
+
+    #[cfg(not(feature = "clipboard"))]
+    {
+        let _ = text;
+        false
+    }
+}
+
+/// In the tests, the text is copied to [test::CLIPBOARD] instead of the
+/// system clipboard.
+#[cfg(test)]
+pub fn copy(text: &str) -> bool {
+    test::CLIPBOARD.with(|clipboard| match clipboard.borrow_mut().as_mut() {
+        Some(copied) => {
+            copied.push(text.to_owned());
+            true
+        }
+        None => false,
+    })
+}
+
+#[cfg(all(feature = "clipboard", not(test)))]
+mod system {
+    use std::{
+        io::{Result, Write},
+        process::{Command, Stdio},
+    };
+
+    /// Clipboard utilities of the platform and their arguments, in the order
+    /// they are tried.
+    fn utilities() -> Vec<(&'static str, &'static [&'static str])> {
+        if cfg!(target_os = "macos") {
+            return vec![("pbcopy", &[])];
+        }
+        if cfg!(windows) {
+            return vec![("clip", &[])];
+        }
+
+        let mut utilities: Vec<(&'static str, &'static [&'static str])> = vec![
+            ("xclip", &["-selection", "clipboard"]),
+            ("xsel", &["--clipboard", "--input"]),
+        ];
+        if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+            utilities.insert(0, ("wl-copy", &[]));
+        }
+
+        utilities
+    }
+
+    pub fn copy(text: &str) -> bool {
+        utilities()
+            .into_iter()
+            .any(|(program, args)| copy_with(program, args, text).unwrap_or(false))
+    }
+
+    /// Writes the text to the standard input of the utility, returning
+    /// whether it exited successfully.
+    fn copy_with(program: &str, args: &[&str], text: &str) -> Result<bool> {
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()?;
+
+        // the utility is waited for even when the text could not be written,
+        // its input being closed once dropped
+        let written = match child.stdin.take() {
+            Some(mut stdin) => stdin.write_all(text.as_bytes()),
+            None => Ok(()),
+        };
+
+        Ok(child.wait()?.success() && written.is_ok())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use std::cell::RefCell;
+
+    thread_local! {
+        /// Texts copied by the tests running on the current thread, or `None`
+        /// to simulate a system without any clipboard.
+        pub static CLIPBOARD: RefCell<Option<Vec<String>>> = const { RefCell::new(Some(vec![])) };
+    }
+}
@@ -1,10 +1,13 @@
 //! Global config definitions.
 
-use std::sync::Mutex;
+use std::{env, sync::Mutex};
 
-use crate::ui::RenderConfig;
+use crate::{formatter::GlobalOptionFormatter, type_aliases::GlobalScorer, ui::RenderConfig};
 use std::sync::LazyLock;
 
+/// Environment variable enabling or disabling vim mode by default.
+const VIM_MODE_ENV_VAR: &str = "INQUIRE_VIM_MODE";
+
 static GLOBAL_RENDER_CONFIGURATION: LazyLock<Mutex<RenderConfig<'static>>> =
     LazyLock::new(|| Mutex::new(RenderConfig::default()));
 
@@ -19,8 +22,116 @@ pub fn set_global_render_config(config: RenderConfig<'static>) {
     *guard = config;
 }
 
+static GLOBAL_VIM_MODE: LazyLock<Mutex<bool>> =
+    LazyLock::new(|| Mutex::new(parse_vim_mode(env::var(VIM_MODE_ENV_VAR).ok().as_deref())));
+
+pub fn get_vim_mode() -> bool {
+    *GLOBAL_VIM_MODE.lock().unwrap()
+}
+
+/// Acquires a write lock to the global vim mode flag and updates it with
+/// the provided argument.
+///
+/// The flag is used as the initial vim mode of prompts that support it,
+/// such as [Select](crate::Select) and [MultiSelect](crate::MultiSelect),
+/// when they are created. Calling `with_vim_mode` on a prompt still
+/// overrides it.
+///
+/// If not set, the flag is read from the `INQUIRE_VIM_MODE` environment
+/// variable, where `1`, `true`, `yes` and `on` enable vim mode.
+pub fn set_global_vim_mode(vim_mode: bool) {
+    let mut guard = GLOBAL_VIM_MODE.lock().unwrap();
+    *guard = vim_mode;
+}
+
+static GLOBAL_SCORER: Mutex<Option<GlobalScorer>> = Mutex::new(None);
+
+pub fn get_global_scorer() -> Option<GlobalScorer> {
+    *GLOBAL_SCORER.lock().unwrap()
+}
+
+/// Acquires a write lock to the global scorer and updates it with the
+/// provided argument.
+///
+/// The scorer is used as the default scorer of [Select](crate::Select)
+/// prompts created afterwards, in place of
+/// [DEFAULT_SCORER](crate::Select::DEFAULT_SCORER). It receives the filter
+/// input, the string value of the option and its index. Calling
+/// `with_scorer` on a prompt still overrides it.
+///
+/// ```no_run
+/// use inquire::{set_global_scorer, Select};
+///
+/// set_global_scorer(|input, value, _idx| {
+///     value.starts_with(input).then_some(0)
+/// });
+///
+/// let env = Select::new("Environment:", vec!["staging", "production"]).prompt()?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+pub fn set_global_scorer(scorer: GlobalScorer) {
+    let mut guard = GLOBAL_SCORER.lock().unwrap();
+    *guard = Some(scorer);
+}
+
+static GLOBAL_FORMATTER: Mutex<Option<GlobalOptionFormatter>> = Mutex::new(None);
+
+pub fn get_global_formatter() -> Option<GlobalOptionFormatter> {
+    *GLOBAL_FORMATTER.lock().unwrap()
+}
+
+/// Acquires a write lock to the global formatter and updates it with the
+/// provided argument.
+///
+/// The formatter is used as the default formatter of
+/// [Select](crate::Select) prompts created afterwards, in place of
+/// [DEFAULT_FORMATTER](crate::Select::DEFAULT_FORMATTER). It receives the
+/// string value of the submitted option. Calling `with_formatter` on a
+/// prompt still overrides it.
+///
+/// ```
+/// use inquire::{list_option::ListOption, set_global_formatter, Select};
+///
+/// set_global_formatter(|ans| ans.value.to_uppercase());
+///
+/// let select = Select::new("Environment:", vec!["staging", "production"]);
+/// assert_eq!("STAGING", (select.formatter)(ListOption::new(0, &"staging")));
+///
+/// let select = select.with_formatter(&|ans| ans.value.to_string());
+/// assert_eq!("staging", (select.formatter)(ListOption::new(0, &"staging")));
+/// ```
+pub fn set_global_formatter(formatter: GlobalOptionFormatter) {
+    let mut guard = GLOBAL_FORMATTER.lock().unwrap();
+    *guard = Some(formatter);
+}
+
+fn parse_vim_mode(value: Option<&str>) -> bool {
+    match value.map(str::trim) {
+        Some(value) => ["1", "true", "yes", "on"]
+            .iter()
+            .any(|truthy| value.eq_ignore_ascii_case(truthy)),
+        None => DEFAULT_VIM_MODE,
+    }
+}
+
 /// Default page size when displaying options to the user.
 pub const DEFAULT_PAGE_SIZE: usize = 7;
 
-/// Default value of vim mode.
+/// Default value of vim mode, used when neither the global vim mode flag nor
+/// the `INQUIRE_VIM_MODE` environment variable are set.
 pub const DEFAULT_VIM_MODE: bool = false;
+
+#[cfg(test)]
+mod test {
+    use super::parse_vim_mode;
+
+    #[test]
+    fn vim_mode_env_var_is_parsed() {
+        assert!(parse_vim_mode(Some("1")));
+        assert!(parse_vim_mode(Some("TRUE")));
+        assert!(parse_vim_mode(Some(" yes ")));
+        assert!(!parse_vim_mode(Some("0")));
+        assert!(!parse_vim_mode(Some("off")));
+        assert!(!parse_vim_mode(None));
+    }
+}
@@ -8,7 +8,11 @@ use std::{error::Error, io};
 pub type CustomUserError = Box<dyn Error + Send + Sync + 'static>;
 
 /// Possible errors returned by `inquire` prompts.
+///
+/// New variants may be added in minor releases, so matches on this type
+/// must include a wildcard arm.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum InquireError {
     /// The input device is not a TTY, which means that enabling raw mode
     /// on the terminal in order to listen to input events is not possible.
@@ -32,10 +36,106 @@ pub enum InquireError {
     /// pressing Ctrl+C will trigger SIGINT.
     OperationInterrupted,
 
+    /// The user asked to go back to the previous prompt, such as the parent
+    /// menu when navigating hierarchical [Select](crate::Select) prompts.
+    NavigateBack,
+
+    /// The user submitted a [Select](crate::Select) prompt whose filter
+    /// input matched none of the options, when configured to fail with
+    /// [Select::with_error_on_empty_submit](crate::Select::with_error_on_empty_submit).
+    NoMatchingOption,
+
     /// Error while executing IO operations.
     Custom(CustomUserError),
 }
 
+/// Category of an [InquireError], without any of the values it carries.
+///
+/// Unlike [InquireError], this type can be cloned and compared, which is
+/// useful when asserting which error was returned or deciding whether an
+/// operation should be retried.
+///
+/// New kinds may be added along with new variants of [InquireError], so
+/// matches on this type must include a wildcard arm.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InquireErrorKind {
+    /// See [InquireError::NotTTY].
+    NotTTY,
+
+    /// See [InquireError::InvalidConfiguration].
+    InvalidConfiguration,
+
+    /// See [InquireError::IO]. Contains the kind of the underlying IO error.
+    IO(io::ErrorKind),
+
+    /// See [InquireError::OperationCanceled].
+    OperationCanceled,
+
+    /// See [InquireError::OperationInterrupted].
+    OperationInterrupted,
+
+    /// See [InquireError::NavigateBack].
+    NavigateBack,
+
+    /// See [InquireError::NoMatchingOption].
+    NoMatchingOption,
+
+    /// See [InquireError::Custom].
+    Custom,
+}
+
+impl InquireError {
+    /// Returns the category of this error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::error::{InquireError, InquireErrorKind};
+    ///
+    /// let err = InquireError::InvalidConfiguration("empty options".into());
+    /// assert_eq!(InquireErrorKind::InvalidConfiguration, err.kind());
+    ///
+    /// let err = InquireError::from(std::io::Error::from(std::io::ErrorKind::BrokenPipe));
+    /// assert_eq!(InquireErrorKind::IO(std::io::ErrorKind::BrokenPipe), err.kind());
+    /// ```
+    pub fn kind(&self) -> InquireErrorKind {
+        match self {
+            InquireError::NotTTY => InquireErrorKind::NotTTY,
+            InquireError::InvalidConfiguration(_) => InquireErrorKind::InvalidConfiguration,
+            InquireError::IO(err) => InquireErrorKind::IO(err.kind()),
+            InquireError::OperationCanceled => InquireErrorKind::OperationCanceled,
+            InquireError::OperationInterrupted => InquireErrorKind::OperationInterrupted,
+            InquireError::NavigateBack => InquireErrorKind::NavigateBack,
+            InquireError::NoMatchingOption => InquireErrorKind::NoMatchingOption,
+            InquireError::Custom(_) => InquireErrorKind::Custom,
+        }
+    }
+
+    /// Whether the operation that returned this error might succeed if
+    /// attempted again.
+    ///
+    /// Errors raised by user-provided callbacks, such as validators and
+    /// suggesters, submissions matching no option and IO errors of kind
+    /// `Interrupted`, `WouldBlock` or `TimedOut` are considered retryable.
+    /// Cancellations, interruptions, navigation back, invalid configurations,
+    /// non-TTY inputs and other IO errors are not.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            InquireError::Custom(_) | InquireError::NoMatchingOption => true,
+            InquireError::IO(err) => matches!(
+                err.kind(),
+                io::ErrorKind::Interrupted | io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+            ),
+            InquireError::NotTTY
+            | InquireError::InvalidConfiguration(_)
+            | InquireError::OperationCanceled
+            | InquireError::OperationInterrupted
+            | InquireError::NavigateBack => false,
+        }
+    }
+}
+
 impl Error for InquireError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
@@ -84,6 +184,10 @@ impl fmt::Display for InquireError {
             InquireError::OperationInterrupted => {
                 f.write_str("Operation was interrupted by the user")
             }
+            InquireError::NavigateBack => f.write_str("User navigated back to the previous prompt"),
+            InquireError::NoMatchingOption => {
+                f.write_str("The filter input submitted by the user matched no option")
+            }
             InquireError::Custom(err) => write!(f, "User-provided error: {}", err),
         }
     }
@@ -101,6 +101,12 @@ pub type BoolFormatter<'a> = &'a dyn Fn(bool) -> String;
 /// ```
 pub type OptionFormatter<'a, T> = &'a dyn Fn(ListOption<&T>) -> String;
 
+/// Type alias for the formatter registered with
+/// [set_global_formatter](crate::set_global_formatter), receiving the string
+/// value of the submitted option so that it applies to prompts of any option
+/// type.
+pub type GlobalOptionFormatter = fn(ListOption<&str>) -> String;
+
 /// Type alias for formatters used in [`MultiSelect`](crate::MultiSelect) prompts.
 ///
 /// Formatters receive the user input and return a [String] to be displayed
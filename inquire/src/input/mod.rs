@@ -29,6 +29,7 @@ pub struct Input {
     placeholder: Option<String>,
     cursor: usize,
     length: usize,
+    max_length: Option<usize>,
 }
 
 impl Input {
@@ -38,6 +39,7 @@ impl Input {
             placeholder: None,
             cursor: 0,
             length: 0,
+            max_length: None,
         }
     }
 
@@ -54,6 +56,7 @@ impl Input {
             placeholder: None,
             length: len,
             cursor: len,
+            max_length: None,
         }
     }
 
@@ -62,6 +65,13 @@ impl Input {
         self
     }
 
+    /// Limits the number of graphemes of the content, rejecting the inserts
+    /// beyond the limit. Content already longer than the limit is kept.
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
     pub fn with_cursor(mut self, cursor: usize) -> Self {
         assert!(
             cursor <= self.length,
@@ -204,6 +214,13 @@ impl Input {
     }
 
     fn insert(&mut self, c: char) -> InputActionResult {
+        if self
+            .max_length
+            .is_some_and(|max_length| self.length >= max_length)
+        {
+            return InputActionResult::Clean;
+        }
+
         let at = self.cursor;
 
         if at >= self.length {
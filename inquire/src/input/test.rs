@@ -420,6 +420,21 @@ fn delete_action_tests() {
     assert_eq!(0, input.cursor());
 }
 
+#[test]
+fn delete_on_empty_input_is_a_no_op() {
+    for magnitude in [Magnitude::Char, Magnitude::Word, Magnitude::Line] {
+        for direction in [LineDirection::Left, LineDirection::Right] {
+            let mut input = Input::new();
+
+            let result = input.handle(InputAction::Delete(magnitude, direction));
+
+            assert_eq!(InputActionResult::Clean, result);
+            assert_eq!("", input.content());
+            assert_eq!(0, input.cursor());
+        }
+    }
+}
+
 #[test]
 fn generic_user_scenario() {
     let mut input = Input::new_with("great idea! you are a genius")
@@ -475,3 +490,22 @@ fn generic_user_scenario() {
     assert_eq!("great idea! you", input.pre_cursor());
     assert_eq!(15, input.cursor());
 }
+
+#[test]
+fn inserts_beyond_max_length_are_rejected() {
+    let mut input = Input::new_with("ab").with_max_length(3);
+
+    assert_eq!(
+        InputActionResult::ContentChanged,
+        input.handle(InputAction::Write('c'))
+    );
+    assert_eq!(
+        InputActionResult::Clean,
+        input.handle(InputAction::Write('d'))
+    );
+    assert_eq!("abc", input.content());
+
+    input.handle(InputAction::Delete(Magnitude::Char, LineDirection::Left));
+    input.handle(InputAction::Write('e'));
+    assert_eq!("abe", input.content());
+}
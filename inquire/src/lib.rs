@@ -66,8 +66,14 @@
 #![deny(unused_crate_dependencies)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![allow(clippy::bool_to_int_with_if)]
+
+// only used by the tests of the serde feature
+#[cfg(all(test, not(feature = "serde")))]
+use serde_json as _;
+
 mod ansi;
 pub mod autocompletion;
+mod clipboard;
 mod config;
 #[cfg(feature = "date")]
 mod date_utils;
@@ -84,7 +90,10 @@ mod utils;
 pub mod validator;
 
 pub use crate::autocompletion::Autocomplete;
-pub use crate::config::set_global_render_config;
+pub use crate::config::{
+    set_global_formatter, set_global_render_config, set_global_scorer, set_global_vim_mode,
+};
 pub use crate::error::{CustomUserError, InquireError};
 pub use crate::input::action::*;
 pub use crate::prompts::*;
+pub use crate::utils::Page;
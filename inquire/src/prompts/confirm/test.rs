@@ -234,7 +234,10 @@ fn default_error_message_is_rendered_on_invalid_input() -> InquireResult<()> {
     }
 
     assert!(
-        rendered_frames[2].has_token(&Token::ErrorMessage(Confirm::DEFAULT_ERROR_MESSAGE.into())),
+        rendered_frames
+            .get(2)
+            .unwrap()
+            .has_token(&Token::ErrorMessage(Confirm::DEFAULT_ERROR_MESSAGE.into())),
         "Third frame did not contain an error message token when one was expected",
     );
 
@@ -279,7 +282,10 @@ fn custom_error_message_is_rendered_on_invalid_input() -> InquireResult<()> {
     }
 
     assert!(
-        rendered_frames[2].has_token(&Token::ErrorMessage("INCORRECT!!!!".into())),
+        rendered_frames
+            .get(2)
+            .unwrap()
+            .has_token(&Token::ErrorMessage("INCORRECT!!!!".into())),
         "Third frame did not contain an error message token when one was expected",
     );
 
@@ -548,3 +554,29 @@ fn ctrl_c_interrupts_prompt() -> InquireResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn buffered_keys_are_handled_before_rendering() -> InquireResult<()> {
+    let keys = vec![
+        Key::Char('y', KeyModifiers::NONE),
+        Key::Char('e', KeyModifiers::NONE),
+        Key::Char('s', KeyModifiers::NONE),
+        Key::Enter,
+    ];
+
+    let mut backend = FakeBackend::new(keys.clone());
+    let answer = Confirm::new("Question").prompt_with_backend(&mut backend)?;
+    assert!(answer);
+    assert_eq!(5, backend.frames().len());
+
+    let mut backend = FakeBackend::new(keys).with_buffered_input();
+    let answer = Confirm::new("Question").prompt_with_backend(&mut backend)?;
+    assert!(answer);
+    assert_eq!(
+        1,
+        backend.frames().len(),
+        "Only the final frame should have been rendered"
+    );
+
+    Ok(())
+}
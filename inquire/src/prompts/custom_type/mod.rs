@@ -1,6 +1,8 @@
 mod action;
 mod config;
 mod prompt;
+#[cfg(test)]
+mod test;
 
 pub use action::*;
 
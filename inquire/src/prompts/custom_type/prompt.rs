@@ -103,6 +103,10 @@ where
         (self.formatter)((*answer).clone())
     }
 
+    fn has_validators(&self) -> bool {
+        !self.validators.is_empty()
+    }
+
     fn submit(&mut self) -> InquireResult<Option<T>> {
         let answer = match self.get_final_answer() {
             Ok(answer) => match self.validate_current_answer(&answer)? {
@@ -0,0 +1,99 @@
+use std::{cell::Cell, rc::Rc};
+
+use crate::{
+    error::InquireResult,
+    input::Input,
+    ui::{
+        test::{FakeBackend, Token},
+        Key, KeyModifiers,
+    },
+    validator::{ErrorMessage, Validation},
+    CustomType, InquireError,
+};
+
+#[test]
+fn validating_indicator_is_rendered_while_validators_run() -> InquireResult<()> {
+    let keys = vec![Key::Char('1', KeyModifiers::NONE), Key::Enter];
+    let mut backend = FakeBackend::new(keys.clone()).with_validating_indicator();
+
+    CustomType::<u32>::new("Question")
+        .with_validator(|_: &u32| Ok(Validation::Valid))
+        .prompt_with_backend(&mut backend)?;
+
+    assert!(backend
+        .frames()
+        .iter()
+        .any(|frame| frame.has_token(&Token::ValidatingIndicator)));
+
+    let mut backend = FakeBackend::new(keys);
+
+    CustomType::<u32>::new("Question")
+        .with_validator(|_: &u32| Ok(Validation::Valid))
+        .prompt_with_backend(&mut backend)?;
+
+    assert!(!backend
+        .frames()
+        .iter()
+        .any(|frame| frame.has_token(&Token::ValidatingIndicator)));
+
+    Ok(())
+}
+
+#[test]
+fn submits_during_validation_are_discarded() {
+    // With buffered input, every key after the first submit is pressed
+    // while the validator runs.
+    let keys = vec![
+        Key::Char('1', KeyModifiers::NONE),
+        Key::Enter,
+        Key::Enter,
+        Key::Char('2', KeyModifiers::NONE),
+        Key::Enter,
+    ];
+    let mut backend = FakeBackend::new(keys).with_buffered_input();
+
+    let validations = Rc::new(Cell::new(0));
+    let counter = Rc::clone(&validations);
+
+    let answer = CustomType::<u32>::new("Question")
+        .with_validator(move |value: &u32| {
+            counter.set(counter.get() + 1);
+
+            match *value >= 10 {
+                true => Ok(Validation::Valid),
+                false => Ok(Validation::Invalid(ErrorMessage::Default)),
+            }
+        })
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(answer, Err(InquireError::IO(_))));
+    assert_eq!(1, validations.get());
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::Input(Input::new_with("12"))));
+}
+
+#[test]
+fn keys_typed_after_a_valid_submit_are_left_for_the_next_prompt() -> InquireResult<()> {
+    let keys = vec![
+        Key::Char('1', KeyModifiers::NONE),
+        Key::Enter,
+        Key::Char('2', KeyModifiers::NONE),
+        Key::Enter,
+    ];
+    let mut backend = FakeBackend::new(keys).with_buffered_input();
+
+    let answer = CustomType::<u32>::new("Question")
+        .with_validator(|_: &u32| Ok(Validation::Valid))
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(1, answer);
+    assert_eq!(
+        vec![Key::Char('2', KeyModifiers::NONE), Key::Enter],
+        Vec::from(backend.input)
+    );
+
+    Ok(())
+}
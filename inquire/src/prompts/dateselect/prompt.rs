@@ -131,6 +131,10 @@ where
         &self.config
     }
 
+    fn has_validators(&self) -> bool {
+        !self.validators.is_empty()
+    }
+
     fn submit(&mut self) -> InquireResult<Option<NaiveDate>> {
         let answer = match self.validate_current_answer()? {
             Validation::Valid => Some(self.cur_answer()),
@@ -116,6 +116,10 @@ where
         (self.formatter)(answer)
     }
 
+    fn has_validators(&self) -> bool {
+        !self.validators.is_empty()
+    }
+
     fn submit(&mut self) -> InquireResult<Option<String>> {
         let answer = match self.validate_current_answer()? {
             Validation::Valid => Some(self.cur_answer()?),
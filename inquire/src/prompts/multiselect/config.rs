@@ -11,6 +11,8 @@ pub struct MultiSelectConfig {
     pub keep_filter: bool,
     /// Whether to reset the cursor to the first option on filter input change.
     pub reset_cursor: bool,
+    /// Whether the selected options are returned in the order they were selected.
+    pub keep_toggle_order: bool,
 }
 
 impl<T> From<&MultiSelect<'_, T>> for MultiSelectConfig {
@@ -20,6 +22,7 @@ impl<T> From<&MultiSelect<'_, T>> for MultiSelectConfig {
             page_size: value.page_size,
             keep_filter: value.keep_filter,
             reset_cursor: value.reset_cursor,
+            keep_toggle_order: value.keep_toggle_order,
         }
     }
 }
@@ -55,6 +55,7 @@ static DEFAULT_MATCHER: LazyLock<SkimMatcherV2> =
 /// - **Display option indexes**: On long lists, it might be helpful to display the indexes of the options to the user. Via the `RenderConfig`, you can set the display mode of the indexes as a prefix of an option. The default configuration is `None`, to not render any index when displaying the options.
 /// - **Scorer function**: Function that defines the order of options and if displayed as all.
 /// - **Keep filter flag**: Whether the current filter input should be cleared or not after a selection is made. Defaults to true.
+/// - **Keep toggle order flag**: Whether the selected options are returned in the order they were selected instead of their order in the option list. Defaults to false.
 ///
 /// # Example
 ///
@@ -80,6 +81,8 @@ pub struct MultiSelect<'a, T> {
 
     /// Whether vim mode is enabled. When enabled, the user can
     /// navigate through the options using hjkl.
+    ///
+    /// Defaults to the global vim mode flag, see [set_global_vim_mode](crate::set_global_vim_mode).
     pub vim_mode: bool,
 
     /// Starting cursor index of the selection.
@@ -105,6 +108,12 @@ pub struct MultiSelect<'a, T> {
     /// Whether the current filter typed by the user is kept or cleaned after a selection is made.
     pub keep_filter: bool,
 
+    /// Whether the selected options are returned in the order they were
+    /// selected, instead of their order in the option list.
+    ///
+    /// Defaults to false.
+    pub keep_toggle_order: bool,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: MultiOptionFormatter<'a, T>,
 
@@ -214,6 +223,10 @@ where
     /// Default behavior of keeping or cleaning the current filter value.
     pub const DEFAULT_KEEP_FILTER: bool = true;
 
+    /// Default behavior of returning the selected options in the order they
+    /// were selected.
+    pub const DEFAULT_KEEP_TOGGLE_ORDER: bool = false;
+
     /// Default help message.
     pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
         Some("↑↓ to move, space to select one, → to all, ← to none, type to filter");
@@ -226,12 +239,13 @@ where
             default: None,
             help_message: Self::DEFAULT_HELP_MESSAGE,
             page_size: Self::DEFAULT_PAGE_SIZE,
-            vim_mode: Self::DEFAULT_VIM_MODE,
+            vim_mode: crate::config::get_vim_mode(),
             starting_cursor: Self::DEFAULT_STARTING_CURSOR,
             starting_filter_input: None,
             reset_cursor: Self::DEFAULT_RESET_CURSOR,
             filter_input_enabled: Self::DEFAULT_FILTER_INPUT_ENABLED,
             keep_filter: Self::DEFAULT_KEEP_FILTER,
+            keep_toggle_order: Self::DEFAULT_KEEP_TOGGLE_ORDER,
             scorer: Self::DEFAULT_SCORER,
             formatter: Self::DEFAULT_FORMATTER,
             validator: None,
@@ -269,6 +283,17 @@ where
         self
     }
 
+    /// Sets whether the selected options are returned, and passed to the
+    /// validator, in the order they were selected.
+    ///
+    /// By default, they are sorted by their index in the option list,
+    /// regardless of the order in which they were toggled. Default
+    /// selections come first, in the order they were provided.
+    pub fn with_keep_toggle_order(mut self, keep_toggle_order: bool) -> Self {
+        self.keep_toggle_order = keep_toggle_order;
+        self
+    }
+
     /// Sets the scoring function.
     pub fn with_scorer(mut self, scorer: Scorer<'a, T>) -> Self {
         self.scorer = scorer;
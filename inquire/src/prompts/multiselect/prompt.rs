@@ -23,6 +23,7 @@ pub struct MultiSelectPrompt<'a, T> {
     help_message: Option<&'a str>,
     cursor_index: usize,
     checked: BTreeSet<usize>,
+    toggle_order: Vec<usize>,
     input: Option<Input>,
     scored_options: Vec<usize>,
     scorer: Scorer<'a, T>,
@@ -55,16 +56,14 @@ where
 
         let string_options = mso.options.iter().map(T::to_string).collect();
         let scored_options = (0..mso.options.len()).collect();
-        let checked_options = mso
-            .default
-            .as_ref()
-            .map(|d| {
-                d.iter()
-                    .cloned()
-                    .filter(|i| *i < mso.options.len())
-                    .collect()
-            })
-            .unwrap_or_default();
+        let mut checked_options = BTreeSet::new();
+        let mut toggle_order = vec![];
+
+        for &i in mso.default.iter().flatten() {
+            if i < mso.options.len() && checked_options.insert(i) {
+                toggle_order.push(i);
+            }
+        }
 
         let input = match mso.filter_input_enabled {
             true => Some(Input::new_with(
@@ -87,6 +86,7 @@ where
             validator: mso.validator,
             error: None,
             checked: checked_options,
+            toggle_order,
         })
     }
 
@@ -134,10 +134,13 @@ where
             None => return ActionResult::Clean,
         };
 
-        if self.checked.contains(idx) {
-            self.checked.remove(idx);
+        let idx = *idx;
+
+        if self.checked.remove(&idx) {
+            self.toggle_order.retain(|i| *i != idx);
         } else {
-            self.checked.insert(*idx);
+            self.checked.insert(idx);
+            self.toggle_order.push(idx);
         }
 
         ActionResult::NeedsRedraw
@@ -172,13 +175,9 @@ where
     fn validate_current_answer(&self) -> InquireResult<Validation> {
         if let Some(validator) = &self.validator {
             let selected_options = self
-                .options
-                .iter()
-                .enumerate()
-                .filter_map(|(idx, opt)| match &self.checked.contains(&idx) {
-                    true => Some(ListOption::new(idx, opt)),
-                    false => None,
-                })
+                .selection_order()
+                .into_iter()
+                .map(|idx| ListOption::new(idx, self.options.get(idx).unwrap()))
                 .collect::<Vec<_>>();
 
             let res = validator.validate(&selected_options)?;
@@ -188,21 +187,29 @@ where
         }
     }
 
-    fn get_final_answer(&mut self) -> Vec<ListOption<T>> {
-        let mut answer = vec![];
-
-        // by iterating in descending order, we can safely
-        // swap remove because the elements to the right
-        // that we did not remove will not matter anymore.
-        for index in self.checked.iter().rev() {
-            let index = *index;
-            let value = self.options.swap_remove(index);
-            let lo = ListOption::new(index, value);
-            answer.push(lo);
+    /// Indexes of the checked options, in the order they should be returned.
+    fn selection_order(&self) -> Vec<usize> {
+        if self.config.keep_toggle_order {
+            self.toggle_order.clone()
+        } else {
+            self.checked.iter().copied().collect()
         }
-        answer.reverse();
+    }
 
-        answer
+    fn get_final_answer(&mut self) -> Vec<ListOption<T>> {
+        let order = self.selection_order();
+        let mut options = std::mem::take(&mut self.options)
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<_>>();
+
+        order
+            .into_iter()
+            .filter_map(|index| {
+                let value = options.get_mut(index)?.take()?;
+                Some(ListOption::new(index, value))
+            })
+            .collect()
     }
 
     fn run_scorer(&mut self) {
@@ -221,7 +228,7 @@ where
             })
             .collect::<Vec<(usize, i64)>>();
 
-        options.sort_unstable_by_key(|(_idx, score)| Reverse(*score));
+        options.sort_by_key(|(_idx, score)| Reverse(*score));
 
         let new_scored_options = options.iter().map(|(idx, _)| *idx).collect::<Vec<usize>>();
 
@@ -266,6 +273,10 @@ where
         Ok(())
     }
 
+    fn has_validators(&self) -> bool {
+        self.validator.is_some()
+    }
+
     fn submit(&mut self) -> InquireResult<Option<Vec<ListOption<T>>>> {
         let answer = match self.validate_current_answer()? {
             Validation::Valid => Some(self.get_final_answer()),
@@ -291,13 +302,16 @@ where
             MultiSelectPromptAction::ToggleCurrentOption => self.toggle_cursor_selection(),
             MultiSelectPromptAction::SelectAll => {
                 self.checked.clear();
+                self.toggle_order.clear();
                 for idx in &self.scored_options {
                     self.checked.insert(*idx);
+                    self.toggle_order.push(*idx);
                 }
                 ActionResult::NeedsRedraw
             }
             MultiSelectPromptAction::ClearSelections => {
                 self.checked.clear();
+                self.toggle_order.clear();
                 ActionResult::NeedsRedraw
             }
             MultiSelectPromptAction::FilterInput(input_action) => match self.input.as_mut() {
@@ -3,7 +3,7 @@ use crate::{
     list_option::ListOption,
     test::fake_backend,
     ui::{Key, KeyModifiers},
-    MultiSelect,
+    InquireError, MultiSelect,
 };
 
 #[test]
@@ -255,3 +255,102 @@ fn keep_filter_false_should_reset_option_list() {
     let expected_answer = vec![ListOption::new(0, 1), ListOption::new(2, 3)];
     assert_eq!(expected_answer, ans);
 }
+
+#[test]
+fn default_options_are_checked_from_the_first_frame() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    MultiSelect::new("Question", vec!["a", "b", "c"])
+        .with_default(&[1])
+        .without_help_message()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let frames = backend.rendered_frames();
+    let first_frame = frames.first().unwrap();
+    assert!(first_frame.contains("[ ] a"));
+    assert!(first_frame.contains("[x] b"));
+    assert!(first_frame.contains("[ ] c"));
+}
+
+#[test]
+fn default_options_can_be_unchecked() {
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Question", vec!["a", "b", "c"])
+        .with_default(&[1, 2])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(2, "c")], ans);
+}
+
+#[test]
+fn out_of_bounds_default_fails() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = MultiSelect::new("Question", vec!["a", "b", "c"])
+        .with_default(&[3])
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn answers_are_returned_in_option_order_by_default() {
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Question", vec!["a", "b", "c"])
+        .with_default(&[1])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            ListOption::new(0, "a"),
+            ListOption::new(1, "b"),
+            ListOption::new(2, "c"),
+        ],
+        ans
+    );
+}
+
+#[test]
+fn answers_are_returned_in_toggle_order_when_enabled() {
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Question", vec!["a", "b", "c"])
+        .with_default(&[1])
+        .with_keep_toggle_order(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            ListOption::new(1, "b"),
+            ListOption::new(2, "c"),
+            ListOption::new(0, "a"),
+        ],
+        ans
+    );
+}
@@ -168,6 +168,10 @@ where
         Ok(true)
     }
 
+    fn has_validators(&self) -> bool {
+        !self.validators.is_empty()
+    }
+
     fn submit(&mut self) -> InquireResult<Option<String>> {
         if let Validation::Invalid(msg) = self.validate_current_answer()? {
             self.error = Some(msg);
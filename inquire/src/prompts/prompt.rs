@@ -1,6 +1,16 @@
 //! Definitions of common behavior shared amongst all different prompt types.
 
-use crate::{error::InquireResult, input::InputActionResult, ui::CommonBackend, InquireError};
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    error::InquireResult,
+    input::InputActionResult,
+    ui::{CommonBackend, Key},
+    InquireError,
+};
 
 use super::action::{Action, InnerAction};
 
@@ -14,11 +24,16 @@ pub enum ActionResult {
     /// The action either didn't result in a state change or the state
     /// change does not require a redraw.
     Clean,
+
+    /// The action requires the prompt to be submitted, just as if the user
+    /// had pressed the submit key.
+    Submit,
 }
 
 impl ActionResult {
     pub fn merge(self, other: Self) -> Self {
         match (self, other) {
+            (Self::Submit, _) | (_, Self::Submit) => Self::Submit,
             (Self::NeedsRedraw, _) | (_, Self::NeedsRedraw) => Self::NeedsRedraw,
             (Self::Clean, Self::Clean) => Self::Clean,
         }
@@ -26,7 +41,7 @@ impl ActionResult {
 
     /// Returns whether the action requires a redraw.
     pub fn needs_redraw(&self) -> bool {
-        matches!(self, Self::NeedsRedraw)
+        matches!(self, Self::NeedsRedraw | Self::Submit)
     }
 }
 
@@ -61,6 +76,15 @@ where
     /// actions to the prompt.
     fn config(&self) -> &Self::Config;
 
+    /// Derives the action triggered by a key event, from the prompt
+    /// configuration.
+    ///
+    /// Prompts may override this to remap the keys handled by [Action::from_key],
+    /// e.g. to replace the key canceling the prompt.
+    fn action_for_key(&self, key: Key) -> Option<Action<Self::InnerAction>> {
+        Action::from_key(key, self.config())
+    }
+
     /// Hook called when a prompt is finished. Returns a string
     /// to be rendered to the user as the final submission to the prompt.
     ///
@@ -95,7 +119,8 @@ where
 
     /// Entrypoint for any business logic for the prompt. Returns the result
     /// of the action. If the result is `Clean`, the prompt will
-    /// not be re-rendered.
+    /// not be re-rendered, and if it is `Submit`, the prompt will be
+    /// submitted as if the user had pressed the submit key.
     ///
     /// On the usual path, users' key presses are parsed into prompt actions,
     /// which are then submitted to this method to be handled.
@@ -104,6 +129,36 @@ where
     /// to the prompt, which will then be submitted to this method just the same.
     fn handle(&mut self, action: Self::InnerAction) -> InquireResult<ActionResult>;
 
+    /// Whether submissions are checked by validators, which might take a
+    /// while to run.
+    ///
+    /// When true, the prompt is redrawn with a validating indicator before
+    /// [Prompt::submit] is called, and submit keys pressed while the
+    /// validators run are discarded instead of submitting the prompt again.
+    fn has_validators(&self) -> bool {
+        false
+    }
+
+    /// Whether the full last frame of the prompt is kept on screen once it is
+    /// submitted, instead of being collapsed into the prompt message and the
+    /// formatted answer.
+    fn persists_full_render(&self) -> bool {
+        false
+    }
+
+    /// Interval between calls to [Prompt::tick], if the prompt needs to be
+    /// updated periodically while waiting for the user's input.
+    fn tick_interval(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Hook called periodically, as defined by [Prompt::tick_interval],
+    /// when no key was pressed during the interval. Returns the result of
+    /// the update, just like [Prompt::handle].
+    fn tick(&mut self) -> InquireResult<ActionResult> {
+        Ok(ActionResult::Clean)
+    }
+
     /// Hook called for the rendering of the prompt UI.
     ///
     /// The implementation should **not** call neither `frame_setup` or
@@ -115,29 +170,57 @@ where
     ///
     /// This should not be reimplemented by types that implement this trait,
     /// unless the situation really warrants it.
-    fn prompt(mut self, backend: &mut Backend) -> InquireResult<Self::Output> {
+    fn prompt(&mut self, backend: &mut Backend) -> InquireResult<Self::Output> {
         self.setup()?;
 
         let mut last_handle = ActionResult::NeedsRedraw;
+        let mut next_tick = None;
+        // Keys pressed while validators were running, other than submits.
+        let mut queued_keys = VecDeque::new();
         let final_answer = loop {
-            if last_handle.needs_redraw() {
-                backend.frame_setup()?;
-                self.render(backend)?;
-                backend.frame_finish(false)?;
-                last_handle = ActionResult::Clean;
+            // When keys are already buffered, e.g. on a paste or key-repeat,
+            // they are all handled before the prompt is redrawn once.
+            let mut pending_key = queued_keys.pop_front();
+
+            if pending_key.is_none() && last_handle.needs_redraw() {
+                pending_key = backend.poll_key(Duration::ZERO)?;
+
+                if pending_key.is_none() {
+                    backend.frame_setup()?;
+                    self.render(backend)?;
+                    backend.frame_finish(false)?;
+                    last_handle = ActionResult::Clean;
+                }
             }
 
-            let key = backend.read_key()?;
-            let action = Action::from_key(key, self.config());
+            let key = match (pending_key, self.tick_interval()) {
+                (Some(key), _) => key,
+                (None, Some(interval)) => {
+                    // a pending deadline is only kept when the tick is not
+                    // requested any sooner, e.g. immediately
+                    let tick_at = Instant::now() + interval;
+                    let deadline = match next_tick {
+                        Some(deadline) if deadline <= tick_at => deadline,
+                        _ => *next_tick.insert(tick_at),
+                    };
+                    let timeout = deadline.saturating_duration_since(Instant::now());
 
-            if let Some(action) = action {
-                last_handle = match action {
-                    Action::Submit => {
-                        if let Some(answer) = self.submit()? {
-                            break answer;
-                        }
-                        ActionResult::NeedsRedraw
+                    if let Some(key) = backend.poll_key(timeout)? {
+                        key
+                    } else {
+                        next_tick = None;
+                        last_handle = last_handle.merge(self.tick()?);
+                        continue;
                     }
+                }
+                (None, None) => backend.read_key()?,
+            };
+
+            let action = self.action_for_key(key);
+
+            if let Some(action) = action {
+                let mut result = match action {
+                    Action::Submit => ActionResult::Submit,
                     Action::Cancel => {
                         let pre_cancel_result = self.pre_cancel()?;
 
@@ -151,16 +234,81 @@ where
                         ActionResult::NeedsRedraw
                     }
                     Action::Interrupt => return Err(InquireError::OperationInterrupted),
-                    Action::Inner(inner_action) => self.handle(inner_action)?,
+                    Action::Inner(inner_action) => match self.handle(inner_action) {
+                        Ok(result) => result,
+                        Err(
+                            err @ (InquireError::NavigateBack | InquireError::OperationCanceled),
+                        ) => {
+                            backend.frame_setup()?;
+                            backend.render_canceled_prompt(self.message())?;
+                            backend.frame_finish(true)?;
+                            return Err(err);
+                        }
+                        Err(err) => return Err(err),
+                    },
                 };
+
+                if let ActionResult::Submit = result {
+                    if self.has_validators() && backend.has_validating_indicator() {
+                        backend.frame_setup()?;
+                        self.render(backend)?;
+                        backend.render_validating_indicator()?;
+                        backend.frame_finish(false)?;
+                    }
+
+                    // the full frame is rendered before submitting, as
+                    // prompts may move the answer out of their state
+                    let persisted = self.persists_full_render();
+                    if persisted {
+                        backend.frame_setup()?;
+                        self.render(backend)?;
+                    }
+
+                    let submitted = self.submit();
+
+                    if persisted {
+                        backend.frame_finish(matches!(submitted, Ok(Some(_))))?;
+                    }
+
+                    let answer = match submitted {
+                        Ok(answer) => answer,
+                        Err(err @ InquireError::NoMatchingOption) => {
+                            backend.frame_setup()?;
+                            backend.render_canceled_prompt(self.message())?;
+                            backend.frame_finish(true)?;
+                            return Err(err);
+                        }
+                        Err(err) => return Err(err),
+                    };
+
+                    if let Some(answer) = answer {
+                        break answer;
+                    }
+
+                    // keys typed ahead are left for the next prompt after a
+                    // valid submit, only the repeated submits of a rejected
+                    // answer are discarded
+                    if self.has_validators() {
+                        while let Some(key) = backend.poll_key(Duration::ZERO)? {
+                            if !matches!(self.action_for_key(key), Some(Action::Submit)) {
+                                queued_keys.push_back(key);
+                            }
+                        }
+                    }
+                    result = ActionResult::NeedsRedraw;
+                }
+
+                last_handle = last_handle.merge(result);
             }
         };
 
-        let formatted = self.format_answer(&final_answer);
+        if !self.persists_full_render() {
+            let formatted = self.format_answer(&final_answer);
 
-        backend.frame_setup()?;
-        backend.render_prompt_with_answer(self.message(), &formatted)?;
-        backend.frame_finish(true)?;
+            backend.frame_setup()?;
+            backend.render_prompt_with_answer(self.message(), &formatted)?;
+            backend.frame_finish(true)?;
+        }
 
         Ok(final_answer)
     }
@@ -1,12 +1,14 @@
 use crate::{
+    input::{LineDirection, Magnitude},
     ui::{Key, KeyModifiers},
-    InnerAction, InputAction,
+    InnerAction, InputAction, SelectLayout,
 };
 
 use super::config::SelectConfig;
 
 /// Set of actions for a SelectPrompt.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum SelectPromptAction {
     /// Action on the value text input handler.
     FilterInput(InputAction),
@@ -22,16 +24,72 @@ pub enum SelectPromptAction {
     MoveToStart,
     /// Moves the cursor to the end of the list.
     MoveToEnd,
+    /// Shows or hides the help message.
+    ToggleHelp,
+    /// Submits the highlighted option, descending into it in hierarchical menus.
+    Descend,
+    /// Goes back to the parent menu in hierarchical menus.
+    NavigateBack,
+    /// Jumps to the next option starting with the typed characters.
+    TypeAhead(char),
+    /// Cancels the prompt because the terminal window lost focus.
+    CancelOnFocusLoss,
+    /// Marks or unmarks the highlighted option.
+    ToggleMark,
+    /// Submits the only option matching the filter input, if any.
+    AcceptSingle,
+    /// Finishes a sequential selection with the options picked so far.
+    FinishPicks,
+    /// Copies the highlighted option to the clipboard.
+    CopyHighlighted,
+    /// Extends the range of options up from its anchor.
+    ExtendRangeUp,
+    /// Extends the range of options down from its anchor.
+    ExtendRangeDown,
 }
 
 impl InnerAction for SelectPromptAction {
     type Config = SelectConfig;
 
     fn from_key(key: Key, config: &SelectConfig) -> Option<Self> {
+        if config.done_key == Some(key) {
+            return Some(Self::FinishPicks);
+        }
+
+        // Arrow keys follow the rendered direction of the list.
+        let key = match key {
+            _ if !config.reverse_layout => key,
+            Key::Up(modifiers) => Key::Down(modifiers),
+            Key::Down(modifiers) => Key::Up(modifiers),
+            Key::PageUp(modifiers) => Key::PageDown(modifiers),
+            Key::PageDown(modifiers) => Key::PageUp(modifiers),
+            Key::Char('p', KeyModifiers::CONTROL) => Key::Char('n', KeyModifiers::CONTROL),
+            Key::Char('n', KeyModifiers::CONTROL) => Key::Char('p', KeyModifiers::CONTROL),
+            Key::Char('k', KeyModifiers::NONE) if config.vim_mode => {
+                Key::Char('j', KeyModifiers::NONE)
+            }
+            Key::Char('j', KeyModifiers::NONE) if config.vim_mode => {
+                Key::Char('k', KeyModifiers::NONE)
+            }
+            key => key,
+        };
+
+        if let Key::FocusLost = key {
+            return config
+                .cancel_on_focus_loss
+                .then_some(Self::CancelOnFocusLoss);
+        }
+
         if config.vim_mode {
             let action = match key {
                 Key::Char('k', KeyModifiers::NONE) => Some(Self::MoveUp),
                 Key::Char('j', KeyModifiers::NONE) => Some(Self::MoveDown),
+                Key::Char('l', KeyModifiers::NONE) if config.hierarchical_nav => {
+                    Some(Self::Descend)
+                }
+                Key::Char('h', KeyModifiers::NONE) if config.hierarchical_nav => {
+                    Some(Self::NavigateBack)
+                }
                 _ => None,
             };
 
@@ -40,6 +98,72 @@ impl InnerAction for SelectPromptAction {
             }
         }
 
+        if config.clipboard_copy {
+            match key {
+                Key::Char('y', KeyModifiers::CONTROL) => return Some(Self::CopyHighlighted),
+                Key::Char('y', KeyModifiers::NONE) if config.vim_mode => {
+                    return Some(Self::CopyHighlighted)
+                }
+                _ => {}
+            }
+        }
+
+        if config.help_on_demand {
+            if let Key::Char('?', _) = key {
+                return Some(Self::ToggleHelp);
+            }
+        }
+
+        if config.layout == SelectLayout::Horizontal {
+            match key {
+                Key::Left(KeyModifiers::NONE) => return Some(Self::MoveUp),
+                Key::Right(KeyModifiers::NONE) => return Some(Self::MoveDown),
+                _ => {}
+            }
+        }
+
+        if config.hierarchical_nav {
+            match key {
+                Key::Right(KeyModifiers::NONE) => return Some(Self::Descend),
+                Key::Left(KeyModifiers::NONE) => return Some(Self::NavigateBack),
+                _ => {}
+            }
+        }
+
+        if config.marking {
+            if let Key::Char(' ', KeyModifiers::NONE) = key {
+                return Some(Self::ToggleMark);
+            }
+        }
+
+        if config.range {
+            match key {
+                Key::Up(KeyModifiers::SHIFT) => return Some(Self::ExtendRangeUp),
+                Key::Down(KeyModifiers::SHIFT) => return Some(Self::ExtendRangeDown),
+                _ => {}
+            }
+        }
+
+        if config.typeahead {
+            if let Key::Char(c, KeyModifiers::NONE | KeyModifiers::SHIFT) = key {
+                return Some(Self::TypeAhead(c));
+            }
+        }
+
+        if config.tab_accepts_single {
+            if let Key::Tab = key {
+                return Some(Self::AcceptSingle);
+            }
+        }
+
+        if config.tab_paging {
+            match key {
+                Key::Tab => return Some(Self::PageDown),
+                Key::BackTab => return Some(Self::PageUp),
+                _ => {}
+            }
+        }
+
         let action = match key {
             Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::MoveUp,
             Key::PageUp(_) => Self::PageUp,
@@ -49,6 +173,17 @@ impl InnerAction for SelectPromptAction {
             Key::PageDown(_) => Self::PageDown,
             Key::End => Self::MoveToEnd,
 
+            // Home and End move between options, so the caret of the filter
+            // input is moved to its start or end with the readline bindings
+            Key::Char('a', KeyModifiers::CONTROL) => Self::FilterInput(InputAction::MoveCursor(
+                Magnitude::Line,
+                LineDirection::Left,
+            )),
+            Key::Char('e', KeyModifiers::CONTROL) => Self::FilterInput(InputAction::MoveCursor(
+                Magnitude::Line,
+                LineDirection::Right,
+            )),
+
             key => match InputAction::from_key(key, &()) {
                 Some(action) => Self::FilterInput(action),
                 None => return None,
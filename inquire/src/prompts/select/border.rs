@@ -0,0 +1,36 @@
+/// Box-drawing characters of the border framing the options of a
+/// [Select](crate::Select) prompt.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BorderStyle {
+    /// Thin lines with square corners, as in `┌─┐`.
+    #[default]
+    Single,
+
+    /// Thin lines with rounded corners, as in `╭─╮`.
+    Rounded,
+
+    /// Double lines, as in `╔═╗`.
+    Double,
+
+    /// Thick lines, as in `┏━┓`.
+    Heavy,
+
+    /// ASCII characters, as in `+-+`, for terminals without box-drawing
+    /// glyphs.
+    Ascii,
+}
+
+impl BorderStyle {
+    /// Characters of the top left, top right, bottom left and bottom right
+    /// corners, followed by the horizontal and vertical lines.
+    pub(crate) fn chars(self) -> [char; 6] {
+        match self {
+            BorderStyle::Single => ['┌', '┐', '└', '┘', '─', '│'],
+            BorderStyle::Rounded => ['╭', '╮', '╰', '╯', '─', '│'],
+            BorderStyle::Double => ['╔', '╗', '╚', '╝', '═', '║'],
+            BorderStyle::Heavy => ['┏', '┓', '┗', '┛', '━', '┃'],
+            BorderStyle::Ascii => ['+', '+', '+', '+', '-', '|'],
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::Select;
+use crate::{ui::Key, BorderStyle, Select, SelectLayout};
 
 /// Configuration settings used in the execution of a SelectPrompt.
 #[derive(Copy, Clone, Debug)]
@@ -9,6 +9,79 @@ pub struct SelectConfig {
     pub page_size: usize,
     /// Whether to reset the cursor to the first option on filter input change.
     pub reset_cursor: bool,
+    /// Maximum number of terminal rows the prompt may use, if any.
+    pub max_rows: Option<usize>,
+    /// Whether the help message is only displayed after the user asks for it.
+    pub help_on_demand: bool,
+    /// Whether Tab and Shift+Tab move the cursor by whole pages.
+    pub tab_paging: bool,
+    /// Whether options are matched by prefix and submitted once the match is unique.
+    pub accesskeys: bool,
+    /// Whether the option uniquely prefixed by the filter input is submitted.
+    pub prefix_autosubmit: bool,
+    /// Character repeated to draw a rule between the options and the help message.
+    pub separator: Option<char>,
+    /// Minimum distance kept between the cursor and the page edges, if the
+    /// page scrolls instead of being centered on the cursor.
+    pub scroll_margin: Option<usize>,
+    /// Whether Right and Left submit the option and go back to the parent menu.
+    pub hierarchical_nav: bool,
+    /// Whether typed characters jump to matching options, only when filtering is disabled.
+    pub typeahead: bool,
+    /// Index of the option flagged as recommended, if any.
+    pub recommended: Option<usize>,
+    /// Whether the prompt is canceled when the terminal window loses focus.
+    pub cancel_on_focus_loss: bool,
+    /// Arrangement of the options.
+    pub layout: SelectLayout,
+    /// Whether leading and trailing whitespace of the filter input is ignored.
+    pub trim_filter: bool,
+    /// Minimum number of characters of the filter input before the options are filtered.
+    pub min_query_len: usize,
+    /// Whether the submitted answer is replaced by a placeholder.
+    pub hidden_answer: bool,
+    /// Whether several options may share the same string value.
+    pub allow_duplicates: bool,
+    /// Whether the filter input is displayed along with the submitted answer.
+    pub show_filter_in_answer: bool,
+    /// Whether Space marks the highlighted option instead of typing in the filter input.
+    pub marking: bool,
+    /// Whether Tab submits the only option matching the filter input.
+    pub tab_accepts_single: bool,
+    /// Whether submitting while no option matches the filter input fails the prompt.
+    pub error_on_empty_submit: bool,
+    /// Key finishing a sequential selection, where Enter picks options
+    /// instead of submitting the prompt.
+    pub done_key: Option<Key>,
+    /// Whether Shift+Up and Shift+Down extend a range of options from an
+    /// anchor, submitted as a whole.
+    pub range: bool,
+    /// Whether the tokens of the filter syntax are highlighted in the filter input.
+    pub filter_syntax_highlight: bool,
+    /// Whether an indicator is prepended to the non-empty filter input.
+    pub search_indicator: bool,
+    /// Key canceling the prompt in place of Esc, if any.
+    pub cancel_key: Option<Key>,
+    /// Current step and total number of steps of the progress rendered above the prompt.
+    pub progress: Option<(usize, usize)>,
+    /// Whether the options are rendered above the prompt line, growing upward.
+    pub reverse_layout: bool,
+    /// Whether panics raised while the prompt runs are returned as errors.
+    pub catch_closure_panics: bool,
+    /// Whether the options contain ANSI escape sequences styling them.
+    pub prestyled_options: bool,
+    /// Whether options spanning several lines are rendered as blocks.
+    pub multiline_options: bool,
+    /// Whether a scrollbar is rendered at the right edge of the options.
+    pub scrollbar: bool,
+    /// Style of the border framing the options, only in the vertical layout.
+    pub border: Option<BorderStyle>,
+    /// Whether the prompt message is rendered as the title of the border.
+    pub border_title: bool,
+    /// Whether the full prompt is kept on screen once submitted.
+    pub persist_full_render: bool,
+    /// Whether Ctrl+Y copies the highlighted option to the clipboard.
+    pub clipboard_copy: bool,
 }
 
 impl<T> From<&Select<'_, T>> for SelectConfig {
@@ -17,6 +90,43 @@ impl<T> From<&Select<'_, T>> for SelectConfig {
             vim_mode: value.vim_mode,
             page_size: value.page_size,
             reset_cursor: value.reset_cursor,
+            max_rows: value.max_rows,
+            help_on_demand: value.help_on_demand,
+            tab_paging: value.tab_paging,
+            accesskeys: value.accesskeys,
+            prefix_autosubmit: value.prefix_autosubmit,
+            separator: value.separator,
+            scroll_margin: value.scroll_margin,
+            hierarchical_nav: value.hierarchical_nav,
+            typeahead: value.typeahead && !value.filter_input_enabled,
+            recommended: value.recommended,
+            cancel_on_focus_loss: value.cancel_on_focus_loss,
+            layout: value.layout,
+            trim_filter: value.trim_filter,
+            min_query_len: value.min_query_len,
+            hidden_answer: value.hidden_answer,
+            allow_duplicates: value.allow_duplicates,
+            show_filter_in_answer: value.show_filter_in_answer,
+            marking: value.marking,
+            tab_accepts_single: value.tab_accepts_single && value.filter_input_enabled,
+            error_on_empty_submit: value.error_on_empty_submit,
+            done_key: None,
+            range: false,
+            filter_syntax_highlight: value.filter_syntax_highlight,
+            search_indicator: value.search_indicator,
+            cancel_key: value.cancel_key,
+            progress: value.progress,
+            reverse_layout: value.reverse_layout && value.layout == SelectLayout::Vertical,
+            catch_closure_panics: value.catch_closure_panics,
+            prestyled_options: value.prestyled_options,
+            multiline_options: value.multiline_options,
+            scrollbar: value.scrollbar,
+            border: value
+                .border
+                .filter(|_| value.layout == SelectLayout::Vertical),
+            border_title: value.border_title,
+            persist_full_render: value.persist_full_render,
+            clipboard_copy: value.clipboard_copy,
         }
     }
 }
@@ -0,0 +1,41 @@
+use crate::list_option::ListOption;
+
+/// Snapshot of the state of a [Select](crate::Select) prompt, provided to
+/// render hooks on every frame.
+#[derive(Debug)]
+pub struct RenderContext<'c, T> {
+    /// Options currently available to the user after filtering, in the order
+    /// they are displayed.
+    pub filtered_options: &'c [ListOption<&'c T>],
+
+    /// Position of the cursor in `filtered_options`.
+    pub cursor_index: usize,
+
+    /// Current content of the filter input. Empty when filtering is disabled.
+    pub input: &'c str,
+
+    /// Width and height of the terminal the prompt is rendered to, in
+    /// columns and rows, if known. Useful to truncate or align the
+    /// rendered lines.
+    pub terminal_size: Option<(u16, u16)>,
+}
+
+/// Aggregate counts of a [Select](crate::Select) prompt, provided to footer
+/// renderers on every frame.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FooterContext {
+    /// Number of options of the prompt.
+    pub total_options: usize,
+
+    /// Number of options available to the user after filtering.
+    pub filtered_options: usize,
+
+    /// Number of options hidden by the filter input.
+    pub hidden_options: usize,
+
+    /// Position of the cursor among the filtered options.
+    pub cursor_index: usize,
+
+    /// Number of marked options.
+    pub marked_options: usize,
+}
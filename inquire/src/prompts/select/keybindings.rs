@@ -0,0 +1,283 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    Select, SelectLayout,
+};
+
+use super::config::SelectConfig;
+
+/// Key binding active on a prompt, as listed by
+/// [Select::keybindings](crate::Select::keybindings), e.g. to render the
+/// bindings in the help screen of an application.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyBinding {
+    /// Labels of the keys triggering the action, such as `"Up"` or `"Ctrl+P"`.
+    pub keys: Vec<String>,
+
+    /// Description of the action triggered by the keys.
+    pub action: &'static str,
+}
+
+impl KeyBinding {
+    fn new(keys: &[Key], action: &'static str) -> Self {
+        Self {
+            keys: keys.iter().map(Key::to_string).collect(),
+            action,
+        }
+    }
+
+    fn described(keys: &'static str, action: &'static str) -> Self {
+        Self {
+            keys: vec![keys.to_owned()],
+            action,
+        }
+    }
+}
+
+/// Lists the key bindings of a select prompt with the provided settings, in
+/// the order of precedence they are checked in.
+pub(super) fn select_keybindings(
+    config: &SelectConfig,
+    filter_input_enabled: bool,
+) -> Vec<KeyBinding> {
+    let ctrl = |c| Key::Char(c, KeyModifiers::CONTROL);
+    let plain = |c| Key::Char(c, KeyModifiers::NONE);
+
+    let mut bindings = vec![
+        KeyBinding::new(&[Key::Enter], "Submit the highlighted option"),
+        KeyBinding::new(
+            &[
+                config.cancel_key.unwrap_or(Key::Escape),
+                ctrl('g'),
+                ctrl('d'),
+            ],
+            "Cancel the prompt",
+        ),
+        KeyBinding::new(&[ctrl('c')], "Interrupt the prompt"),
+    ];
+
+    if let Some(done_key) = config.done_key {
+        bindings.push(KeyBinding::new(
+            &[done_key],
+            "Finish the selection with the picked options",
+        ));
+    }
+
+    if config.vim_mode {
+        bindings.push(KeyBinding::new(&[plain('k')], "Move up"));
+        bindings.push(KeyBinding::new(&[plain('j')], "Move down"));
+
+        if config.hierarchical_nav {
+            bindings.push(KeyBinding::new(
+                &[Key::Char('l', KeyModifiers::NONE)],
+                "Submit the highlighted option and descend into it",
+            ));
+            bindings.push(KeyBinding::new(
+                &[Key::Char('h', KeyModifiers::NONE)],
+                "Go back to the parent menu",
+            ));
+        }
+    }
+
+    if config.clipboard_copy {
+        let mut keys = vec![ctrl('y')];
+        if config.vim_mode {
+            keys.push(plain('y'));
+        }
+
+        bindings.push(KeyBinding::new(
+            &keys,
+            "Copy the highlighted option to the clipboard",
+        ));
+    }
+
+    if config.help_on_demand {
+        bindings.push(KeyBinding::new(
+            &[Key::Char('?', KeyModifiers::NONE)],
+            "Show or hide the help message",
+        ));
+    }
+
+    if config.layout == SelectLayout::Horizontal {
+        bindings.push(KeyBinding::new(
+            &[Key::Left(KeyModifiers::NONE)],
+            "Move to the previous option",
+        ));
+        bindings.push(KeyBinding::new(
+            &[Key::Right(KeyModifiers::NONE)],
+            "Move to the next option",
+        ));
+    } else if config.hierarchical_nav {
+        bindings.push(KeyBinding::new(
+            &[Key::Right(KeyModifiers::NONE)],
+            "Submit the highlighted option and descend into it",
+        ));
+        bindings.push(KeyBinding::new(
+            &[Key::Left(KeyModifiers::NONE)],
+            "Go back to the parent menu",
+        ));
+    }
+
+    if config.marking {
+        bindings.push(KeyBinding::new(
+            &[Key::Char(' ', KeyModifiers::NONE)],
+            "Mark or unmark the highlighted option",
+        ));
+    }
+
+    if config.range {
+        bindings.push(KeyBinding::new(
+            &[Key::Up(KeyModifiers::SHIFT)],
+            "Extend the range up",
+        ));
+        bindings.push(KeyBinding::new(
+            &[Key::Down(KeyModifiers::SHIFT)],
+            "Extend the range down",
+        ));
+    }
+
+    if config.typeahead {
+        bindings.push(KeyBinding::described(
+            "Letters",
+            "Jump to the next option starting with the typed letters",
+        ));
+    }
+
+    if config.accesskeys {
+        bindings.push(KeyBinding::described(
+            "Letters",
+            "Submit the option once the typed letters match it alone",
+        ));
+    }
+
+    if config.prefix_autosubmit {
+        bindings.push(KeyBinding::described(
+            "Text",
+            "Submit the option once the filter input is a prefix of it alone",
+        ));
+    }
+
+    if config.tab_accepts_single {
+        bindings.push(KeyBinding::new(
+            &[Key::Tab],
+            "Submit the only option matching the filter",
+        ));
+    }
+
+    let mut page_down = vec![Key::PageDown(KeyModifiers::NONE)];
+    let mut page_up = vec![Key::PageUp(KeyModifiers::NONE)];
+
+    if config.tab_paging {
+        // Tab moves to the next page of the list, which is rendered above
+        // the current one in the reverse layout.
+        let (next_page, previous_page) = match config.reverse_layout {
+            true => (&mut page_up, &mut page_down),
+            false => (&mut page_down, &mut page_up),
+        };
+
+        if !config.tab_accepts_single {
+            next_page.push(Key::Tab);
+        }
+        previous_page.push(Key::BackTab);
+    }
+
+    bindings.extend([
+        KeyBinding::new(&[Key::Up(KeyModifiers::NONE), ctrl('p')], "Move up"),
+        KeyBinding::new(&[Key::Down(KeyModifiers::NONE), ctrl('n')], "Move down"),
+        KeyBinding::new(&page_up, "Move one page up"),
+        KeyBinding::new(&page_down, "Move one page down"),
+        KeyBinding::new(&[Key::Home], "Move to the first option"),
+        KeyBinding::new(&[Key::End], "Move to the last option"),
+    ]);
+
+    if filter_input_enabled {
+        if config.layout == SelectLayout::Vertical && !config.hierarchical_nav {
+            bindings.push(KeyBinding::new(
+                &[Key::Left(KeyModifiers::NONE)],
+                "Move the caret of the filter input back one character",
+            ));
+            bindings.push(KeyBinding::new(
+                &[Key::Right(KeyModifiers::NONE)],
+                "Move the caret of the filter input forward one character",
+            ));
+        }
+
+        bindings.extend([
+            KeyBinding::new(
+                &[ctrl('a')],
+                "Move the caret to the start of the filter input",
+            ),
+            KeyBinding::new(
+                &[ctrl('e')],
+                "Move the caret to the end of the filter input",
+            ),
+            KeyBinding::described("Text", "Filter the options"),
+        ]);
+    }
+
+    bindings
+}
+
+/// Builds the default help message of a select prompt, only mentioning the
+/// key bindings enabled by the provided settings.
+pub(super) fn default_help_message(config: &SelectConfig, filter_input_enabled: bool) -> String {
+    let mut parts = vec![match config.layout {
+        SelectLayout::Horizontal => String::from("←→ to move"),
+        SelectLayout::Vertical => String::from("↑↓ to move"),
+    }];
+
+    match config.done_key {
+        Some(done_key) => {
+            parts.push(String::from("enter to pick"));
+            parts.push(format!("{done_key} to finish"));
+        }
+        None => parts.push(String::from("enter to select")),
+    }
+
+    if config.marking {
+        parts.push(String::from("space to mark"));
+    }
+
+    if config.range {
+        parts.push(String::from("shift+↑↓ to extend"));
+    }
+
+    if config.tab_accepts_single {
+        parts.push(String::from("tab to accept a single match"));
+    }
+
+    if filter_input_enabled && config.accesskeys {
+        parts.push(String::from("type to select"));
+    } else if filter_input_enabled {
+        parts.push(String::from("type to filter"));
+    } else if config.typeahead {
+        parts.push(String::from("type to jump"));
+    }
+
+    if let Some(cancel_key) = config.cancel_key {
+        parts.push(format!("{cancel_key} to cancel"));
+    }
+
+    parts.join(", ")
+}
+
+/// Builds the help message displayed by a select prompt: the default help
+/// message matching the key bindings while the default is set, or the
+/// provided one otherwise, followed by the suffix, if any.
+pub(super) fn displayed_help_message(
+    help_message: &str,
+    suffix: Option<&str>,
+    config: &SelectConfig,
+    filter_input_enabled: bool,
+) -> String {
+    let mut message = match Some(help_message) == Select::<&str>::DEFAULT_HELP_MESSAGE {
+        true => default_help_message(config, filter_input_enabled),
+        false => String::from(help_message),
+    };
+
+    if let Some(suffix) = suffix {
+        message.push_str(", ");
+        message.push_str(suffix);
+    }
+
+    message
+}
@@ -0,0 +1,14 @@
+/// Arrangement of the options of a [Select](crate::Select) prompt.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SelectLayout {
+    /// The options are listed one per line below the prompt, navigated with
+    /// the up and down arrow keys.
+    #[default]
+    Vertical,
+
+    /// Only the highlighted option is displayed, on the same line as the
+    /// prompt, as in `? Fruit < Apple >`. The left and right arrow keys cycle
+    /// through the options and filtering is disabled.
+    Horizontal,
+}
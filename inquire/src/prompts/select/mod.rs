@@ -1,25 +1,67 @@
 mod action;
+mod border;
 mod config;
+mod context;
+mod keybindings;
+mod layout;
+mod overflow;
+mod owned;
 mod prompt;
+mod range;
+mod result;
+mod settings;
+mod state;
+mod status;
 #[cfg(test)]
 #[cfg(feature = "crossterm")]
 mod test;
 
 pub use action::*;
-use std::fmt::Display;
+pub use border::*;
+pub use context::*;
+pub use keybindings::KeyBinding;
+pub use layout::*;
+pub use overflow::*;
+pub use owned::*;
+pub use range::*;
+pub use result::*;
+pub use settings::*;
+pub use state::*;
+pub use status::*;
+use std::{
+    fmt::Display,
+    io::{self, Write},
+    rc::Rc,
+    sync::{mpsc, Arc},
+    thread,
+    time::Duration,
+};
 
 use crate::{
     config::get_configuration,
     error::{InquireError, InquireResult},
     formatter::OptionFormatter,
     list_option::ListOption,
-    prompts::prompt::Prompt,
-    terminal::get_default_terminal,
-    type_aliases::Scorer,
-    ui::{Backend, RenderConfig, SelectBackend},
+    prompts::{action::Action, prompt::Prompt},
+    terminal::{
+        get_terminal,
+        replay::{NullTerminal, ReplayInput},
+        Terminal,
+    },
+    type_aliases::{
+        DescriptionProvider, FinishHook, FooterRenderer, HighlightHook, IconProvider, KeyFilter,
+        LoadingSource, PaginationRenderer, Refresher, RenderHook, Scorer, ShowAllPredicate,
+        StartHook, StatusProvider,
+    },
+    ui::{Backend, InputReader, Key, RenderConfig, SelectBackend},
+    utils::{paginate_rows, retry, wrap_words},
+    PromptSession,
 };
 
-use self::prompt::SelectPrompt;
+use self::{
+    config::SelectConfig,
+    prompt::{match_strings, score_options, SelectOutput, SelectPrompt},
+};
 
 #[cfg(feature = "fuzzy")]
 use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
@@ -43,14 +85,70 @@ static DEFAULT_MATCHER: LazyLock<SkimMatcherV2> =
 ///
 /// - **Prompt message**: Required when creating the prompt.
 /// - **Options list**: Options displayed to the user. Must be **non-empty**.
-/// - **Starting cursor**: Index of the cursor when the prompt is first rendered. Default is 0 (first option). If the index is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error.
+/// - **Starting cursor**: Index of the cursor when the prompt is first rendered. Default is 0 (first option). If the index is out-of-range of the option list, the prompt will fail with an [`InquireError::InvalidConfiguration`] error, unless configured to clamp or wrap the index.
+/// - **Recommended option**: Flags one of the options as recommended, independently from the cursor.
 /// - **Starting filter input**: Sets the initial value of the filter section of the prompt.
+/// - **Create new**: Returns the typed filter input as a new answer when it matches no option. Disabled by default, and only supported by [`Select::prompt_result`].
+/// - **Filter label**: Displays the filter input on its own line, after the given label, instead of next to the prompt message.
+/// - **Filter placeholder**: Dim hint such as `Type to filter…` displayed in the filter input while it is empty. None by default.
+/// - **Type-ahead**: When filtering is disabled, typing jumps to the next option starting with the typed letters. Disabled by default.
 /// - **Help message**: Message displayed at the line below the prompt.
+/// - **Help on demand**: Hides the help message until the user presses `?`. Disabled by default.
 /// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
 ///   - Prints the selected option string value by default.
 /// - **Page size**: Number of options displayed at once, 7 by default.
+/// - **Max rows**: Upper bound on the number of terminal rows used by the whole prompt, shrinking the page size when needed. No limit by default.
+/// - **Scroll margin**: Scrolls the page only when the cursor gets close to its edges, instead of keeping it centered. Disabled by default.
+/// - **Ensure visible**: Scrolls the first page so that a given option is visible along with the cursor. None by default.
 /// - **Display option indexes**: On long lists, it might be helpful to display the indexes of the options to the user. Via the `RenderConfig`, you can set the display mode of the indexes as a prefix of an option. The default configuration is `None`, to not render any index when displaying the options.
+/// - **Hierarchical navigation**: Right submits the option and Left returns [`InquireError::NavigateBack`], to navigate nested menus. Disabled by default.
+/// - **Access keys**: Selects options by typing their first letters, without pressing enter. Disabled by default.
+/// - **Prefix auto-submit**: Submits the option once the filter input is a prefix of that option alone. Disabled by default.
+/// - **Alternate screen**: Displays the prompt in the alternate screen buffer, restoring the terminal content when done. Disabled by default.
+/// - **Weights**: Ranks the options with the highest weights first, such as the most frequently chosen ones. None by default.
+/// - **Aligned annotations**: Annotations displayed next to options with a matching label, aligned in a column. None by default.
+/// - **Icons**: Function providing an icon rendered before each option, aligned in a column. None by default.
+/// - **Status**: Function providing the status of each option, rendered as a colored indicator before it on every frame. None by default.
+/// - **Title**: Styled line rendered above the prompt message while it is active. None by default.
+/// - **Reverse layout**: Renders the options above the prompt line, growing upward. Disabled by default.
+/// - **Layout**: Whether the options are listed vertically or displayed one at a time on the prompt line. Vertical by default.
+/// - **TTY input**: Reads keys from and renders to the controlling terminal even when the standard streams are redirected. Disabled by default.
+/// - **Cancel key**: Key canceling the prompt in place of Esc. Esc by default.
+/// - **Stdout echo**: Writes the submitted answer to the standard output once the prompt is finished, for shell capture. Disabled by default.
+/// - **Cancel on focus loss**: Cancels the prompt when the terminal window loses focus. Disabled by default.
 /// - **Scorer function**: Function that defines the order of options and if displayed as all.
+/// - **Filter on rendered**: Matches the filter input against the options as rendered, with their icon and annotation. Disabled by default.
+/// - **Error on empty submit**: Fails with [`InquireError::NoMatchingOption`] when submitting while no option matches the filter input. Disabled by default.
+/// - **Search indicator**: Prepends `/` to the filter input while it is not empty, distinguishing searching from browsing. Disabled by default.
+/// - **Trimmed filter**: Whether leading and trailing whitespace of the filter input is ignored. Enabled by default.
+/// - **Minimum query length**: Number of characters of the filter input below which all options are displayed, unfiltered. 0 by default.
+/// - **Hidden answer**: Displays a placeholder instead of the submitted answer. Disabled by default.
+/// - **Filter in answer**: Displays the typed filter input along with the submitted answer. Disabled by default.
+/// - **Allow duplicates**: Whether several options may be displayed the same. Enabled by default.
+/// - **Marking**: Space marks options to be returned along with the answer by [Select::prompt_with_marks]. Disabled by default.
+/// - **Footer**: Function rendering a line with aggregate counts below the help message.
+/// - **Clipboard copy**: Ctrl+Y copies the highlighted option to the clipboard, with the `clipboard` feature. Disabled by default.
+/// - **Escape timeout**: Time to wait for the rest of an escape sequence split across reads. Disabled by default.
+/// - **Scrollbar**: One-column scrollbar at the right edge of the options, reflecting the position in long lists. Disabled by default.
+/// - **Border**: Box-drawing border framing the options, optionally titled with the prompt message. Disabled by default.
+/// - **Persist full render**: Keeps the whole prompt, options included, on screen once submitted. Disabled by default.
+/// - **Multi-line options**: Options spanning several lines are rendered as blocks. Disabled by default.
+/// - **Progress**: Row of indicators above the prompt showing the step of a multi-step flow.
+/// - **Render hook**: Function called on every frame to render additional lines below the options.
+/// - **Refresh**: Function called periodically to replace the options while the prompt is displayed.
+/// - **Highlight hook**: Function called whenever a different option is highlighted, e.g. to update a preview.
+/// - **Lazy description**: Function describing the highlighted option below the list, computed once per option when it is first highlighted.
+/// - **Key filter**: Function remapping or dropping the pressed keys before they are handled.
+/// - **Confirmed options**: Options whose submission must be confirmed by pressing Enter again, such as destructive entries. None by default.
+/// - **Lifecycle hooks**: Functions called when the prompt becomes interactive and once it is finished.
+/// - **Separator**: Character used to draw a horizontal rule between the options and the help message. Not drawn by default.
+///
+/// When the `INQUIRE_DEBUG` environment variable is set to `1` or `true`, the
+/// number of filtered options, the cursor index and the displayed page of
+/// options are written to stderr on every render, to help diagnose
+/// pagination and filtering issues. As the prompt is rendered to stderr too,
+/// `INQUIRE_DEBUG_FILE` can be set to the path of a file to which they are
+/// appended instead.
 ///
 /// # Example
 ///
@@ -81,16 +179,90 @@ pub struct Select<'a, T> {
     /// Help message to be presented to the user.
     pub help_message: Option<&'a str>,
 
+    /// Hint appended to the help message, after a comma, if any.
+    ///
+    /// Defaults to None.
+    pub help_suffix: Option<&'a str>,
+
+    /// Whether the help message is hidden until the user presses `?`.
+    ///
+    /// Defaults to false.
+    pub help_on_demand: bool,
+
     /// Page size of the options displayed to the user.
     pub page_size: usize,
 
+    /// Maximum number of terminal rows the prompt is allowed to use,
+    /// including the prompt line and the help message.
+    ///
+    /// When set, the effective page size is the smallest between `page_size`
+    /// and the rows left after reserving space for the other lines.
+    pub max_rows: Option<usize>,
+
+    /// Minimum number of options kept between the cursor and the edges of
+    /// the page. When set, the page only scrolls when the cursor gets closer
+    /// to an edge than the margin, instead of being kept centered on the
+    /// cursor.
+    ///
+    /// Defaults to None.
+    pub scroll_margin: Option<usize>,
+
+    /// Index of an option, in the original list, displayed on the first page
+    /// along with the cursor, if any. When set, the page scrolls as with a
+    /// scroll margin of 0 unless a scroll margin is set.
+    ///
+    /// Defaults to None.
+    pub ensure_visible: Option<usize>,
+
     /// Whether vim mode is enabled. When enabled, the user can
     /// navigate through the options using hjkl.
+    ///
+    /// Defaults to the global vim mode flag, see [set_global_vim_mode](crate::set_global_vim_mode).
     pub vim_mode: bool,
 
+    /// Whether Tab and Shift+Tab move the cursor one page down and up,
+    /// respectively.
+    ///
+    /// Defaults to false.
+    pub tab_paging: bool,
+
+    /// Whether Right submits the highlighted option and Left cancels the
+    /// prompt with [InquireError::NavigateBack], for hierarchical menus.
+    ///
+    /// Defaults to false.
+    pub hierarchical_nav: bool,
+
+    /// Whether options can be selected by typing their first letters, without
+    /// pressing enter.
+    ///
+    /// Defaults to false.
+    pub accesskeys: bool,
+
+    /// Whether the option is submitted as soon as the filter input is a
+    /// prefix of that option alone.
+    ///
+    /// Defaults to false.
+    pub prefix_autosubmit: bool,
+
+    /// Character repeated across the terminal width to draw a horizontal
+    /// rule between the list of options and the help message.
+    ///
+    /// Defaults to None.
+    pub separator: Option<char>,
+
     /// Starting cursor index of the selection.
     pub starting_cursor: usize,
 
+    /// Index of the option flagged as recommended, if any.
+    ///
+    /// Defaults to None.
+    pub recommended: Option<usize>,
+
+    /// Behavior when the starting cursor index is out of bounds.
+    ///
+    /// Defaults to [CursorOverflow::Error].
+    pub cursor_overflow: CursorOverflow,
+
     /// Starting filter input
     pub starting_filter_input: Option<&'a str>,
 
@@ -98,6 +270,43 @@ pub struct Select<'a, T> {
     /// Defaults to true.
     pub reset_cursor: bool,
 
+    /// Title rendered on its own line above the prompt message, if any.
+    ///
+    /// Defaults to None.
+    pub title: Option<&'a str>,
+
+    /// Current step and total number of steps of the progress rendered above
+    /// the prompt, if any.
+    ///
+    /// Defaults to None.
+    pub progress: Option<(usize, usize)>,
+
+    /// Label of a dedicated line displaying the filter input, below the
+    /// prompt message. When None, the filter input is displayed on the same
+    /// line as the prompt message.
+    ///
+    /// Defaults to None.
+    pub filter_label: Option<&'a str>,
+
+    /// Hint displayed in the filter input while it is empty, such as
+    /// `"Type to filter…"`.
+    ///
+    /// Defaults to None.
+    pub filter_placeholder: Option<&'a str>,
+
+    /// Whether typing jumps the cursor to the next option starting with the
+    /// typed characters. Only used when filtering is disabled.
+    ///
+    /// Defaults to false.
+    pub typeahead: bool,
+
+    /// Whether submitting filter input that matches none of the options
+    /// returns the typed text as a new answer, when prompting with
+    /// [Select::prompt_result].
+    ///
+    /// Defaults to false.
+    pub create_new: bool,
+
     /// Whether to allow the option list to be filtered by user input or not.
     ///
     /// Defaults to true.
@@ -105,11 +314,240 @@ pub struct Select<'a, T> {
 
     /// Function called with the current user input to score the provided
     /// options.
+    ///
+    /// Options with greater scores are displayed first, options with equal
+    /// scores keep their original order, and options scored as `None` are
+    /// filtered out.
     pub scorer: Scorer<'a, T>,
 
+    /// Predicate deciding whether the current user input displays all
+    /// options in their original order, instead of scoring them. The scorer
+    /// is not called for such inputs.
+    ///
+    /// Defaults to [Select::DEFAULT_SHOW_ALL_PREDICATE].
+    pub show_all_predicate: ShowAllPredicate<'a>,
+
+    /// Whether leading and trailing whitespace of the filter input is
+    /// ignored when matching options.
+    ///
+    /// Defaults to true.
+    pub trim_filter: bool,
+
+    /// Minimum number of characters of the filter input, after trimming if
+    /// enabled, before the options are filtered. Shorter inputs display all
+    /// options in their original order.
+    ///
+    /// Defaults to 0.
+    pub min_query_len: usize,
+
+    /// Whether the submitted answer is replaced by
+    /// [Select::HIDDEN_ANSWER_PLACEHOLDER] when the prompt is finished.
+    ///
+    /// Defaults to false.
+    pub hidden_answer: bool,
+
+    /// Whether the filter input typed to find the answer is displayed along
+    /// with it when the prompt is finished.
+    ///
+    /// Defaults to false.
+    pub show_filter_in_answer: bool,
+
+    /// Whether several options may share the same string value.
+    ///
+    /// Defaults to true.
+    pub allow_duplicates: bool,
+
+    /// Whether Space marks or unmarks the highlighted option, instead of
+    /// typing in the filter input. The marked options are returned by
+    /// [Select::prompt_with_marks].
+    ///
+    /// Defaults to false.
+    pub marking: bool,
+
+    /// Whether Tab submits the only option matching the filter input, instead
+    /// of requiring Enter.
+    ///
+    /// Defaults to false.
+    pub tab_accepts_single: bool,
+
+    /// Whether submitting the prompt while the filter input matches none of
+    /// the options fails with [InquireError::NoMatchingOption].
+    ///
+    /// Defaults to false.
+    pub error_on_empty_submit: bool,
+
+    /// Whether exclusion operators and quoted phrases typed in the filter
+    /// input are highlighted.
+    ///
+    /// Defaults to false.
+    pub filter_syntax_highlight: bool,
+
+    /// Whether an indicator is prepended to the filter input while it is not
+    /// empty, to distinguish searching from browsing.
+    ///
+    /// Defaults to false.
+    pub search_indicator: bool,
+
+    /// Maximum number of characters of the filter input, if any.
+    ///
+    /// Defaults to None.
+    pub max_filter_len: Option<usize>,
+
+    /// Key canceling the prompt in place of Esc, if any.
+    ///
+    /// Defaults to None.
+    pub cancel_key: Option<Key>,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: OptionFormatter<'a, T>,
 
+    /// Function called on every frame to render additional lines right after
+    /// the list of options.
+    pub render_hook: Option<RenderHook<'a, T>>,
+
+    /// Function called on every frame with the displayed page of options, to
+    /// render pagination indicators right after them.
+    pub pagination_renderer: Option<PaginationRenderer<'a, T>>,
+
+    /// Function called on every frame to render a footer line below the help
+    /// message.
+    pub footer: Option<FooterRenderer<'a>>,
+
+    /// Indexes of the options whose submission must be confirmed by pressing
+    /// Enter again, along with the confirmation message.
+    pub confirm_indices: Option<(&'a [usize], &'a str)>,
+
+    /// Function called periodically to replace the options, along with the
+    /// interval between calls.
+    pub refresh: Option<(Refresher<'a, T>, Duration)>,
+
+    /// Function starting to load the options in the background, replacing
+    /// the provided ones once loaded.
+    pub loading_source: Option<LoadingSource<'a, T>>,
+
+    /// Function called whenever a different option becomes highlighted.
+    pub on_highlight: Option<HighlightHook<'a, T>>,
+
+    /// Function describing the highlighted option, called once per option.
+    pub lazy_description: Option<DescriptionProvider<'a, T>>,
+
+    /// Function transforming the pressed keys before they are handled.
+    pub key_filter: Option<KeyFilter<'a>>,
+
+    /// Function called when the prompt becomes interactive.
+    pub on_start: Option<StartHook<'a>>,
+
+    /// Function called with the result of the prompt once it is finished.
+    pub on_finish: Option<FinishHook<'a, T>>,
+
+    /// Whether the prompt is displayed in the alternate screen buffer of the
+    /// terminal, restoring the previous content of the terminal when done.
+    ///
+    /// Defaults to false.
+    pub alternate_screen: bool,
+
+    /// Whether the prompt uses the controlling terminal directly instead of
+    /// the standard streams.
+    ///
+    /// Defaults to false.
+    pub tty_input: bool,
+
+    /// Whether the prompt is canceled when the terminal window loses focus.
+    ///
+    /// Defaults to false.
+    pub cancel_on_focus_loss: bool,
+
+    /// Whether the submitted answer is written to the standard output once
+    /// the prompt is finished.
+    ///
+    /// Defaults to false.
+    pub stdout_echo: bool,
+
+    /// Whether the options are rendered above the prompt line, growing upward.
+    ///
+    /// Defaults to false.
+    pub reverse_layout: bool,
+
+    /// Whether panics raised by the provided closures are returned as
+    /// [InquireError::Custom] errors.
+    ///
+    /// Defaults to false.
+    pub catch_closure_panics: bool,
+
+    /// Whether the string values of the options contain ANSI escape
+    /// sequences styling them.
+    ///
+    /// Defaults to false.
+    pub prestyled_options: bool,
+
+    /// Whether the filter input is matched against the options as rendered,
+    /// along with their icon and annotation, instead of their string values.
+    ///
+    /// Defaults to false.
+    pub filter_on_rendered: bool,
+
+    /// Whether options spanning several lines are rendered as blocks.
+    ///
+    /// Defaults to false.
+    pub multiline_options: bool,
+
+    /// Whether a scrollbar is rendered at the right edge of the options when
+    /// they do not fit the page.
+    ///
+    /// Defaults to false.
+    pub scrollbar: bool,
+
+    /// Style of the border framing the options, if any.
+    ///
+    /// Defaults to None.
+    pub border: Option<BorderStyle>,
+
+    /// Whether the prompt message is rendered as the title of the border,
+    /// instead of on the prompt line.
+    ///
+    /// Defaults to false.
+    pub border_title: bool,
+
+    /// Whether the full prompt, options included, is kept on screen once
+    /// submitted, instead of collapsing it into the prompt and the answer.
+    ///
+    /// Defaults to false.
+    pub persist_full_render: bool,
+
+    /// Whether Ctrl+Y copies the highlighted option to the clipboard.
+    ///
+    /// Defaults to false.
+    pub clipboard_copy: bool,
+
+    /// How long to wait for the rest of an escape sequence after a lone Esc
+    /// is read, before treating it as a standalone Esc key.
+    ///
+    /// Defaults to None, not waiting.
+    pub escape_timeout: Option<Duration>,
+
+    /// Arrangement of the options.
+    ///
+    /// Defaults to [SelectLayout::Vertical].
+    pub layout: SelectLayout,
+
+    /// Pairs of option labels and annotations displayed next to the options
+    /// whose string value matches the label.
+    ///
+    /// Defaults to no annotations.
+    pub annotations: &'a [(&'a str, &'a str)],
+
+    /// Weights of the options, in the same order as the options, ranking the
+    /// options with the highest weights first.
+    ///
+    /// Defaults to no weights.
+    pub weights: &'a [f64],
+
+    /// Function providing the icon rendered before each option, if any.
+    pub icons: Option<IconProvider<'a>>,
+
+    /// Function providing the status rendered before each option, if any.
+    pub status: Option<StatusProvider<'a>>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -179,15 +617,176 @@ where
             }
         };
 
+    /// Scorer calling the global scorer registered with
+    /// [set_global_scorer](crate::set_global_scorer).
+    const GLOBAL_SCORER: Scorer<'a, T> =
+        &|input, option, string_value, idx| match crate::config::get_global_scorer() {
+            Some(scorer) => scorer(input, string_value, idx),
+            None => Self::DEFAULT_SCORER(input, option, string_value, idx),
+        };
+
+    /// Formatter calling the global formatter registered with
+    /// [set_global_formatter](crate::set_global_formatter).
+    const GLOBAL_FORMATTER: OptionFormatter<'a, T> =
+        &|ans| match crate::config::get_global_formatter() {
+            Some(formatter) => formatter(ListOption::new(ans.index, &ans.value.to_string())),
+            None => Self::DEFAULT_FORMATTER(ans),
+        };
+
+    /// Default show-all predicate, displaying all options when the filter
+    /// input is empty or only contains whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::Select;
+    ///
+    /// let show_all = Select::<&str>::DEFAULT_SHOW_ALL_PREDICATE;
+    /// assert!(show_all(""));
+    /// assert!(show_all("  "));
+    /// assert!(!show_all(" sa "));
+    /// ```
+    pub const DEFAULT_SHOW_ALL_PREDICATE: ShowAllPredicate<'a> = &|input| input.trim().is_empty();
+
+    /// Default filter trimming behaviour.
+    /// Defaults to true.
+    pub const DEFAULT_TRIM_FILTER: bool = true;
+
+    /// Default minimum length of the filter input before the options are
+    /// filtered.
+    /// Defaults to 0.
+    pub const DEFAULT_MIN_QUERY_LEN: usize = 0;
+
     /// Default page size.
     pub const DEFAULT_PAGE_SIZE: usize = crate::config::DEFAULT_PAGE_SIZE;
 
     /// Default value of vim mode.
     pub const DEFAULT_VIM_MODE: bool = crate::config::DEFAULT_VIM_MODE;
 
+    /// Default behaviour of Tab and Shift+Tab keys.
+    /// Defaults to false.
+    pub const DEFAULT_TAB_PAGING: bool = false;
+
+    /// Default access keys behaviour.
+    /// Defaults to false.
+    pub const DEFAULT_ACCESSKEYS: bool = false;
+
+    /// Default prefix auto-submit behaviour.
+    /// Defaults to false.
+    pub const DEFAULT_PREFIX_AUTOSUBMIT: bool = false;
+
+    /// Minimum length of the filter input before an option is auto-submitted
+    /// by [prefix](Select::with_prefix_autosubmit).
+    pub const PREFIX_AUTOSUBMIT_MIN_LEN: usize = 2;
+
+    /// Default hierarchical navigation behaviour.
+    /// Defaults to false.
+    pub const DEFAULT_HIERARCHICAL_NAV: bool = false;
+
+    /// Default type-ahead behaviour.
+    /// Defaults to false.
+    pub const DEFAULT_TYPEAHEAD: bool = false;
+
+    /// Default alternate screen behaviour.
+    /// Defaults to false.
+    pub const DEFAULT_ALTERNATE_SCREEN: bool = false;
+
+    /// Default TTY input behaviour.
+    /// Defaults to false.
+    pub const DEFAULT_TTY_INPUT: bool = false;
+
+    /// Default cancel on focus loss behaviour.
+    /// Defaults to false.
+    pub const DEFAULT_CANCEL_ON_FOCUS_LOSS: bool = false;
+
+    /// Default stdout echo behaviour.
+    /// Defaults to false.
+    pub const DEFAULT_STDOUT_ECHO: bool = false;
+
+    /// Default reverse layout behaviour.
+    /// Defaults to false.
+    pub const DEFAULT_REVERSE_LAYOUT: bool = false;
+
+    /// Default closure panics catching behaviour.
+    /// Defaults to false.
+    pub const DEFAULT_CATCH_CLOSURE_PANICS: bool = false;
+
+    /// Default prestyled options behaviour.
+    /// Defaults to false.
+    pub const DEFAULT_PRESTYLED_OPTIONS: bool = false;
+
+    /// Default behaviour of matching the filter input against the rendered options.
+    /// Defaults to false.
+    pub const DEFAULT_FILTER_ON_RENDERED: bool = false;
+
+    /// Default multiline options behaviour.
+    /// Defaults to false.
+    pub const DEFAULT_MULTILINE_OPTIONS: bool = false;
+
+    /// Default scrollbar behaviour.
+    /// Defaults to false.
+    pub const DEFAULT_SCROLLBAR: bool = false;
+
+    /// Default behaviour of rendering the prompt message as the border title.
+    /// Defaults to false.
+    pub const DEFAULT_BORDER_TITLE: bool = false;
+
+    /// Default behaviour of keeping the full prompt on screen once submitted.
+    /// Defaults to false.
+    pub const DEFAULT_PERSIST_FULL_RENDER: bool = false;
+
+    /// Default clipboard copy behaviour.
+    /// Defaults to false.
+    pub const DEFAULT_CLIPBOARD_COPY: bool = false;
+
+    /// Default hidden answer behaviour.
+    /// Defaults to false.
+    pub const DEFAULT_HIDDEN_ANSWER: bool = false;
+
+    /// Default show filter in answer behaviour.
+    /// Defaults to false.
+    pub const DEFAULT_SHOW_FILTER_IN_ANSWER: bool = false;
+
+    /// Default allow duplicates behaviour.
+    /// Defaults to true.
+    pub const DEFAULT_ALLOW_DUPLICATES: bool = true;
+
+    /// Default behaviour of Tab when a single option matches the filter input.
+    /// Defaults to false.
+    pub const DEFAULT_TAB_ACCEPTS_SINGLE: bool = false;
+
+    /// Default behaviour of submitting while no option matches the filter input.
+    /// Defaults to false.
+    pub const DEFAULT_ERROR_ON_EMPTY_SUBMIT: bool = false;
+
+    /// Default filter syntax highlighting behaviour.
+    /// Defaults to false.
+    pub const DEFAULT_FILTER_SYNTAX_HIGHLIGHT: bool = false;
+
+    /// Default search indicator behaviour.
+    /// Defaults to false.
+    pub const DEFAULT_SEARCH_INDICATOR: bool = false;
+
+    /// Default marking behaviour.
+    /// Defaults to false.
+    pub const DEFAULT_MARKING: bool = false;
+
+    /// Text displayed in place of the answer when it is hidden.
+    pub const HIDDEN_ANSWER_PLACEHOLDER: &'static str = "<hidden>";
+
+    /// Default layout.
+    pub const DEFAULT_LAYOUT: SelectLayout = SelectLayout::Vertical;
+
+    /// Default behaviour when submitting filter input that matches no option.
+    /// Defaults to false.
+    pub const DEFAULT_CREATE_NEW: bool = false;
+
     /// Default starting cursor index.
     pub const DEFAULT_STARTING_CURSOR: usize = 0;
 
+    /// Default behavior when the starting cursor index is out of bounds.
+    pub const DEFAULT_CURSOR_OVERFLOW: CursorOverflow = CursorOverflow::Error;
+
     /// Default cursor behaviour on filter input change.
     /// Defaults to true.
     pub const DEFAULT_RESET_CURSOR: bool = true;
@@ -197,22 +796,103 @@ where
     pub const DEFAULT_FILTER_INPUT_ENABLED: bool = true;
 
     /// Default help message.
+    ///
+    /// While this message is set, the prompt displays a variant of it that
+    /// matches the enabled key bindings, e.g. mentioning Space when marking
+    /// is enabled or the configured cancel key.
     pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
         Some("↑↓ to move, enter to select, type to filter");
 
+    /// Default help message of the horizontal layout, used in place of
+    /// [Select::DEFAULT_HELP_MESSAGE].
+    pub const DEFAULT_HORIZONTAL_HELP_MESSAGE: Option<&'a str> =
+        Some("←→ to move, enter to select");
+
+    /// Default help on demand behaviour.
+    /// Defaults to false.
+    pub const DEFAULT_HELP_ON_DEMAND: bool = false;
+
     /// Creates a [Select] with the provided message and options, along with default configuration values.
     pub fn new(message: &'a str, options: Vec<T>) -> Self {
         Self {
             message,
             options,
             help_message: Self::DEFAULT_HELP_MESSAGE,
+            help_suffix: None,
+            help_on_demand: Self::DEFAULT_HELP_ON_DEMAND,
             page_size: Self::DEFAULT_PAGE_SIZE,
-            vim_mode: Self::DEFAULT_VIM_MODE,
+            max_rows: None,
+            scroll_margin: None,
+            ensure_visible: None,
+            vim_mode: crate::config::get_vim_mode(),
+            tab_paging: Self::DEFAULT_TAB_PAGING,
+            hierarchical_nav: Self::DEFAULT_HIERARCHICAL_NAV,
+            accesskeys: Self::DEFAULT_ACCESSKEYS,
+            prefix_autosubmit: Self::DEFAULT_PREFIX_AUTOSUBMIT,
+            separator: None,
             starting_cursor: Self::DEFAULT_STARTING_CURSOR,
+            cursor_overflow: Self::DEFAULT_CURSOR_OVERFLOW,
+            recommended: None,
             reset_cursor: Self::DEFAULT_RESET_CURSOR,
             filter_input_enabled: Self::DEFAULT_FILTER_INPUT_ENABLED,
-            scorer: Self::DEFAULT_SCORER,
-            formatter: Self::DEFAULT_FORMATTER,
+            filter_label: None,
+            filter_placeholder: None,
+            title: None,
+            progress: None,
+            typeahead: Self::DEFAULT_TYPEAHEAD,
+            create_new: Self::DEFAULT_CREATE_NEW,
+            scorer: match crate::config::get_global_scorer() {
+                Some(_) => Self::GLOBAL_SCORER,
+                None => Self::DEFAULT_SCORER,
+            },
+            show_all_predicate: Self::DEFAULT_SHOW_ALL_PREDICATE,
+            trim_filter: Self::DEFAULT_TRIM_FILTER,
+            min_query_len: Self::DEFAULT_MIN_QUERY_LEN,
+            hidden_answer: Self::DEFAULT_HIDDEN_ANSWER,
+            show_filter_in_answer: Self::DEFAULT_SHOW_FILTER_IN_ANSWER,
+            allow_duplicates: Self::DEFAULT_ALLOW_DUPLICATES,
+            marking: Self::DEFAULT_MARKING,
+            tab_accepts_single: Self::DEFAULT_TAB_ACCEPTS_SINGLE,
+            error_on_empty_submit: Self::DEFAULT_ERROR_ON_EMPTY_SUBMIT,
+            filter_syntax_highlight: Self::DEFAULT_FILTER_SYNTAX_HIGHLIGHT,
+            search_indicator: Self::DEFAULT_SEARCH_INDICATOR,
+            max_filter_len: None,
+            cancel_key: None,
+            formatter: match crate::config::get_global_formatter() {
+                Some(_) => Self::GLOBAL_FORMATTER,
+                None => Self::DEFAULT_FORMATTER,
+            },
+            render_hook: None,
+            pagination_renderer: None,
+            footer: None,
+            on_highlight: None,
+            on_start: None,
+            key_filter: None,
+            lazy_description: None,
+            on_finish: None,
+            refresh: None,
+            confirm_indices: None,
+            loading_source: None,
+            alternate_screen: Self::DEFAULT_ALTERNATE_SCREEN,
+            tty_input: Self::DEFAULT_TTY_INPUT,
+            cancel_on_focus_loss: Self::DEFAULT_CANCEL_ON_FOCUS_LOSS,
+            stdout_echo: Self::DEFAULT_STDOUT_ECHO,
+            reverse_layout: Self::DEFAULT_REVERSE_LAYOUT,
+            catch_closure_panics: Self::DEFAULT_CATCH_CLOSURE_PANICS,
+            prestyled_options: Self::DEFAULT_PRESTYLED_OPTIONS,
+            filter_on_rendered: Self::DEFAULT_FILTER_ON_RENDERED,
+            multiline_options: Self::DEFAULT_MULTILINE_OPTIONS,
+            scrollbar: Self::DEFAULT_SCROLLBAR,
+            border: None,
+            border_title: Self::DEFAULT_BORDER_TITLE,
+            persist_full_render: Self::DEFAULT_PERSIST_FULL_RENDER,
+            clipboard_copy: Self::DEFAULT_CLIPBOARD_COPY,
+            escape_timeout: None,
+            layout: Self::DEFAULT_LAYOUT,
+            annotations: &[],
+            weights: &[],
+            icons: None,
+            status: None,
             render_config: get_configuration(),
             starting_filter_input: None,
         }
@@ -230,90 +910,1304 @@ where
         self
     }
 
+    /// Appends a hint to the help message, after a comma, such as
+    /// `"ctrl+r to reload"`. Unlike replacing the help message, the default
+    /// help message keeps matching the enabled key bindings. Nothing is
+    /// rendered when the help message is removed.
+    ///
+    /// ```
+    /// use inquire::Select;
+    ///
+    /// let select = Select::new("Fruit:", vec!["apple", "banana"])
+    ///     .with_help_suffix("ctrl+r to reload");
+    ///
+    /// assert_eq!(
+    ///     Some(String::from("↑↓ to move, enter to select, type to filter, ctrl+r to reload")),
+    ///     select.displayed_help_message(),
+    /// );
+    /// ```
+    pub fn with_help_suffix(mut self, help_suffix: &'a str) -> Self {
+        self.help_suffix = Some(help_suffix);
+        self
+    }
+
+    /// Returns the help message displayed with the current settings, if any:
+    /// the default help message matching the enabled key bindings while the
+    /// default is set, or the custom one, followed by the
+    /// [help suffix](Select::with_help_suffix).
+    pub fn displayed_help_message(&self) -> Option<String> {
+        let config = SelectConfig::from(self);
+        let filter_input_enabled =
+            self.filter_input_enabled && self.layout == SelectLayout::Vertical;
+
+        self.help_message.map(|help_message| {
+            keybindings::displayed_help_message(
+                help_message,
+                self.help_suffix,
+                &config,
+                filter_input_enabled,
+            )
+        })
+    }
+
+    /// Sets whether the help message is hidden until the user asks for it.
+    ///
+    /// When enabled, pressing `?` shows or hides the help message, and the
+    /// character is no longer inserted into the filter input.
+    pub fn with_help_on_demand(mut self, help_on_demand: bool) -> Self {
+        self.help_on_demand = help_on_demand;
+        self
+    }
+
     /// Sets the page size.
     pub fn with_page_size(mut self, page_size: usize) -> Self {
         self.page_size = page_size;
         self
     }
 
+    /// Sets the maximum number of terminal rows the prompt is allowed to use.
+    ///
+    /// The rows used by the prompt line and the help message are reserved first,
+    /// and the remaining ones cap the number of options displayed at once. The
+    /// configured page size is still respected when it is smaller, and at least
+    /// one option is always displayed.
+    pub fn with_max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = Some(max_rows);
+        self
+    }
+
+    /// Sets the scroll margin, the minimum number of options kept between
+    /// the cursor and the top and bottom edges of the page.
+    ///
+    /// The page scrolls as little as possible to respect the margin, which
+    /// shrinks when the cursor gets close to either end of the list. A margin
+    /// of 0 only scrolls the page when the cursor moves past its edges.
+    pub fn with_scroll_margin(mut self, scroll_margin: usize) -> Self {
+        self.scroll_margin = Some(scroll_margin);
+        self
+    }
+
+    /// Sets the index of an option, in the original list, to display on the
+    /// first page, e.g. next to the option a preview refers to.
+    ///
+    /// The first page is scrolled as little as needed to show the option, as
+    /// long as the cursor stays visible, and away from the page edges when a
+    /// [scroll margin](Select::with_scroll_margin) is set. Once the cursor
+    /// moves, the page scrolls as configured again.
+    pub fn with_ensure_visible(mut self, ensure_visible: usize) -> Self {
+        self.ensure_visible = Some(ensure_visible);
+        self
+    }
+
     /// Enables or disables vim_mode.
     pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
         self.vim_mode = vim_mode;
         self
     }
 
-    /// Sets the scoring function.
-    pub fn with_scorer(mut self, scorer: Scorer<'a, T>) -> Self {
-        self.scorer = scorer;
+    /// Enables or disables page navigation with Tab and Shift+Tab.
+    ///
+    /// When enabled, Tab moves the cursor one page down and Shift+Tab moves it
+    /// one page up, as an alternative to the PageDown and PageUp keys.
+    /// Disabled by default.
+    pub fn with_tab_paging(mut self, tab_paging: bool) -> Self {
+        self.tab_paging = tab_paging;
         self
     }
 
-    /// Sets the formatter.
-    pub fn with_formatter(mut self, formatter: OptionFormatter<'a, T>) -> Self {
-        self.formatter = formatter;
+    /// Enables or disables navigation of hierarchical menus.
+    ///
+    /// When enabled, Right submits the highlighted option, just like enter,
+    /// and Left ends the prompt with an [InquireError::NavigateBack] error,
+    /// which callers chaining prompts can use to display the parent menu
+    /// again. With vim mode, `l` and `h` behave the same way.
+    ///
+    /// Left and Right no longer move the cursor of the filter input.
+    pub fn with_hierarchical_nav(mut self, hierarchical_nav: bool) -> Self {
+        self.hierarchical_nav = hierarchical_nav;
         self
     }
 
-    /// Sets the starting cursor index.
+    /// Enables or disables selection by access keys.
     ///
-    /// This index might be overridden if the `reset_cursor` option is set to true (default)
-    /// and starting_filter_input is set to something other than None.
-    pub fn with_starting_cursor(mut self, starting_cursor: usize) -> Self {
-        self.starting_cursor = starting_cursor;
+    /// When enabled, the first letter of each option is underlined and the
+    /// filter input matches options by their prefix, case-insensitively,
+    /// instead of using the scorer. As soon as a single option matches the
+    /// typed characters, it is submitted without the user pressing enter.
+    /// When several options share a prefix, they are narrowed down until
+    /// the match is unique.
+    ///
+    /// Has no effect when filtering is disabled.
+    pub fn with_accesskeys(mut self, accesskeys: bool) -> Self {
+        self.accesskeys = accesskeys;
         self
     }
 
-    /// Sets the starting filter input
-    pub fn with_starting_filter_input(mut self, starting_filter_input: &'a str) -> Self {
-        self.starting_filter_input = Some(starting_filter_input);
+    /// Enables or disables submitting the option uniquely identified by the
+    /// filter input, e.g. for command palettes whose users know the names of
+    /// the commands.
+    ///
+    /// Unlike [access keys](Select::with_accesskeys), the options are still
+    /// filtered by the scorer. Once at least
+    /// [two characters](Select::PREFIX_AUTOSUBMIT_MIN_LEN) are typed and the
+    /// filter input is a case-insensitive prefix of a single option, that
+    /// option is submitted without the user pressing enter.
+    ///
+    /// Has no effect when filtering is disabled.
+    pub fn with_prefix_autosubmit(mut self, prefix_autosubmit: bool) -> Self {
+        self.prefix_autosubmit = prefix_autosubmit;
         self
     }
 
-    /// Sets the reset_cursor behaviour. Defaults to true.
+    /// Sets the character used to draw a horizontal rule between the list
+    /// of options and the help message.
     ///
-    /// When there's an input change that results in a different list of options being displayed,
-    /// whether by filtering or re-ordering, the cursor will be reset to highlight the first option.
-    pub fn with_reset_cursor(mut self, reset_cursor: bool) -> Self {
-        self.reset_cursor = reset_cursor;
+    /// The rule spans the whole terminal width and is only drawn when the
+    /// help message is displayed.
+    pub fn with_separator(mut self, separator: char) -> Self {
+        self.separator = Some(separator);
         self
     }
 
-    /// Disables the filter input, which means the user will not be able to filter the options
-    /// by typing.
-    ///
-    /// This is useful when you want to simplify the UX if the filter does not add any value,
-    /// such as when the list is already short.
-    pub fn without_filtering(mut self) -> Self {
-        self.filter_input_enabled = false;
+    /// Sets the scoring function.
+    pub fn with_scorer(mut self, scorer: Scorer<'a, T>) -> Self {
+        self.scorer = scorer;
         self
     }
 
-    /// Sets the provided color theme to this prompt.
+    /// Sets the predicate deciding whether the filter input displays all
+    /// options in their original order, skipping the scorer.
     ///
-    /// Note: The default render config considers if the NO_COLOR environment variable
-    /// is set to decide whether to render the colored config or the empty one.
+    /// By default, empty and whitespace-only inputs display all options, so
+    /// a [custom scorer](Select::with_scorer) is not called for them. Use
+    /// `&|_| false` to score every input, as before this predicate existed.
     ///
-    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
-    /// config is treated as the only source of truth. If you want to customize colors
-    /// and still support NO_COLOR, you will have to do this on your end.
-    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
-        self.render_config = render_config;
+    /// A sentinel such as `*` can be added with:
+    ///
+    /// ```
+    /// use inquire::Select;
+    ///
+    /// let select = Select::new("Fruit:", vec!["Banana", "Apple"])
+    ///     .with_show_all_predicate(&|input| input.trim().is_empty() || input == "*");
+    ///
+    /// assert_eq!(vec![0, 1], select.filtered_indices("*"));
+    /// ```
+    pub fn with_show_all_predicate(mut self, show_all_predicate: ShowAllPredicate<'a>) -> Self {
+        self.show_all_predicate = show_all_predicate;
         self
     }
 
-    /// Parses the provided behavioral and rendering options and prompts
-    /// the CLI user for input according to the defined rules.
+    /// Sets whether leading and trailing whitespace of the filter input is
+    /// ignored, so that " apple" matches "apple". Enabled by default.
     ///
-    /// Returns the owned object selected by the user.
-    pub fn prompt(self) -> InquireResult<T> {
-        self.raw_prompt().map(|op| op.value)
+    /// The trimmed input is also the one passed to the scorer and to the
+    /// show-all predicate, so an input made of whitespace only displays all
+    /// options.
+    pub fn with_trimmed_filter(mut self, trim_filter: bool) -> Self {
+        self.trim_filter = trim_filter;
+        self
     }
 
-    /// Parses the provided behavioral and rendering options and prompts
-    /// the CLI user for input according to the defined rules.
+    /// Sets the minimum number of characters of the filter input before the
+    /// options are filtered, so that short queries on huge lists, or on
+    /// expensive sources, display all options instead.
     ///
-    /// This method is intended for flows where the user skipping/cancelling
-    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// Deleting characters back below the minimum displays all options again.
+    ///
+    /// ```
+    /// use inquire::Select;
+    ///
+    /// let select = Select::new("Package:", vec!["serde", "tokio", "rand"])
+    ///     .with_min_query_len(2);
+    ///
+    /// assert_eq!(vec![0, 1, 2], select.filtered_indices("s"));
+    /// assert_eq!(vec![0], select.filtered_indices("se"));
+    /// ```
+    pub fn with_min_query_len(mut self, min_query_len: usize) -> Self {
+        self.min_query_len = min_query_len;
+        self
+    }
+
+    /// Sets whether the submitted answer is hidden once the prompt is
+    /// finished, displaying [Select::HIDDEN_ANSWER_PLACEHOLDER] instead of
+    /// the formatted option. Useful when the selection itself is sensitive.
+    ///
+    /// The answer returned by the prompt is not affected.
+    pub fn with_hidden_answer(mut self, hidden_answer: bool) -> Self {
+        self.hidden_answer = hidden_answer;
+        self
+    }
+
+    /// Sets whether the filter input typed to find the answer is displayed
+    /// before it once the prompt is finished, e.g.
+    /// `What's your fruit? [typed 'wat'] → Watermelon`, to keep track of how
+    /// the answer was found in transcripts.
+    ///
+    /// Nothing is added when the filter input is empty, and hidden answers
+    /// stay hidden.
+    pub fn with_show_filter_in_answer(mut self, show_filter_in_answer: bool) -> Self {
+        self.show_filter_in_answer = show_filter_in_answer;
+        self
+    }
+
+    /// Sets whether several options may share the same string value. When
+    /// disallowed, the prompt fails with an
+    /// [InquireError::InvalidConfiguration] error if two of the options
+    /// provided are displayed the same, which is useful for programmatically
+    /// built lists that should not contain duplicates. Options replaced by a
+    /// [refresher](Select::with_refresh) or a
+    /// [loading source](Select::with_loading_source) are checked as well.
+    ///
+    /// When allowed, duplicates are told apart by the index of the answer.
+    pub fn with_allow_duplicates(mut self, allow_duplicates: bool) -> Self {
+        self.allow_duplicates = allow_duplicates;
+        self
+    }
+
+    /// Sets whether Space marks or unmarks the highlighted option, flagging
+    /// it with the
+    /// [marked option indicator](crate::ui::RenderConfig::marked_option_indicator).
+    ///
+    /// Enter still submits the highlighted option as the answer, while the
+    /// marked options are returned along with it by [Select::prompt_with_marks].
+    /// Space can no longer be typed in the filter input.
+    pub fn with_marking(mut self, marking: bool) -> Self {
+        self.marking = marking;
+        self
+    }
+
+    /// Sets the function providing the icon rendered before each option,
+    /// right after the cursor prefix, such as a file type glyph or a status
+    /// badge.
+    ///
+    /// The function receives the index of the option in the original list
+    /// and its string value. Icons are padded to the widest icon of the page,
+    /// so that the labels stay aligned when some options have no icon.
+    pub fn with_icons(mut self, icons: IconProvider<'a>) -> Self {
+        self.icons = Some(icons);
+        self
+    }
+
+    /// Sets the function providing the status of each option, rendered as an
+    /// indicator before the option, and its icon if any, such as a green
+    /// check mark for a running service.
+    ///
+    /// The function receives the index of the option in the original list.
+    /// It is called for the options of the page on every frame, so that the
+    /// indicators reflect live states, e.g. along with
+    /// [Select::with_refresh]. The indicators are set by
+    /// [RenderConfig::with_option_status] and padded to the widest one of
+    /// the page.
+    pub fn with_status(mut self, status: StatusProvider<'a>) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the formatter.
+    pub fn with_formatter(mut self, formatter: OptionFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Sets the render hook, called on every frame with the current state
+    /// of the prompt. The returned lines are rendered right after the list
+    /// of options.
+    pub fn with_render_hook(mut self, render_hook: RenderHook<'a, T>) -> Self {
+        self.render_hook = Some(render_hook);
+        self
+    }
+
+    /// Sets the pagination renderer, called on every frame with the page of
+    /// options displayed to the user. The returned lines are rendered right
+    /// after the options, before the lines of the render hook, such as to
+    /// draw a scrollbar or a custom "more options" indicator.
+    ///
+    /// The renderer is not called in the horizontal layout.
+    pub fn with_pagination_renderer(
+        mut self,
+        pagination_renderer: PaginationRenderer<'a, T>,
+    ) -> Self {
+        self.pagination_renderer = Some(pagination_renderer);
+        self
+    }
+
+    /// Sets the footer renderer, called on every frame with aggregate counts
+    /// of the prompt, such as the number of options hidden by the filter. The
+    /// returned line is rendered last, below the help message, with the
+    /// [footer style sheet](crate::ui::RenderConfig::footer).
+    ///
+    /// ```no_run
+    /// use inquire::Select;
+    ///
+    /// let fruit = Select::new("Fruit:", vec!["Apple", "Banana", "Cherry"])
+    ///     .with_footer(&|ctx| {
+    ///         format!("{} options • {} hidden by filter", ctx.total_options, ctx.hidden_options)
+    ///     })
+    ///     .prompt()?;
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn with_footer(mut self, footer: FooterRenderer<'a>) -> Self {
+        self.footer = Some(footer);
+        self
+    }
+
+    /// Sets the function called whenever a different option becomes
+    /// highlighted, such as to update a preview of the option.
+    ///
+    /// The function is called once for the option highlighted when the prompt
+    /// starts, then once every time the cursor moves or the filter input
+    /// changes the highlighted option. Moves that keep the same option
+    /// highlighted do not call it.
+    pub fn with_on_highlight(mut self, on_highlight: HighlightHook<'a, T>) -> Self {
+        self.on_highlight = Some(on_highlight);
+        self
+    }
+
+    /// Sets the function describing the highlighted option, rendered below
+    /// the list of options, such as details fetched from a remote service.
+    ///
+    /// The description is computed only when an option is first highlighted
+    /// and cached for the rest of the prompt, or until the options are
+    /// replaced. An ellipsis is rendered in its place until it is computed.
+    pub fn with_lazy_description(mut self, describe: DescriptionProvider<'a, T>) -> Self {
+        self.lazy_description = Some(describe);
+        self
+    }
+
+    /// Sets the function called with every pressed key before the prompt
+    /// handles it, returning the key to handle in its place or `None` to
+    /// drop it, such as to translate Tab to Down or to ignore keys sent by an
+    /// unusual terminal.
+    ///
+    /// The function runs before any other key handling, the keys submitting,
+    /// canceling and interrupting the prompt included.
+    pub fn with_key_filter(mut self, key_filter: KeyFilter<'a>) -> Self {
+        self.key_filter = Some(key_filter);
+        self
+    }
+
+    /// Sets the function called when the prompt becomes interactive, once
+    /// the terminal is set up and right before the prompt is first rendered,
+    /// such as to log that the prompt opened or to start a timer.
+    ///
+    /// The function is called before the [highlight hook](Select::with_on_highlight)
+    /// is first called.
+    pub fn on_start(mut self, on_start: StartHook<'a>) -> Self {
+        self.on_start = Some(on_start);
+        self
+    }
+
+    /// Sets the function called with the result of the prompt once it is
+    /// finished, whether it was submitted, canceled or failed, to integrate
+    /// the prompt into a larger state machine without wrapping every call
+    /// site.
+    pub fn on_finish(mut self, on_finish: FinishHook<'a, T>) -> Self {
+        self.on_finish = Some(on_finish);
+        self
+    }
+
+    /// Sets the indexes of the options whose submission must be confirmed,
+    /// such as destructive entries of a menu, along with the message asking
+    /// for the confirmation, while the other options are submitted right
+    /// away.
+    ///
+    /// Submitting one of these options displays the message below the
+    /// options, and the option is only submitted when Enter is pressed again.
+    /// Any other key, Esc included, dismisses the confirmation.
+    ///
+    /// ```no_run
+    /// use inquire::Select;
+    ///
+    /// let action = Select::new("Action:", vec!["Back up", "Restore", "Delete everything"])
+    ///     .with_confirm_indices(&[2], "Press enter again to delete everything")
+    ///     .prompt()?;
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn with_confirm_indices(mut self, indices: &'a [usize], message: &'a str) -> Self {
+        self.confirm_indices = Some((indices, message));
+        self
+    }
+
+    /// Sets a function called every `interval` to replace the options while
+    /// the prompt is displayed, such as when listing data that changes over
+    /// time.
+    ///
+    /// The current filter input is applied to the new options, and the
+    /// cursor stays on the highlighted option when it is still available,
+    /// which is determined by comparing the string values of the options.
+    pub fn with_refresh(mut self, refresher: Refresher<'a, T>, interval: Duration) -> Self {
+        self.refresh = Some((refresher, interval));
+        self
+    }
+
+    /// Sets a function loading the options on a worker thread, for options
+    /// that come from a slow source such as the network.
+    ///
+    /// A loading indicator is displayed until the function returns, then
+    /// the returned options replace the ones provided to [Select::new],
+    /// which can be empty. The [starting cursor](Select::with_starting_cursor)
+    /// is then applied to them, clamped to the last one. Canceling the prompt
+    /// while loading returns
+    /// immediately, without waiting for the function. The prompt fails with
+    /// an [InquireError::InvalidConfiguration] error if no options are
+    /// loaded.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use inquire::Select;
+    ///
+    /// let ans = Select::new("Repository:", vec![])
+    ///     .with_loading_source(|| {
+    ///         // e.g. a network request
+    ///         vec![String::from("inquire"), String::from("crossterm")]
+    ///     })
+    ///     .prompt();
+    /// ```
+    pub fn with_loading_source<F>(mut self, source: F) -> Self
+    where
+        F: Fn() -> Vec<T> + Send + Sync + 'static,
+        T: Send + 'static,
+    {
+        let source = Arc::new(source);
+
+        self.loading_source = Some(Rc::new(move || {
+            let (sender, receiver) = mpsc::channel();
+            let source = Arc::clone(&source);
+
+            thread::spawn(move || {
+                // The prompt may be gone already, such as when canceled.
+                let _unused = sender.send(source());
+            });
+
+            receiver
+        }));
+        self
+    }
+
+    /// Sets the starting cursor index.
+    ///
+    /// This index might be overridden if the `reset_cursor` option is set to true (default)
+    /// and starting_filter_input is set to something other than None.
+    pub fn with_starting_cursor(mut self, starting_cursor: usize) -> Self {
+        self.starting_cursor = starting_cursor;
+        self
+    }
+
+    /// Sets the starting cursor index to the first option matching the
+    /// provided predicate, which receives the index of each option and its
+    /// string value. Useful when the current option is identified by a
+    /// property rather than by its position.
+    ///
+    /// The options are scanned when this method is called, so the options
+    /// replaced later by a refresher or a loading source are not considered.
+    /// When no option matches, the cursor starts on the first option.
+    ///
+    /// ```
+    /// use inquire::Select;
+    ///
+    /// let select = Select::new("Branch:", vec!["main", "* feature", "fix"])
+    ///     .with_starting_cursor_where(|_, branch| branch.starts_with('*'));
+    ///
+    /// assert_eq!(1, select.starting_cursor);
+    /// ```
+    pub fn with_starting_cursor_where<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(usize, &str) -> bool,
+    {
+        self.starting_cursor = self
+            .options
+            .iter()
+            .enumerate()
+            .position(|(index, option)| predicate(index, &option.to_string()))
+            .unwrap_or(Self::DEFAULT_STARTING_CURSOR);
+        self
+    }
+
+    /// Sets whether pressing Tab submits the option right away when the
+    /// filter input narrows the options down to a single one, saving the
+    /// Enter keystroke.
+    ///
+    /// When several options match, Tab keeps its usual behaviour, moving the
+    /// cursor one page down if [tab paging](Select::with_tab_paging) is
+    /// enabled. Has no effect when filtering is disabled.
+    pub fn with_tab_accepts_single(mut self, tab_accepts_single: bool) -> Self {
+        self.tab_accepts_single = tab_accepts_single;
+        self
+    }
+
+    /// Sets whether submitting the prompt while the filter input matches
+    /// none of the options fails with [InquireError::NoMatchingOption],
+    /// instead of being ignored, so that the caller can decide what to do,
+    /// such as prompting again or falling back to a default.
+    ///
+    /// Created answers take precedence when [Select::with_create_new] is
+    /// enabled. Has no effect when filtering is disabled.
+    pub fn with_error_on_empty_submit(mut self, error_on_empty_submit: bool) -> Self {
+        self.error_on_empty_submit = error_on_empty_submit;
+        self
+    }
+
+    /// Flags the option at the provided index as recommended, which is
+    /// rendered with the
+    /// [recommended option indicator](crate::ui::RenderConfig::recommended_option_indicator),
+    /// `(recommended)` by default.
+    ///
+    /// The cursor is not moved to the recommended option, which can be done
+    /// with [Select::with_starting_cursor].
+    pub fn with_recommended(mut self, recommended: usize) -> Self {
+        self.recommended = Some(recommended);
+        self
+    }
+
+    /// Sets the behavior when the starting cursor index is out of bounds,
+    /// such as when restoring a previously saved position into a list that
+    /// has since shrunk.
+    ///
+    /// By default, the prompt fails with an [InquireError::InvalidConfiguration]
+    /// error.
+    pub fn with_cursor_overflow(mut self, cursor_overflow: CursorOverflow) -> Self {
+        self.cursor_overflow = cursor_overflow;
+        self
+    }
+
+    /// Sets the starting filter input
+    pub fn with_starting_filter_input(mut self, starting_filter_input: &'a str) -> Self {
+        self.starting_filter_input = Some(starting_filter_input);
+        self
+    }
+
+    /// Sets the reset_cursor behaviour. Defaults to true.
+    ///
+    /// When there's an input change that results in a different list of options being displayed,
+    /// whether by filtering or re-ordering, the cursor will be reset to highlight the first option.
+    pub fn with_reset_cursor(mut self, reset_cursor: bool) -> Self {
+        self.reset_cursor = reset_cursor;
+        self
+    }
+
+    /// Disables the filter input, which means the user will not be able to filter the options
+    /// by typing.
+    ///
+    /// This is useful when you want to simplify the UX if the filter does not add any value,
+    /// such as when the list is already short.
+    pub fn without_filtering(mut self) -> Self {
+        self.filter_input_enabled = false;
+        self
+    }
+
+    /// Sets a title rendered on its own line above the prompt message, styled
+    /// with the [title style sheet](crate::ui::RenderConfig::title), such as
+    /// `"Step 2 of 5: Choose region"` in multi-step flows.
+    ///
+    /// The title is only displayed while the prompt is active, the answered
+    /// prompt being rendered alone once it is finished.
+    pub fn with_title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the progress of a multi-step flow, rendered as a row of
+    /// indicators above the prompt, such as `●●○○ 2/4` for the second of
+    /// four steps. The indicators are set in the render config with
+    /// [RenderConfig::with_progress_indicators].
+    ///
+    /// `current` is the step of this prompt, starting at 1. Like the
+    /// [title](Select::with_title), the progress is only displayed while the
+    /// prompt is active.
+    pub fn with_progress(mut self, current: usize, total: usize) -> Self {
+        self.progress = Some((current, total));
+        self
+    }
+
+    /// Sets whether the tokens of the filter syntax typed in the filter input
+    /// are highlighted with the
+    /// [filter syntax style sheet](crate::ui::RenderConfig::filter_syntax):
+    /// quoted phrases, quotes included, and `!` operators at the start of a
+    /// word, as commonly used to exclude matches.
+    ///
+    /// Highlighting does not change how options are matched, which is up to
+    /// the [scorer](Select::with_scorer). Unterminated quotes are highlighted
+    /// up to the end of the input.
+    pub fn with_filter_syntax_highlight(mut self, filter_syntax_highlight: bool) -> Self {
+        self.filter_syntax_highlight = filter_syntax_highlight;
+        self
+    }
+
+    /// Sets whether the [search indicator](crate::ui::RenderConfig::search_indicator),
+    /// `/` by default as in vim searches, is prepended to the filter input
+    /// while it is not empty, making it obvious whether the user is browsing
+    /// or searching the options.
+    pub fn with_search_indicator(mut self, search_indicator: bool) -> Self {
+        self.search_indicator = search_indicator;
+        self
+    }
+
+    /// Limits the filter input to the provided number of characters, ignoring
+    /// the characters typed beyond the limit. A starting filter input longer
+    /// than the limit is kept as is.
+    pub fn with_max_filter_len(mut self, max_filter_len: usize) -> Self {
+        self.max_filter_len = Some(max_filter_len);
+        self
+    }
+
+    /// Sets the key canceling the prompt, for setups where Esc is awkward to
+    /// reach or is intercepted by the terminal.
+    ///
+    /// The provided key replaces Esc, which is then ignored. Ctrl+G and Ctrl+D
+    /// still cancel the prompt.
+    pub fn with_cancel_key(mut self, cancel_key: Key) -> Self {
+        self.cancel_key = Some(cancel_key);
+        self
+    }
+
+    /// Displays the filter input on a dedicated line below the prompt message,
+    /// after the provided label, e.g. `"Search:"`.
+    ///
+    /// This makes it clearer that typing filters the options. Has no effect
+    /// when filtering is disabled.
+    pub fn with_filter_label(mut self, filter_label: &'a str) -> Self {
+        self.filter_label = Some(filter_label);
+        self
+    }
+
+    /// Sets a hint displayed in the filter input while it is empty, such as
+    /// `"Type to filter…"`, styled by [RenderConfig::placeholder]. The
+    /// hint disappears as soon as the user types and is never part of the
+    /// filter input. Has no effect when filtering is disabled.
+    pub fn with_filter_placeholder(mut self, filter_placeholder: &'a str) -> Self {
+        self.filter_placeholder = Some(filter_placeholder);
+        self
+    }
+
+    /// Enables or disables type-ahead navigation when filtering is disabled.
+    ///
+    /// When enabled, typing a letter moves the cursor to the next option
+    /// starting with it, and pressing the same letter again cycles through
+    /// the matching options. Letters typed in quick succession form a
+    /// prefix, so typing "ba" quickly jumps to "Banana".
+    ///
+    /// Has no effect unless filtering is disabled with [Select::without_filtering].
+    pub fn with_typeahead(mut self, typeahead: bool) -> Self {
+        self.typeahead = typeahead;
+        self
+    }
+
+    /// Sets whether the prompt is displayed in the alternate screen buffer of
+    /// the terminal, anchored to its top, for a full-screen experience.
+    ///
+    /// The previous content of the terminal is restored once the prompt
+    /// finishes, whether it was submitted, canceled or failed. As the final
+    /// answer is rendered in the alternate screen, it is not left visible in
+    /// the terminal.
+    pub fn with_alternate_screen(mut self, alternate_screen: bool) -> Self {
+        self.alternate_screen = alternate_screen;
+        self
+    }
+
+    /// Sets whether the prompt reads keys from and renders to the controlling
+    /// terminal directly, as `git` does, so that it stays interactive when
+    /// the standard streams are redirected, e.g. in `cat list | my-tool`.
+    ///
+    /// With the crossterm backend, the prompt is rendered to `/dev/tty` on
+    /// Unix and to `CONOUT$` on Windows instead of stderr, while keys are
+    /// read from `/dev/tty` or `CONIN$` whenever stdin is not a terminal.
+    /// The termion backend always uses the controlling terminal, and the
+    /// console backend always renders to stderr, ignoring this setting.
+    pub fn with_tty_input(mut self, tty_input: bool) -> Self {
+        self.tty_input = tty_input;
+        self
+    }
+
+    /// Sets whether the prompt is canceled when the terminal window loses
+    /// focus, as if the user had pressed ESC.
+    ///
+    /// Focus reporting is enabled on the terminal while the prompt runs. Only
+    /// the crossterm backend, and terminals that support focus reporting,
+    /// deliver focus events; with other backends this setting has no effect.
+    pub fn with_cancel_on_focus_loss(mut self, cancel_on_focus_loss: bool) -> Self {
+        self.cancel_on_focus_loss = cancel_on_focus_loss;
+        self
+    }
+
+    /// Sets whether the options are rendered above the prompt line, with the
+    /// first option right above it and the list growing upward, as in pickers
+    /// anchored to the bottom of the screen.
+    ///
+    /// The arrow keys follow the rendered direction, so Up moves the cursor to
+    /// the option displayed above. Has no effect on the
+    /// [horizontal layout](SelectLayout::Horizontal).
+    pub fn with_reverse_layout(mut self, reverse_layout: bool) -> Self {
+        self.reverse_layout = reverse_layout;
+        self
+    }
+
+    /// Sets whether Ctrl+Y, or `y` in vim mode, copies the highlighted option
+    /// to the system clipboard without submitting the prompt. A short
+    /// notice is displayed below the options until the next key press.
+    ///
+    /// Copying requires the `clipboard` feature, which hands the option over
+    /// to the clipboard utility of the platform, such as `pbcopy`, `clip`,
+    /// `wl-copy`, `xclip` or `xsel`. Without the feature, or when none of
+    /// these utilities is available, the bell is rung instead.
+    pub fn with_clipboard_copy(mut self, clipboard_copy: bool) -> Self {
+        self.clipboard_copy = clipboard_copy;
+        self
+    }
+
+    /// Sets how long to wait for the rest of an escape sequence, such as the
+    /// one sent by an arrow key, after reading a lone Esc.
+    ///
+    /// Over high-latency connections, a sequence can be split across reads and
+    /// decoded as Esc followed by plain characters, cancelling the prompt. With
+    /// a timeout, the characters arriving in time are reassembled into the
+    /// key they encode, and Esc only cancels once the timeout elapses.
+    pub fn with_escape_timeout(mut self, escape_timeout: Duration) -> Self {
+        self.escape_timeout = Some(escape_timeout);
+        self
+    }
+
+    /// Restores the state of a previous prompt, returned by
+    /// [Select::prompt_with_state], setting the starting cursor on the option
+    /// that was highlighted and the starting filter input to the one that
+    /// was typed.
+    pub fn with_state(mut self, state: &'a SelectState) -> Self {
+        if let Some(highlighted) = state.highlighted {
+            self.starting_cursor = highlighted;
+        }
+        if !state.filter_input.is_empty() {
+            self.starting_filter_input = Some(&state.filter_input);
+        }
+        self
+    }
+
+    /// Sets whether options whose string value spans several lines, such as
+    /// a commit message preview, are rendered as blocks. The following lines
+    /// are aligned below the first one and styled the same, so the whole
+    /// block is highlighted along with the option.
+    ///
+    /// Navigation moves between options and the page size counts options,
+    /// not lines. When a row limit is set with [Select::with_max_rows], the
+    /// page holds as many options as fit in the rows left for them, summing
+    /// their lines.
+    pub fn with_multiline_options(mut self, multiline_options: bool) -> Self {
+        self.multiline_options = multiline_options;
+        self
+    }
+
+    /// Sets whether a one-column scrollbar is rendered at the right edge of
+    /// the terminal, next to the options, giving feedback on the position in
+    /// long lists. The size of its thumb reflects the share of the filtered
+    /// options displayed on the page and its position reflects the cursor.
+    ///
+    /// No scrollbar is rendered when all the filtered options fit the page.
+    /// Its characters are set by [RenderConfig::with_scrollbar].
+    pub fn with_scrollbar(mut self, scrollbar: bool) -> Self {
+        self.scrollbar = scrollbar;
+        self
+    }
+
+    /// Sets the style of a border framing the options, drawn with box-drawing
+    /// characters across the width of the terminal. Options too wide to fit
+    /// inside the border are cut, with an ellipsis at their end.
+    ///
+    /// The border is only drawn in the vertical layout. Its style sheet is
+    /// set by [RenderConfig::with_border].
+    pub fn with_border(mut self, style: BorderStyle) -> Self {
+        self.border = Some(style);
+        self
+    }
+
+    /// Sets whether the prompt message is rendered as the title of the
+    /// border set by [Select::with_border], as in `┌─ Fruit ─┐`, instead of
+    /// on the prompt line. The title is cut to fit the terminal width.
+    pub fn with_border_title(mut self, border_title: bool) -> Self {
+        self.border_title = border_title;
+        self
+    }
+
+    /// Sets whether the last frame of the prompt, with the list of options
+    /// and the submitted option highlighted, is kept on screen once the
+    /// prompt is submitted, instead of collapsing it into the prompt message
+    /// and the formatted answer. Useful to build visible transcripts, such as
+    /// in demo recordings and tutorials.
+    ///
+    /// Canceled prompts are still collapsed, and so are prompts with a
+    /// [hidden answer](Select::with_hidden_answer), so that the submitted
+    /// option is not left on screen.
+    pub fn with_persist_full_render(mut self, persist_full_render: bool) -> Self {
+        self.persist_full_render = persist_full_render;
+        self
+    }
+
+    /// Sets whether the options are prestyled, i.e. their `Display`
+    /// implementation already contains ANSI escape sequences, such as when
+    /// listing the colored output of another tool.
+    ///
+    /// The styles are reset after each option, so that they do not leak into
+    /// the rest of the prompt, and the escape sequences are ignored when
+    /// filtering the options. The access keys of prestyled options are not
+    /// underlined.
+    pub fn with_prestyled_options(mut self, prestyled_options: bool) -> Self {
+        self.prestyled_options = prestyled_options;
+        self
+    }
+
+    /// Sets whether the filter input is matched against the text of the
+    /// options as rendered in the list, with their [icon](Select::with_icons)
+    /// and [annotation](Select::with_aligned_annotations), instead of their string
+    /// values, so that users can search for what they see.
+    ///
+    /// The rendered text is computed once, when the prompt starts or its
+    /// options are replaced, and is the string value received by the scorer.
+    pub fn with_filter_on_rendered(mut self, filter_on_rendered: bool) -> Self {
+        self.filter_on_rendered = filter_on_rendered;
+        self
+    }
+
+    /// Sets whether panics raised by the provided closures, such as the
+    /// [scorer](Select::with_scorer) or the [formatter](Select::with_formatter),
+    /// are caught and returned as [InquireError::Custom] errors instead of
+    /// unwinding through the caller.
+    ///
+    /// The panic hook still runs, printing the panic message by default. Only
+    /// unwinding panics can be caught, not those aborting the process.
+    pub fn with_catch_closure_panics(mut self, catch_closure_panics: bool) -> Self {
+        self.catch_closure_panics = catch_closure_panics;
+        self
+    }
+
+    /// Sets whether the submitted answer is written to the standard output, on
+    /// its own line and without any styling, once the prompt is finished.
+    ///
+    /// The prompt itself is rendered to stderr, so this allows capturing the
+    /// answer in shell scripts, e.g. `VALUE=$(mytool)`. The answer is written
+    /// after the terminal has left raw mode, using the `Display` implementation
+    /// of the option rather than the [formatter](Select::with_formatter).
+    pub fn with_stdout_echo(mut self, stdout_echo: bool) -> Self {
+        self.stdout_echo = stdout_echo;
+        self
+    }
+
+    /// Sets the layout of the options.
+    ///
+    /// With [SelectLayout::Horizontal], the prompt takes a single line
+    /// showing only the highlighted option, and the left and right arrow
+    /// keys cycle through the options. Filtering is disabled in this layout,
+    /// and the default help message is replaced by
+    /// [Select::DEFAULT_HORIZONTAL_HELP_MESSAGE].
+    pub fn with_layout(mut self, layout: SelectLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Sets annotations displayed next to the options, as pairs of option
+    /// labels and annotations.
+    ///
+    /// An annotation is displayed next to the option whose string value is
+    /// equal to its label. Annotations are aligned in a column right after
+    /// the widest option of the current page, as in subcommand menus:
+    ///
+    /// ```text
+    /// > deploy    Deploys the app
+    ///   rollback  Reverts the last deployment
+    /// ```
+    pub fn with_aligned_annotations(mut self, annotations: &'a [(&'a str, &'a str)]) -> Self {
+        self.annotations = annotations;
+        self
+    }
+
+    /// Sets the weights of the options, in the same order as the options,
+    /// such as usage frequencies, so that the options with the highest
+    /// weights are listed first before any filtering. Options without a
+    /// weight have a weight of zero.
+    ///
+    /// When filtering, options with the same score are ranked by descending
+    /// weight. The returned answers keep the index of the option in the
+    /// original list, and the [starting cursor](Select::with_starting_cursor)
+    /// is a position in the ranked list.
+    ///
+    /// ```
+    /// use inquire::Select;
+    ///
+    /// let usage = [1.0, 12.0, 5.0];
+    /// let select = Select::new("Command:", vec!["init", "build", "test"]).with_weights(&usage);
+    ///
+    /// assert_eq!(vec![1, 2, 0], select.filtered_indices(""));
+    /// ```
+    pub fn with_weights(mut self, weights: &'a [f64]) -> Self {
+        self.weights = weights;
+        self
+    }
+
+    /// Sets whether submitting filter input that matches none of the options
+    /// creates a new answer with the typed text, such as when adding a new
+    /// tag.
+    ///
+    /// The new answer can only be returned by [Select::prompt_result], so
+    /// other prompt methods keep ignoring submissions without matches.
+    pub fn with_create_new(mut self, create_new: bool) -> Self {
+        self.create_new = create_new;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    ///
+    /// Note: The default render config considers if the NO_COLOR environment variable
+    /// is set to decide whether to render the colored config or the empty one.
+    ///
+    /// When overriding the config in a prompt, NO_COLOR is no longer considered and your
+    /// config is treated as the only source of truth. If you want to customize colors
+    /// and still support NO_COLOR, you will have to do this on your end.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Returns the indexes of the options matching the provided filter input,
+    /// in the order they would be displayed to the user, without prompting.
+    ///
+    /// The options are scored just like when the user types in the filter
+    /// input, using the configured scorer, or access keys when enabled.
+    ///
+    /// ```
+    /// use inquire::Select;
+    ///
+    /// let select = Select::new("Fruit?", vec!["Banana", "Apple", "Blueberry"])
+    ///     .with_scorer(&|input, _option, string_value, _idx| {
+    ///         string_value.starts_with(input).then_some(0)
+    ///     });
+    ///
+    /// assert_eq!(vec![0, 2], select.filtered_indices("B"));
+    /// ```
+    pub fn filtered_indices(&self, query: &str) -> Vec<usize> {
+        let query = match self.trim_filter {
+            true => query.trim(),
+            false => query,
+        };
+        let (string_options, rendered_options) = match_strings(
+            &self.options,
+            self.prestyled_options,
+            self.filter_on_rendered,
+            self.icons,
+            self.annotations,
+        );
+        let show_all = |input: &str| {
+            input.chars().count() < self.min_query_len || (self.show_all_predicate)(input)
+        };
+
+        score_options(
+            &self.options,
+            rendered_options.as_deref().unwrap_or(&string_options),
+            query,
+            self.scorer,
+            &show_all,
+            self.accesskeys,
+            self.weights,
+        )
+    }
+
+    /// Resolves the provided query to an option without prompting, when it
+    /// identifies one: either a single option matches the query, as scored by
+    /// [Select::filtered_indices], or a single option is displayed exactly as
+    /// the query.
+    ///
+    /// Useful to skip the prompt when a command-line argument already
+    /// identifies the option, prompting the user otherwise.
+    ///
+    /// ```
+    /// use inquire::Select;
+    ///
+    /// let select = Select::new("Fruit?", vec!["Apple", "Apple pie", "Banana"]);
+    ///
+    /// assert_eq!(Some(2), select.try_resolve("ban").map(|fruit| fruit.index));
+    /// assert_eq!(Some(0), select.try_resolve("Apple").map(|fruit| fruit.index));
+    /// assert_eq!(None, select.try_resolve("app").map(|fruit| fruit.index));
+    /// ```
+    pub fn try_resolve(&self, query: &str) -> Option<ListOption<&T>> {
+        let to_option = |index: usize| ListOption::new(index, self.options.get(index).unwrap());
+
+        if let [index] = self.filtered_indices(query).as_slice() {
+            return Some(to_option(*index));
+        }
+
+        let query = match self.trim_filter {
+            true => query.trim(),
+            false => query,
+        };
+        let (string_options, _) = match_strings(
+            &self.options,
+            self.prestyled_options,
+            false,
+            self.icons,
+            self.annotations,
+        );
+        let mut exact_matches = string_options
+            .iter()
+            .enumerate()
+            .filter(|(_, value)| *value == query);
+
+        match (exact_matches.next(), exact_matches.next()) {
+            (Some((index, _)), None) => Some(to_option(index)),
+            _ => None,
+        }
+    }
+
+    /// Applies all the provided settings to the prompt, replacing the values
+    /// previously set on the matching fields.
+    ///
+    /// Settings that are closures or borrow other data, such as the
+    /// [formatter](Select::with_formatter), are left untouched and can be set
+    /// before or after this call.
+    pub fn with_settings(mut self, settings: &'a SelectSettings) -> Self {
+        self.help_message = settings.help_message.as_deref();
+        self.help_suffix = settings.help_suffix.as_deref();
+        self.help_on_demand = settings.help_on_demand;
+        self.page_size = settings.page_size;
+        self.max_rows = settings.max_rows;
+        self.scroll_margin = settings.scroll_margin;
+        self.ensure_visible = settings.ensure_visible;
+        self.vim_mode = settings.vim_mode;
+        self.tab_paging = settings.tab_paging;
+        self.hierarchical_nav = settings.hierarchical_nav;
+        self.accesskeys = settings.accesskeys;
+        self.prefix_autosubmit = settings.prefix_autosubmit;
+        self.separator = settings.separator;
+        self.starting_cursor = settings.starting_cursor;
+        self.recommended = settings.recommended;
+        self.cursor_overflow = settings.cursor_overflow;
+        self.starting_filter_input = settings.starting_filter_input.as_deref();
+        self.reset_cursor = settings.reset_cursor;
+        self.title = settings.title.as_deref();
+        self.progress = settings.progress;
+        self.filter_label = settings.filter_label.as_deref();
+        self.filter_placeholder = settings.filter_placeholder.as_deref();
+        self.typeahead = settings.typeahead;
+        self.create_new = settings.create_new;
+        self.filter_input_enabled = settings.filter_input_enabled;
+        self.trim_filter = settings.trim_filter;
+        self.min_query_len = settings.min_query_len;
+        self.hidden_answer = settings.hidden_answer;
+        self.show_filter_in_answer = settings.show_filter_in_answer;
+        self.allow_duplicates = settings.allow_duplicates;
+        self.marking = settings.marking;
+        self.tab_accepts_single = settings.tab_accepts_single;
+        self.error_on_empty_submit = settings.error_on_empty_submit;
+        self.filter_syntax_highlight = settings.filter_syntax_highlight;
+        self.search_indicator = settings.search_indicator;
+        self.max_filter_len = settings.max_filter_len;
+        self.cancel_key = settings.cancel_key;
+        self.alternate_screen = settings.alternate_screen;
+        self.tty_input = settings.tty_input;
+        self.cancel_on_focus_loss = settings.cancel_on_focus_loss;
+        self.stdout_echo = settings.stdout_echo;
+        self.reverse_layout = settings.reverse_layout;
+        self.catch_closure_panics = settings.catch_closure_panics;
+        self.prestyled_options = settings.prestyled_options;
+        self.filter_on_rendered = settings.filter_on_rendered;
+        self.multiline_options = settings.multiline_options;
+        self.scrollbar = settings.scrollbar;
+        self.border = settings.border;
+        self.border_title = settings.border_title;
+        self.persist_full_render = settings.persist_full_render;
+        self.clipboard_copy = settings.clipboard_copy;
+        self.escape_timeout = settings.escape_timeout;
+        self.layout = settings.layout;
+        self
+    }
+
+    /// Lists the key bindings active on this prompt with its current settings,
+    /// e.g. to render them in a help screen shared by the whole application.
+    ///
+    /// ```
+    /// use inquire::Select;
+    ///
+    /// let select = Select::new("Fruit:", vec!["apple", "banana"]).with_vim_mode(true);
+    ///
+    /// for binding in select.keybindings() {
+    ///     println!("{:<20} {}", binding.keys.join(" / "), binding.action);
+    /// }
+    /// ```
+    pub fn keybindings(&self) -> Vec<KeyBinding> {
+        keybindings::select_keybindings(&SelectConfig::from(self), self.filter_input_enabled)
+    }
+
+    /// Estimates the number of rows the prompt occupies when first rendered
+    /// in a terminal of the provided width, without rendering it, e.g. to
+    /// reserve space for it in a larger layout.
+    ///
+    /// The estimate accounts for the title, progress, prompt line, filter
+    /// line, first page of options, separator, help message and footer, with
+    /// long lines wrapped. The search indicator and the filter placeholder
+    /// are included in the width of the line of the filter input. Lines
+    /// rendered by render hooks and pagination renderers are not known in
+    /// advance and are not accounted for.
+    ///
+    /// ```
+    /// use inquire::Select;
+    ///
+    /// let select = Select::new("Fruit:", vec!["apple", "banana", "cherry"])
+    ///     .with_help_message("Pick one");
+    ///
+    /// assert_eq!(5, select.estimated_height(80));
+    /// ```
+    pub fn estimated_height(&self, terminal_width: u16) -> usize {
+        let config = SelectConfig::from(self);
+        let width = usize::from(terminal_width);
+        let text_rows = |text: &str| wrap_words(text, width).len();
+
+        let horizontal = self.layout == SelectLayout::Horizontal;
+        let filter_input_enabled = self.filter_input_enabled && !horizontal;
+        let filter_line = self.filter_label.is_some() && filter_input_enabled;
+        let help_message = self.help_message.filter(|_| !self.help_on_demand);
+
+        let optional_lines = [
+            self.title.is_some(),
+            filter_line,
+            self.footer.is_some(),
+            self.lazy_description.is_some() && !horizontal,
+        ];
+        let reserved_rows = prompt::reserved_rows(&config, help_message.is_some(), optional_lines);
+
+        // the filter input is preceded by the search indicator once typed,
+        // and replaced by the placeholder while empty. A scrolled input fills
+        // the rest of the line instead of wrapping.
+        let input = match self.starting_filter_input.filter(|input| !input.is_empty()) {
+            Some(input) => {
+                let indicator = match self.search_indicator {
+                    true => self.render_config.search_indicator.content,
+                    false => "",
+                };
+                let input = match self.render_config.input_scroll {
+                    true => "",
+                    false => input,
+                };
+
+                format!("{indicator}{input}")
+            }
+            None => self.filter_placeholder.unwrap_or_default().to_string(),
+        };
+        let (prompt_input, filter_rows) = match (filter_input_enabled, self.filter_label) {
+            (true, Some(label)) => ("", text_rows(&format!("{label} {input}"))),
+            (true, None) => (input.as_str(), 1),
+            (false, _) => ("", 1),
+        };
+
+        // rows added by wrapping the lines counted once in the reserved rows
+        let title_rows = self.title.map(text_rows).unwrap_or(1);
+        let prompt_rows = text_rows(&format!("? {} {}", self.message, prompt_input));
+        let help_rows = match help_message {
+            Some(help) => text_rows(&format!(
+                "[{}]",
+                keybindings::displayed_help_message(
+                    help,
+                    self.help_suffix,
+                    &config,
+                    filter_input_enabled
+                )
+            )),
+            None => 1,
+        };
+        let wrapped_rows = title_rows + prompt_rows + filter_rows + help_rows - 4;
+
+        let option_rows = if horizontal {
+            0
+        } else {
+            // the page size is computed as if the help was displayed
+            let page_reserved_rows =
+                prompt::reserved_rows(&config, self.help_message.is_some(), optional_lines);
+            let page = self
+                .options
+                .iter()
+                .take(prompt::page_size(&config, page_reserved_rows));
+
+            match self.multiline_options {
+                true => {
+                    let lines = page
+                        .map(|option| prompt::option_lines(&option.to_string()))
+                        .collect::<Vec<_>>();
+
+                    match prompt::option_rows(&config, page_reserved_rows) {
+                        Some(rows) => paginate_rows(lines.len(), rows, &lines, 0, |lines| *lines)
+                            .content
+                            .iter()
+                            .sum(),
+                        None => lines.iter().sum(),
+                    }
+                }
+                false => page.count(),
+            }
+        };
+
+        reserved_rows + wrapped_rows + option_rows
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the owned object selected by the user.
+    pub fn prompt(self) -> InquireResult<T> {
+        self.raw_prompt().map(|op| op.value)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules, without
+    /// consuming the prompt, so the same configuration can be reused, e.g.
+    /// in a menu loop.
+    ///
+    /// Returns the owned object selected by the user.
+    ///
+    /// ```no_run
+    /// use inquire::Select;
+    ///
+    /// let menu = Select::new("Action:", vec!["Status", "Sync", "Quit"]);
+    ///
+    /// while menu.prompt_ref()? != "Quit" {
+    ///     // handle the action
+    /// }
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn prompt_ref(&self) -> InquireResult<T>
+    where
+        T: Clone,
+    {
+        self.clone().prompt()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn prompt_ref_with_backend<B: SelectBackend>(
+        &self,
+        backend: &mut B,
+    ) -> InquireResult<T>
+    where
+        T: Clone,
+    {
+        self.clone()
+            .prompt_with_backend(backend)
+            .map(|answer| answer.value)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules, prompting
+    /// again when the attempt fails with a retryable error.
+    ///
+    /// The prompt is displayed up to `max_attempts` times, and at least once.
+    /// See [InquireError::is_retryable] for the errors considered retryable.
+    /// Notably, the user canceling the prompt is never retried.
+    ///
+    /// Returns the owned object selected by the user.
+    pub fn prompt_retry(self, max_attempts: usize) -> InquireResult<T>
+    where
+        T: Clone,
+    {
+        retry(max_attempts, || self.clone().prompt())
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
     /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
     ///
     /// Meanwhile, if the user does submit an answer, the method wraps the return
@@ -326,6 +2220,33 @@ where
         }
     }
 
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for command-line tools where canceling or
+    /// interrupting the prompt - by pressing ESC or Ctrl+C - means quitting.
+    /// In this case, the process exits with the provided status code, once
+    /// the terminal is restored from raw mode and the canceled prompt is
+    /// rendered. Other errors are returned as usual.
+    ///
+    /// Returns the owned object selected by the user.
+    ///
+    /// ```no_run
+    /// use inquire::Select;
+    ///
+    /// let env = Select::new("Environment:", vec!["staging", "production"]).prompt_or_exit(130)?;
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn prompt_or_exit(self, code: i32) -> InquireResult<T> {
+        // the terminal is dropped, and thus restored, when the prompt returns
+        match self.prompt() {
+            Err(InquireError::OperationCanceled | InquireError::OperationInterrupted) => {
+                std::process::exit(code)
+            }
+            result => result,
+        }
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -351,16 +2272,477 @@ where
     ///
     /// Returns a [`ListOption`](crate::list_option::ListOption) containing
     /// the index of the selection and the owned object selected by the user.
+    ///
+    /// When the options are references, such as string slices borrowed from a
+    /// long-lived collection, the returned value borrows from that collection
+    /// as well and no option is copied.
+    ///
+    /// ```no_run
+    /// use inquire::Select;
+    ///
+    /// let fruits: Vec<String> = vec!["Banana".into(), "Apple".into()];
+    ///
+    /// let options = fruits.iter().map(String::as_str).collect();
+    /// let answer = Select::new("What's your favorite fruit?", options).raw_prompt()?;
+    ///
+    /// // `answer.value` is a `&str` borrowing from `fruits`.
+    /// assert_eq!(fruits[answer.index], answer.value);
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
     pub fn raw_prompt(self) -> InquireResult<ListOption<T>> {
-        let (input_reader, terminal) = get_default_terminal()?;
-        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
-        self.prompt_with_backend(&mut backend)
+        let stdout_echo = self.stdout_echo;
+        let mut backend = self.default_backend()?;
+        let answer = self.prompt_with_backend(&mut backend)?;
+        drop(backend);
+
+        if stdout_echo {
+            echo_to_stdout(&answer.value)?;
+        }
+
+        Ok(answer)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns either the option selected by the user or, when
+    /// [Select::with_create_new] is enabled and the submitted filter input
+    /// matches none of the options, the typed text.
+    ///
+    /// ```no_run
+    /// use inquire::{Select, SelectResult};
+    ///
+    /// let tags = vec!["bug", "feature"];
+    ///
+    /// match Select::new("Tag:", tags).with_create_new(true).prompt_result()? {
+    ///     SelectResult::Selected(tag) => println!("Existing tag {}", tag.value),
+    ///     SelectResult::New(tag) => println!("New tag {tag}"),
+    /// }
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn prompt_result(self) -> InquireResult<SelectResult<T>> {
+        let stdout_echo = self.stdout_echo;
+        let mut backend = self.default_backend()?;
+        let answer = self.prompt_result_with_backend(&mut backend)?;
+        drop(backend);
+
+        if stdout_echo {
+            match &answer {
+                SelectResult::Selected(option) => echo_to_stdout(&option.value)?,
+                SelectResult::New(text) => echo_to_stdout(text)?,
+            }
+        }
+
+        Ok(answer)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Each option is associated with the payload at the same position in
+    /// `payloads`, which is returned along with the owned object selected by
+    /// the user. This avoids mapping the index of the answer back to your
+    /// data, e.g. when labels are associated with handler functions.
+    ///
+    /// The prompt fails with an [InquireError::InvalidConfiguration] error
+    /// when the number of payloads differs from the number of options, or
+    /// when the options are replaced by a
+    /// [refresher](Select::with_refresh) or a
+    /// [loading source](Select::with_loading_source), since the payloads
+    /// would no longer line up with them.
+    ///
+    /// ```no_run
+    /// use inquire::Select;
+    ///
+    /// fn open() {}
+    /// fn save() {}
+    ///
+    /// let handlers: [fn(); 2] = [open, save];
+    ///
+    /// let (_label, handler) = Select::new("Action?", vec!["Open", "Save"])
+    ///     .prompt_with_payloads(&handlers)?;
+    /// handler();
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn prompt_with_payloads<P>(self, payloads: &[P]) -> InquireResult<(T, &P)> {
+        let stdout_echo = self.stdout_echo;
+        let mut backend = self.default_backend()?;
+        let answer = self.prompt_with_payloads_and_backend(payloads, &mut backend)?;
+        drop(backend);
+
+        if stdout_echo {
+            echo_to_stdout(&answer.0)?;
+        }
+
+        Ok(answer)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the answer along with the state of the prompt when it
+    /// finished, which is also available when the prompt was canceled or
+    /// failed. Restoring the state with [Select::with_state] resumes where
+    /// the user left off.
+    ///
+    /// ```no_run
+    /// use inquire::{InquireError, Select, SelectState};
+    ///
+    /// let mut state = SelectState::default();
+    ///
+    /// let answer = loop {
+    ///     let (answer, last_state) = Select::new("Fruit?", vec!["Banana", "Apple"])
+    ///         .with_state(&state)
+    ///         .prompt_with_state();
+    ///
+    ///     match answer {
+    ///         Err(InquireError::OperationCanceled) => state = last_state,
+    ///         answer => break answer?,
+    ///     }
+    /// };
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn prompt_with_state(self) -> (InquireResult<ListOption<T>>, SelectState) {
+        let stdout_echo = self.stdout_echo;
+        let mut backend = match self.default_backend() {
+            Ok(backend) => backend,
+            Err(err) => return (Err(err), SelectState::default()),
+        };
+        let (answer, state) = self.prompt_with_state_and_backend(&mut backend);
+        drop(backend);
+
+        if let (true, Ok(answer)) = (stdout_echo, &answer) {
+            if let Err(err) = echo_to_stdout(&answer.value) {
+                return (Err(err), state);
+            }
+        }
+
+        (answer, state)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules, with
+    /// [marking](Select::with_marking) enabled.
+    ///
+    /// Returns a [`ListOption`](crate::list_option::ListOption) containing
+    /// the index of the option submitted with Enter and the owned object
+    /// selected by the user, along with the sorted indexes of the options
+    /// marked with Space.
+    ///
+    /// ```no_run
+    /// use inquire::Select;
+    ///
+    /// let tasks = vec!["Write docs", "Fix tests", "Release"];
+    ///
+    /// let (now, later) = Select::new("Task to start now:", tasks).prompt_with_marks()?;
+    /// println!("Starting {}, {} flagged for later", now.value, later.len());
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn prompt_with_marks(self) -> InquireResult<(ListOption<T>, Vec<usize>)> {
+        let stdout_echo = self.stdout_echo;
+        let mut backend = self.default_backend()?;
+        let answer = self.prompt_with_marks_and_backend(&mut backend)?;
+        drop(backend);
+
+        if stdout_echo {
+            echo_to_stdout(&answer.0.value)?;
+        }
+
+        Ok(answer)
+    }
+
+    /// Drives the prompt with the provided keys instead of the user's input,
+    /// without rendering anything, and returns the owned object selected as
+    /// if the user had pressed those keys.
+    ///
+    /// This is useful to replay a recorded session, e.g. to reproduce a bug
+    /// from the exact key sequence. The prompt fails with an
+    /// [InquireError::IO] error of kind
+    /// [UnexpectedEof](std::io::ErrorKind::UnexpectedEof) when the keys are
+    /// exhausted before the prompt is finished.
+    ///
+    /// ```
+    /// use inquire::{ui::{Key, KeyModifiers}, Select};
+    ///
+    /// let answer = Select::new("Fruit?", vec!["Banana", "Apple"])
+    ///     .prompt_with_events([Key::Down(KeyModifiers::NONE), Key::Enter])?;
+    ///
+    /// assert_eq!("Apple", answer);
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn prompt_with_events(self, events: impl IntoIterator<Item = Key>) -> InquireResult<T> {
+        let input_reader = ReplayInput(events.into_iter());
+        let mut backend = Backend::new(input_reader, NullTerminal, self.render_config)?;
+
+        self.prompt_with_backend(&mut backend).map(|op| op.value)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input in the provided session, keeping its terminal
+    /// set up for the next prompts.
+    ///
+    /// Returns the owned object selected by the user. The prompt stays
+    /// rendered once finished, and the next prompt of the session is rendered
+    /// below it. See [PromptSession] for an example.
+    pub fn prompt_with_session<'s>(self, session: &mut PromptSession<'s>) -> InquireResult<T>
+    where
+        'a: 's,
+    {
+        session.backend.set_render_config(self.render_config);
+
+        let answer = self.prompt_with_backend(&mut session.backend);
+        session.backend.detach_frames()?;
+
+        answer.map(|op| op.value)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// for input read from the provided crossterm events, instead of the
+    /// terminal, while still rendering to the terminal.
+    ///
+    /// Unlike [Select::prompt_with_events], the prompt is fully rendered,
+    /// which is useful for realistic tests and demos with scripted input.
+    /// The prompt fails with an [InquireError::IO] error of kind
+    /// [UnexpectedEof](std::io::ErrorKind::UnexpectedEof) when the events are
+    /// exhausted before the prompt is finished.
+    ///
+    /// ```no_run
+    /// use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+    /// use inquire::Select;
+    ///
+    /// let events = [KeyCode::Down, KeyCode::Enter]
+    ///     .map(|code| Event::Key(KeyEvent::new(code, KeyModifiers::NONE)));
+    ///
+    /// let answer = Select::new("Fruit?", vec!["Banana", "Apple"])
+    ///     .prompt_with_crossterm_events(events)?;
+    ///
+    /// assert_eq!("Apple", answer);
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    #[cfg(feature = "crossterm")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "crossterm")))]
+    pub fn prompt_with_crossterm_events<E>(self, events: E) -> InquireResult<T>
+    where
+        E: IntoIterator<Item = ::crossterm::event::Event>,
+        E::IntoIter: 'static,
+    {
+        use crate::terminal::crossterm::{CrosstermKeyReader, CrosstermTerminal};
+
+        let input_reader = CrosstermKeyReader::new().with_event_source(events.into_iter());
+        let terminal = match self.tty_input {
+            true => CrosstermTerminal::from_tty()?,
+            false => CrosstermTerminal::new()?,
+        };
+
+        let mut backend = self.setup_backend(input_reader, terminal)?;
+        self.prompt_with_backend(&mut backend).map(|op| op.value)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user to pick options one at a time, until `done_key` is
+    /// pressed.
+    ///
+    /// Each Enter picks the highlighted option, flagging it with the
+    /// [marked option indicator](crate::ui::RenderConfig::marked_option_indicator),
+    /// or unpicks it if it already was. The prompt stays open until the user
+    /// presses `done_key`, and returns the picked options in the order they
+    /// were picked, possibly none.
+    ///
+    /// The prompt fails with an [InquireError::InvalidConfiguration] error
+    /// when `done_key` already submits or cancels the prompt, such as Enter
+    /// or Escape.
+    ///
+    /// ```no_run
+    /// use inquire::{ui::{Key, KeyModifiers}, Select};
+    ///
+    /// let toppings = vec!["Cheese", "Olives", "Basil"];
+    ///
+    /// let picks = Select::new("Toppings (Ctrl+S when done):", toppings)
+    ///     .prompt_multi_sequential(Key::Char('s', KeyModifiers::CONTROL))?;
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn prompt_multi_sequential(self, done_key: Key) -> InquireResult<Vec<ListOption<T>>> {
+        let stdout_echo = self.stdout_echo;
+        let mut backend = self.default_backend()?;
+        let picks = self.prompt_multi_sequential_with_backend(done_key, &mut backend)?;
+        drop(backend);
+
+        if stdout_echo {
+            for pick in &picks {
+                echo_to_stdout(&pick.value)?;
+            }
+        }
+
+        Ok(picks)
+    }
+
+    fn default_backend(&self) -> InquireResult<Backend<'a, impl InputReader, impl Terminal>> {
+        let (input_reader, terminal) = get_terminal(self.tty_input)?;
+
+        self.setup_backend(input_reader, terminal)
+    }
+
+    fn setup_backend<I: InputReader, Tm: Terminal>(
+        &self,
+        mut input_reader: I,
+        mut terminal: Tm,
+    ) -> InquireResult<Backend<'a, I, Tm>> {
+        if let Some(escape_timeout) = self.escape_timeout {
+            input_reader.set_escape_timeout(escape_timeout);
+        }
+
+        if self.alternate_screen {
+            terminal.enter_alternate_screen()?;
+        }
+
+        if self.cancel_on_focus_loss {
+            terminal.enable_focus_reporting()?;
+        }
+
+        Ok(Backend::new(input_reader, terminal, self.render_config)?)
     }
 
     pub(crate) fn prompt_with_backend<B: SelectBackend>(
-        self,
+        mut self,
         backend: &mut B,
     ) -> InquireResult<ListOption<T>> {
-        SelectPrompt::new(self)?.prompt(backend)
+        // without creating new answers, the result is always a selection
+        self.create_new = false;
+
+        match self.prompt_result_with_backend(backend)? {
+            SelectResult::Selected(answer) => Ok(answer),
+            SelectResult::New(_) => unreachable!("new answers are disabled"),
+        }
+    }
+
+    pub(crate) fn prompt_result_with_backend<B: SelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<SelectResult<T>> {
+        match SelectPrompt::new(self)?.run(backend)? {
+            SelectOutput::Answer(answer, _marked) => Ok(answer),
+            SelectOutput::Picks(_) => unreachable!("sequential selection is disabled"),
+            SelectOutput::Range(..) => unreachable!("range selection is disabled"),
+        }
+    }
+
+    pub(crate) fn prompt_with_state_and_backend<B: SelectBackend>(
+        mut self,
+        backend: &mut B,
+    ) -> (InquireResult<ListOption<T>>, SelectState) {
+        self.create_new = false;
+
+        let prompt = match SelectPrompt::new(self) {
+            Ok(prompt) => prompt,
+            Err(err) => return (Err(err), SelectState::default()),
+        };
+
+        let (output, state) = prompt.run_with_state(backend);
+        let answer = output.map(|output| match output {
+            SelectOutput::Answer(SelectResult::Selected(answer), _) => answer,
+            SelectOutput::Answer(SelectResult::New(_), _) => {
+                unreachable!("new answers are disabled")
+            }
+            SelectOutput::Picks(_) => unreachable!("sequential selection is disabled"),
+            SelectOutput::Range(..) => unreachable!("range selection is disabled"),
+        });
+
+        (answer, state)
+    }
+
+    pub(crate) fn prompt_with_marks_and_backend<B: SelectBackend>(
+        mut self,
+        backend: &mut B,
+    ) -> InquireResult<(ListOption<T>, Vec<usize>)> {
+        self.marking = true;
+        self.create_new = false;
+
+        match SelectPrompt::new(self)?.run(backend)? {
+            SelectOutput::Answer(SelectResult::Selected(answer), marked) => Ok((answer, marked)),
+            SelectOutput::Answer(SelectResult::New(_), _) => {
+                unreachable!("new answers are disabled")
+            }
+            SelectOutput::Picks(_) => unreachable!("sequential selection is disabled"),
+            SelectOutput::Range(..) => unreachable!("range selection is disabled"),
+        }
+    }
+
+    pub(crate) fn prompt_multi_sequential_with_backend<B: SelectBackend>(
+        self,
+        done_key: Key,
+        backend: &mut B,
+    ) -> InquireResult<Vec<ListOption<T>>> {
+        let prompt = SelectPrompt::new(self)?;
+
+        if let Some(Action::Submit | Action::Cancel | Action::Interrupt) =
+            Prompt::<B>::action_for_key(&prompt, done_key)
+        {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Key {done_key:?} can not finish a sequential selection, as it submits or cancels the prompt"
+            )));
+        }
+
+        match prompt.sequential(done_key).run(backend)? {
+            SelectOutput::Picks(picks) => Ok(picks),
+            SelectOutput::Answer(..) => unreachable!("sequential selection is enabled"),
+            SelectOutput::Range(..) => unreachable!("range selection is disabled"),
+        }
+    }
+
+    pub(crate) fn prompt_range_with_backend<B: SelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<(usize, usize)> {
+        match SelectPrompt::new(self)?.ranged().run(backend)? {
+            SelectOutput::Range(start, end) => Ok((start, end)),
+            SelectOutput::Answer(..) | SelectOutput::Picks(_) => {
+                unreachable!("range selection is enabled")
+            }
+        }
+    }
+
+    pub(crate) fn prompt_with_payloads_and_backend<'p, P, B: SelectBackend>(
+        self,
+        payloads: &'p [P],
+        backend: &mut B,
+    ) -> InquireResult<(T, &'p P)> {
+        if self.refresh.is_some() || self.loading_source.is_some() {
+            return Err(InquireError::InvalidConfiguration(String::from(
+                "Payloads are not supported when the options are refreshed or loaded",
+            )));
+        }
+
+        if payloads.len() != self.options.len() {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Length {} of payloads does not match length {} of options",
+                payloads.len(),
+                self.options.len()
+            )));
+        }
+
+        let answer = self.prompt_with_backend(backend)?;
+        let payload = payloads.get(answer.index).ok_or_else(|| {
+            InquireError::InvalidConfiguration(format!(
+                "No payload for the option at index {}",
+                answer.index
+            ))
+        })?;
+
+        Ok((answer.value, payload))
     }
 }
+
+/// Writes the answer to the standard output on its own line, bypassing the
+/// terminal used to render the prompt.
+fn echo_to_stdout(answer: &impl Display) -> InquireResult<()> {
+    echo_answer(answer, &mut io::stdout().lock())
+}
+
+/// Writes the answer to the provided output on its own line, without any
+/// styling.
+fn echo_answer(answer: &impl Display, output: &mut impl Write) -> InquireResult<()> {
+    writeln!(output, "{answer}")?;
+    output.flush()?;
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+/// Behavior of a [Select](crate::Select) prompt when the starting cursor
+/// index is out of the bounds of the list of options.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CursorOverflow {
+    /// The prompt fails with an
+    /// [InquireError::InvalidConfiguration](crate::InquireError::InvalidConfiguration) error.
+    #[default]
+    Error,
+
+    /// The cursor is moved to the last option.
+    Clamp,
+
+    /// The cursor wraps around the list of options, as if the index was
+    /// taken modulo the number of options.
+    Wrap,
+}
+
+impl CursorOverflow {
+    /// Applies the behavior to the provided cursor index, returning the
+    /// resulting index or `None` when the index is out of bounds and the
+    /// behavior is to fail.
+    pub(crate) fn apply(self, index: usize, len: usize) -> Option<usize> {
+        if index < len {
+            return Some(index);
+        }
+
+        match self {
+            CursorOverflow::Error => None,
+            CursorOverflow::Clamp => len.checked_sub(1),
+            CursorOverflow::Wrap => index.checked_rem(len),
+        }
+    }
+}
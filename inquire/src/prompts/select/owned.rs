@@ -0,0 +1,190 @@
+use std::fmt::Display;
+
+use crate::{
+    config::get_configuration,
+    error::{InquireError, InquireResult},
+    list_option::ListOption,
+    ui::RenderConfig,
+    Select,
+};
+
+/// Variant of [Select] owning its message and help message, so that a
+/// configured prompt has no lifetime and can be stored in a struct or passed
+/// around before prompting.
+///
+/// Only the settings that do not borrow data are available. For scorers,
+/// formatters, hooks and the other settings, build a [Select] from the owned
+/// prompt with [OwnedSelect::as_select].
+///
+/// ```no_run
+/// use inquire::OwnedSelect;
+///
+/// struct Wizard {
+///     step: OwnedSelect<String>,
+/// }
+///
+/// let environments = vec![String::from("staging"), String::from("production")];
+/// let wizard = Wizard {
+///     step: OwnedSelect::new(format!("Deploy to ({} available):", environments.len()), environments)
+///         .with_help_message("production requires approval"),
+/// };
+///
+/// let environment = wizard.step.prompt()?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Clone)]
+pub struct OwnedSelect<T> {
+    /// Message to be presented to the user.
+    pub message: String,
+
+    /// Options displayed to the user.
+    pub options: Vec<T>,
+
+    /// Help message to be presented to the user.
+    pub help_message: Option<String>,
+
+    /// Page size of the options displayed to the user.
+    pub page_size: usize,
+
+    /// Whether vim mode is enabled. When enabled, the user can
+    /// navigate through the options using hjkl.
+    pub vim_mode: bool,
+
+    /// Starting cursor index of the selection.
+    pub starting_cursor: usize,
+
+    /// Whether the filter input is enabled.
+    pub filter_input_enabled: bool,
+
+    /// RenderConfig to apply to the rendered interface.
+    pub render_config: RenderConfig<'static>,
+}
+
+impl<T> OwnedSelect<T>
+where
+    T: Display,
+{
+    /// Creates an [OwnedSelect] with the provided message and options, along
+    /// with the default configuration values of [Select].
+    pub fn new(message: impl Into<String>, options: Vec<T>) -> Self {
+        Self {
+            message: message.into(),
+            options,
+            help_message: Select::<T>::DEFAULT_HELP_MESSAGE.map(String::from),
+            page_size: Select::<T>::DEFAULT_PAGE_SIZE,
+            vim_mode: crate::config::get_vim_mode(),
+            starting_cursor: Select::<T>::DEFAULT_STARTING_CURSOR,
+            filter_input_enabled: Select::<T>::DEFAULT_FILTER_INPUT_ENABLED,
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: impl Into<String>) -> Self {
+        self.help_message = Some(message.into());
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.help_message = None;
+        self
+    }
+
+    /// Sets the page size.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Enables or disables vim_mode.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets the starting cursor index.
+    pub fn with_starting_cursor(mut self, starting_cursor: usize) -> Self {
+        self.starting_cursor = starting_cursor;
+        self
+    }
+
+    /// Disables the filter input, which means the user is not able to filter the options
+    /// by typing.
+    pub fn without_filtering(mut self) -> Self {
+        self.filter_input_enabled = false;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'static>) -> Self {
+        self.render_config = render_config;
+        self
+    }
+
+    /// Creates a [Select] borrowing the messages of this prompt, with a copy of
+    /// its options, to configure the settings only available on [Select].
+    pub fn as_select(&self) -> Select<'_, T>
+    where
+        T: Clone,
+    {
+        self.to_select(self.options.clone())
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the owned object selected by the user.
+    pub fn prompt(self) -> InquireResult<T> {
+        self.raw_prompt().map(|op| op.value)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<T>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns a [`ListOption`](crate::list_option::ListOption) containing
+    /// the index of the selection and the owned object selected by the user.
+    pub fn raw_prompt(mut self) -> InquireResult<ListOption<T>> {
+        let options = std::mem::take(&mut self.options);
+        self.to_select(options).raw_prompt()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn prompt_with_backend<B: crate::ui::SelectBackend>(
+        mut self,
+        backend: &mut B,
+    ) -> InquireResult<ListOption<T>> {
+        let options = std::mem::take(&mut self.options);
+        self.to_select(options).prompt_with_backend(backend)
+    }
+
+    fn to_select(&self, options: Vec<T>) -> Select<'_, T> {
+        let mut select = Select::new(&self.message, options)
+            .with_page_size(self.page_size)
+            .with_vim_mode(self.vim_mode)
+            .with_starting_cursor(self.starting_cursor)
+            .with_render_config(self.render_config);
+
+        select.help_message = self.help_message.as_deref();
+        select.filter_input_enabled = self.filter_input_enabled;
+
+        select
+    }
+}
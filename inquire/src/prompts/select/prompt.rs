@@ -1,30 +1,139 @@
-use std::{cmp::Reverse, fmt::Display};
+use std::{
+    collections::{BTreeSet, HashMap},
+    env,
+    fmt::Display,
+    fs::{File, OpenOptions},
+    io::{self, Write},
+    panic::{self, AssertUnwindSafe},
+    sync::mpsc::{Receiver, TryRecvError},
+    time::{Duration, Instant},
+};
 
 use crate::{
+    ansi::AnsiStrippable,
+    clipboard,
     error::InquireResult,
     formatter::OptionFormatter,
     input::{Input, InputActionResult},
     list_option::ListOption,
-    prompts::prompt::{ActionResult, Prompt},
-    type_aliases::Scorer,
-    ui::SelectBackend,
-    utils::paginate,
-    InquireError, Select,
+    prompts::{
+        action::{Action, InnerAction},
+        prompt::{ActionResult, Prompt},
+    },
+    type_aliases::{
+        DescriptionProvider, FinishHook, FooterRenderer, HighlightHook, IconProvider, KeyFilter,
+        PaginationRenderer, Refresher, RenderHook, Scorer, ShowAllPredicate, StartHook,
+        StatusProvider,
+    },
+    ui::{Key, OptionDecorations, SelectBackend},
+    utils::{paginate, paginate_from, paginate_rows, scroll_window_start, Page},
+    FooterContext, InquireError, RenderContext, Select, SelectLayout, SelectResult, SelectState,
+};
+
+use super::{
+    action::SelectPromptAction, config::SelectConfig, keybindings::displayed_help_message,
 };
 
-use super::{action::SelectPromptAction, config::SelectConfig};
+/// Environment variable writing render diagnostics to stderr when set to
+/// `1` or `true`.
+const DEBUG_ENV_VAR: &str = "INQUIRE_DEBUG";
+
+/// Environment variable holding the path of a file to which render
+/// diagnostics are appended instead, as the prompt itself is rendered to
+/// stderr.
+const DEBUG_FILE_ENV_VAR: &str = "INQUIRE_DEBUG_FILE";
+
+/// Destination of the render diagnostics.
+enum DebugLog {
+    Stderr,
+    File(File),
+}
+
+impl DebugLog {
+    fn write_line(&self, line: &str) {
+        let _unused = match self {
+            Self::Stderr => writeln!(io::stderr(), "{line}"),
+            Self::File(file) => {
+                let mut file: &File = file;
+                writeln!(file, "{line}")
+            }
+        };
+    }
+}
+
+/// Time after which typed characters no longer extend the type-ahead prefix.
+const TYPEAHEAD_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Interval between checks for loaded options, also animating the spinner.
+const LOADING_TICK_INTERVAL: Duration = Duration::from_millis(80);
+
+/// Frames of the spinner displayed while options are loading.
+const LOADING_SPINNER: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
 
 pub struct SelectPrompt<'a, T> {
     message: &'a str,
     config: SelectConfig,
     options: Vec<T>,
     string_options: Vec<String>,
+    rendered_options: Option<Vec<String>>,
     scored_options: Vec<usize>,
     help_message: Option<&'a str>,
+    help_suffix: Option<&'a str>,
+    show_help: bool,
     cursor_index: usize,
+    page_start: usize,
+    /// Whether the page starts at `page_start` without a scroll margin, as
+    /// scrolled to an option, until the cursor or the options change.
+    page_pinned: bool,
     input: Option<Input>,
+    filter_label: Option<&'a str>,
+    title: Option<&'a str>,
+    annotations: &'a [(&'a str, &'a str)],
+    weights: &'a [f64],
+    icons: Option<IconProvider<'a>>,
+    status: Option<StatusProvider<'a>>,
+    create_new: bool,
+    typeahead: String,
+    last_typeahead: Option<Instant>,
     scorer: Scorer<'a, T>,
+    show_all: ShowAllPredicate<'a>,
     formatter: OptionFormatter<'a, T>,
+    render_hook: Option<RenderHook<'a, T>>,
+    pagination_renderer: Option<PaginationRenderer<'a, T>>,
+    footer: Option<FooterRenderer<'a>>,
+    on_highlight: Option<HighlightHook<'a, T>>,
+    on_start: Option<StartHook<'a>>,
+    key_filter: Option<KeyFilter<'a>>,
+    lazy_description: Option<DescriptionProvider<'a, T>>,
+    descriptions: HashMap<usize, String>,
+    on_finish: Option<FinishHook<'a, T>>,
+    refresh: Option<(Refresher<'a, T>, Duration)>,
+    loading: Option<Receiver<Vec<T>>>,
+    loading_frame: usize,
+    /// Starting cursor to apply to the options once they are loaded.
+    loading_starting_cursor: Option<usize>,
+    highlighted: Option<usize>,
+    marked: BTreeSet<usize>,
+    picks: Vec<usize>,
+    finishing_picks: bool,
+    /// Whether the last copy to the clipboard succeeded, displayed until the
+    /// next key press.
+    copied: Option<bool>,
+    confirm_indices: Option<(&'a [usize], &'a str)>,
+    confirming: Option<usize>,
+    range_anchor: Option<usize>,
+    ensure_visible: Option<usize>,
+    debug_log: Option<DebugLog>,
+}
+
+/// Answer of a [SelectPrompt].
+pub enum SelectOutput<T> {
+    /// Submitted answer, along with the indexes of the marked options.
+    Answer(SelectResult<T>, Vec<usize>),
+    /// Indexes of the first and last options of a submitted range.
+    Range(usize, usize),
+    /// Options picked one at a time in a sequential selection.
+    Picks(Vec<ListOption<T>>),
 }
 
 impl<'a, T> SelectPrompt<'a, T>
@@ -32,44 +141,131 @@ where
     T: Display,
 {
     pub fn new(so: Select<'a, T>) -> InquireResult<Self> {
-        if so.options.is_empty() {
+        let loading = so.loading_source.as_ref().map(|source| source());
+
+        if so.options.is_empty() && loading.is_none() {
             return Err(InquireError::InvalidConfiguration(
                 "Available options can not be empty".into(),
             ));
         }
 
-        if so.starting_cursor >= so.options.len() {
-            return Err(InquireError::InvalidConfiguration(format!(
-                "Starting cursor index {} is out-of-bounds for length {} of options",
-                so.starting_cursor,
-                &so.options.len()
-            )));
+        let starting_cursor = match so
+            .cursor_overflow
+            .apply(so.starting_cursor, so.options.len())
+        {
+            Some(index) => index,
+            None if loading.is_some() => 0,
+            None => {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Starting cursor index {} is out-of-bounds for length {} of options",
+                    so.starting_cursor,
+                    &so.options.len()
+                )))
+            }
+        };
+
+        let (string_options, rendered_options) = match_strings(
+            &so.options,
+            so.prestyled_options,
+            so.filter_on_rendered,
+            so.icons,
+            so.annotations,
+        );
+        let scored_options = weighted_order(so.options.len(), so.weights);
+
+        if !so.allow_duplicates {
+            check_duplicates(&string_options)?;
         }
 
-        let string_options = so.options.iter().map(T::to_string).collect();
-        let scored_options = (0..so.options.len()).collect();
+        let horizontal = so.layout == SelectLayout::Horizontal;
 
-        let input = match so.filter_input_enabled {
-            true => Some(Input::new_with(
-                so.starting_filter_input.unwrap_or_default(),
-            )),
-            false => None,
-        };
+        let input = (so.filter_input_enabled && !horizontal).then(|| {
+            let mut input = Input::new_with(so.starting_filter_input.unwrap_or_default());
+
+            if let Some(max_filter_len) = so.max_filter_len {
+                input = input.with_max_length(max_filter_len);
+            }
+            if let Some(filter_placeholder) = so.filter_placeholder {
+                input = input.with_placeholder(filter_placeholder);
+            }
+
+            input
+        });
 
         Ok(Self {
             message: so.message,
             config: (&so).into(),
             options: so.options,
             string_options,
+            rendered_options,
             scored_options,
             help_message: so.help_message,
-            cursor_index: so.starting_cursor,
+            help_suffix: so.help_suffix,
+            show_help: !so.help_on_demand,
+            cursor_index: starting_cursor,
+            page_start: 0,
+            page_pinned: false,
             input,
+            filter_label: so.filter_label,
+            title: so.title,
+            annotations: so.annotations,
+            weights: so.weights,
+            icons: so.icons,
+            status: so.status,
+            create_new: so.create_new,
+            typeahead: String::new(),
+            last_typeahead: None,
             scorer: so.scorer,
+            show_all: so.show_all_predicate,
             formatter: so.formatter,
+            render_hook: so.render_hook,
+            pagination_renderer: so.pagination_renderer,
+            footer: so.footer,
+            on_highlight: so.on_highlight,
+            on_start: so.on_start,
+            key_filter: so.key_filter,
+            lazy_description: so.lazy_description,
+            descriptions: HashMap::new(),
+            on_finish: so.on_finish,
+            refresh: so.refresh,
+            loading_starting_cursor: loading.is_some().then_some(so.starting_cursor),
+            loading,
+            loading_frame: 0,
+            highlighted: None,
+            marked: BTreeSet::new(),
+            picks: vec![],
+            finishing_picks: false,
+            copied: None,
+            confirm_indices: so.confirm_indices,
+            confirming: None,
+            range_anchor: None,
+            ensure_visible: so.ensure_visible,
+            debug_log: open_debug_log(),
         })
     }
 
+    /// Scrolls the page as little as needed to show the option at the
+    /// provided index of the original list, if it matches the filter, while
+    /// keeping the cursor visible.
+    fn scroll_to_option(&mut self, index: usize) {
+        let Some(position) = self.scored_options.iter().position(|i| *i == index) else {
+            return;
+        };
+
+        let page_size = self.page_size();
+        let total = self.scored_options.len();
+        let start = scroll_window_start(page_size, total, position, self.page_start, 0);
+
+        self.page_start = scroll_window_start(
+            page_size,
+            total,
+            self.cursor_index,
+            start,
+            self.config.scroll_margin.unwrap_or(0),
+        );
+        self.page_pinned = true;
+    }
+
     fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
         let new_position = if wrap {
             let after_wrap = qty.saturating_sub(self.cursor_index);
@@ -102,16 +298,426 @@ where
     fn update_cursor_position(&mut self, new_position: usize) -> ActionResult {
         if new_position != self.cursor_index {
             self.cursor_index = new_position;
+            self.page_start = self.scroll_window_start();
+            self.page_pinned = false;
+            self.notify_highlight();
             ActionResult::NeedsRedraw
         } else {
             ActionResult::Clean
         }
     }
 
+    /// Moves the cursor to the next option starting with the characters typed
+    /// in quick succession. Typing the same character repeatedly cycles
+    /// through the options starting with it.
+    fn type_ahead(&mut self, c: char) -> ActionResult {
+        let now = Instant::now();
+        let expired = match self.last_typeahead {
+            Some(last) => now.duration_since(last) > TYPEAHEAD_TIMEOUT,
+            None => true,
+        };
+
+        if expired {
+            self.typeahead.clear();
+        }
+
+        self.last_typeahead = Some(now);
+        self.typeahead.extend(c.to_lowercase());
+
+        let first = self.typeahead.chars().next();
+        let cycling = self.typeahead.chars().all(|c| Some(c) == first);
+
+        // when cycling, the search starts after the current option so that
+        // the cursor moves to the next match.
+        let (prefix, skip) = match cycling {
+            true => (first.map(String::from).unwrap_or_default(), 1),
+            false => (self.typeahead.clone(), 0),
+        };
+
+        let len = self.scored_options.len();
+        let found = (0..len)
+            .map(|offset| (self.cursor_index + skip + offset) % len)
+            .find(|pos| {
+                let index = *self.scored_options.get(*pos).unwrap();
+                let string_value = self.string_options.get(index).unwrap();
+
+                string_value.to_lowercase().starts_with(&prefix)
+            });
+
+        match found {
+            Some(pos) => self.update_cursor_position(pos),
+            None => ActionResult::Clean,
+        }
+    }
+
+    /// Replaces the options with the ones returned by the refresher, keeping
+    /// the filter input and, if still available, the highlighted option.
+    fn refresh_options(&mut self, refresher: Refresher<'a, T>) -> InquireResult<ActionResult> {
+        self.replace_options(refresher())
+    }
+
+    /// Checks whether the options finished loading, replacing the current
+    /// ones with them if so.
+    fn poll_loading(&mut self) -> InquireResult<ActionResult> {
+        let received = match &self.loading {
+            Some(receiver) => receiver.try_recv(),
+            None => return Ok(ActionResult::Clean),
+        };
+
+        match received {
+            Ok(options) if options.is_empty() => Err(InquireError::InvalidConfiguration(
+                "Available options can not be empty".into(),
+            )),
+            Ok(options) => {
+                self.loading = None;
+                let result = self.replace_options(options)?;
+
+                // the starting cursor is clamped to the loaded options, as it
+                // could not apply to the ones displayed while loading
+                if let Some(starting_cursor) = self.loading_starting_cursor.take() {
+                    let index = starting_cursor.min(self.options.len().saturating_sub(1));
+                    if let Some(position) = self.scored_options.iter().position(|i| *i == index) {
+                        let _ = self.update_cursor_position(position);
+                    }
+                }
+
+                Ok(result)
+            }
+            Err(TryRecvError::Empty) => {
+                self.loading_frame = (self.loading_frame + 1) % LOADING_SPINNER.len();
+                Ok(ActionResult::NeedsRedraw)
+            }
+            Err(TryRecvError::Disconnected) => Err(InquireError::Custom(
+                "The loading source stopped without returning any options".into(),
+            )),
+        }
+    }
+
+    /// Replaces the options, applying the current filter input and keeping
+    /// the highlighted option when it is still available.
+    ///
+    /// Fails like the construction of the prompt when duplicates are
+    /// disallowed and two of the new options are displayed the same.
+    fn replace_options(&mut self, options: Vec<T>) -> InquireResult<ActionResult> {
+        let (string_options, rendered_options) = match_strings(
+            &options,
+            self.config.prestyled_options,
+            self.rendered_options.is_some(),
+            self.icons,
+            self.annotations,
+        );
+        if !self.config.allow_duplicates {
+            check_duplicates(&string_options)?;
+        }
+
+        let highlighted = self
+            .scored_options
+            .get(self.cursor_index)
+            .and_then(|index| Some((*index, self.string_options.get(*index)?.clone())));
+
+        self.options = options;
+        self.descriptions.clear();
+        let len = self.options.len();
+        self.marked.retain(|index| *index < len);
+        self.picks.retain(|index| *index < len);
+        self.string_options = string_options;
+        self.rendered_options = rendered_options;
+        self.scored_options = match self.filter_query() {
+            Some(query) => self.score_query(query),
+            None => (0..self.options.len()).collect(),
+        };
+
+        // the option is looked up at its previous index first, so that the
+        // cursor does not jump to an earlier duplicate of it
+        let highlighted_position = highlighted.and_then(|(highlighted_index, highlighted)| {
+            let matches = |index: &usize| self.string_options.get(*index) == Some(&highlighted);
+
+            self.scored_options
+                .iter()
+                .position(|index| *index == highlighted_index && matches(index))
+                .or_else(|| self.scored_options.iter().position(matches))
+        });
+
+        self.cursor_index = highlighted_position.unwrap_or_else(|| {
+            self.cursor_index
+                .min(self.scored_options.len().saturating_sub(1))
+        });
+        self.page_start = self.scroll_window_start();
+        self.page_pinned = false;
+        self.notify_highlight();
+
+        Ok(ActionResult::NeedsRedraw)
+    }
+
+    /// Thumb of the scrollbar rendered next to the page, measured in rendered
+    /// rows, so that options spanning several lines are accounted for.
+    fn scrollbar_thumb(&self, page: &Page<'_, ListOption<&T>>) -> Option<(usize, usize)> {
+        let rows = |index: &usize| match self.config.multiline_options {
+            true => self
+                .string_options
+                .get(*index)
+                .map(|option| option_lines(option))
+                .unwrap_or(1),
+            false => 1,
+        };
+
+        let total = self.scored_options.iter().map(rows).sum();
+        let cursor_row = self
+            .scored_options
+            .iter()
+            .take(self.cursor_index)
+            .map(rows)
+            .sum();
+        let page_len = page.content.iter().map(|option| rows(&option.index)).sum();
+
+        scrollbar_thumb(cursor_row, total, page_len)
+    }
+
+    /// Number of rows used by the prompt besides the list of options.
+    fn reserved_rows(&self) -> usize {
+        let optional_lines = [
+            self.title.is_some(),
+            self.filter_label.is_some() && self.input.is_some(),
+            self.footer.is_some(),
+            self.lazy_description.is_some() && self.config.layout == SelectLayout::Vertical,
+        ];
+
+        reserved_rows(&self.config, self.help_message.is_some(), optional_lines)
+    }
+
+    /// Page size after applying the configured row limit, if any.
+    fn page_size(&self) -> usize {
+        page_size(&self.config, self.reserved_rows())
+    }
+
+    /// Start of the page that keeps the cursor within the scroll margin,
+    /// scrolling from the current page as little as possible.
+    fn scroll_window_start(&self) -> usize {
+        match self.config.scroll_margin {
+            Some(margin) => scroll_window_start(
+                self.page_size(),
+                self.scored_options.len(),
+                self.cursor_index,
+                self.page_start,
+                margin,
+            ),
+            None => 0,
+        }
+    }
+
+    /// Calls the highlight hook if the highlighted option changed since the
+    /// last call.
+    fn notify_highlight(&mut self) {
+        let highlighted = self.scored_options.get(self.cursor_index).copied();
+
+        if highlighted == self.highlighted {
+            return;
+        }
+
+        self.highlighted = highlighted;
+
+        if let (Some(on_highlight), Some(index)) = (self.on_highlight, highlighted) {
+            on_highlight(ListOption::new(index, self.options.get(index).unwrap()));
+        }
+    }
+
+    /// Index of the highlighted option when its description is yet to be
+    /// computed.
+    fn pending_description(&self) -> Option<usize> {
+        self.lazy_description?;
+
+        self.scored_options
+            .get(self.cursor_index)
+            .copied()
+            .filter(|index| !self.descriptions.contains_key(index))
+    }
+
+    /// Index of the highlighted option when it requires a confirmation that
+    /// was not given yet.
+    fn unconfirmed_highlighted(&self) -> Option<usize> {
+        let (indices, _) = self.confirm_indices?;
+
+        self.scored_options
+            .get(self.cursor_index)
+            .copied()
+            .filter(|index| indices.contains(index) && self.confirming != Some(*index))
+    }
+
     fn has_answer_highlighted(&mut self) -> bool {
         self.scored_options.get(self.cursor_index).is_some()
     }
 
+    fn toggle_mark(&mut self) -> ActionResult {
+        match self.scored_options.get(self.cursor_index) {
+            Some(index) => {
+                if !self.marked.remove(index) {
+                    self.marked.insert(*index);
+                }
+
+                ActionResult::NeedsRedraw
+            }
+            None => ActionResult::Clean,
+        }
+    }
+
+    /// Runs the prompt, converting panics raised while it runs into
+    /// [InquireError::Custom] errors when configured to.
+    pub fn run<B: SelectBackend>(self, backend: &mut B) -> InquireResult<SelectOutput<T>> {
+        self.run_with_state(backend).0
+    }
+
+    /// Runs the prompt, returning its state when it finished along with the
+    /// result, even if it was canceled.
+    pub fn run_with_state<B: SelectBackend>(
+        mut self,
+        backend: &mut B,
+    ) -> (InquireResult<SelectOutput<T>>, SelectState) {
+        let result = self.run_catching_panics(backend);
+
+        if let Some(on_finish) = self.on_finish {
+            on_finish(match &result {
+                Ok(SelectOutput::Answer(SelectResult::Selected(answer), _)) => {
+                    Ok(Some(ListOption::new(answer.index, &answer.value)))
+                }
+                Ok(_) => Ok(None),
+                Err(err) => Err(err),
+            });
+        }
+
+        (result, self.state())
+    }
+
+    fn run_catching_panics<B: SelectBackend>(
+        &mut self,
+        backend: &mut B,
+    ) -> InquireResult<SelectOutput<T>> {
+        if !self.config.catch_closure_panics {
+            return self.prompt(backend);
+        }
+
+        match panic::catch_unwind(AssertUnwindSafe(|| self.prompt(backend))) {
+            Ok(result) => result,
+            Err(payload) => {
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .copied()
+                    .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                    .unwrap_or("unknown panic payload");
+
+                Err(InquireError::Custom(
+                    format!("Prompt panicked: {message}").into(),
+                ))
+            }
+        }
+    }
+
+    /// Makes Enter pick the highlighted option, or unpick it if it already
+    /// was, instead of submitting the prompt, which is finished by the
+    /// provided key instead.
+    pub fn sequential(mut self, done_key: Key) -> Self {
+        self.config.done_key = Some(done_key);
+        self
+    }
+
+    /// Makes Shift+Up and Shift+Down extend a range of options from the
+    /// highlighted one, the anchor, with Enter submitting the whole range.
+    pub fn ranged(mut self) -> Self {
+        self.config.range = true;
+        self
+    }
+
+    /// Positions of the first and last options of the range, in the
+    /// filtered list, spanning from the anchor to the cursor.
+    fn range_span(&self) -> (usize, usize) {
+        let anchor = self.range_anchor.unwrap_or(self.cursor_index);
+
+        (anchor.min(self.cursor_index), anchor.max(self.cursor_index))
+    }
+
+    /// Indexes of the options of the range, in the original list.
+    fn range_indexes(&self) -> BTreeSet<usize> {
+        let (start, end) = self.range_span();
+
+        self.scored_options
+            .get(start..=end)
+            .unwrap_or_default()
+            .iter()
+            .copied()
+            .collect()
+    }
+
+    fn extend_range(&mut self, up: bool) -> ActionResult {
+        self.range_anchor.get_or_insert(self.cursor_index);
+
+        match up {
+            true => self.move_cursor_up(1, false),
+            false => self.move_cursor_down(1, false),
+        }
+    }
+
+    fn state(&self) -> SelectState {
+        SelectState {
+            highlighted: self.scored_options.get(self.cursor_index).copied(),
+            filter_input: self
+                .input
+                .as_ref()
+                .map(|input| input.content().to_owned())
+                .unwrap_or_default(),
+        }
+    }
+
+    fn render_prompt_line<B: SelectBackend>(&self, backend: &mut B) -> io::Result<()> {
+        // the message is rendered in the border instead
+        let message = match self.config.border.is_some() && self.config.border_title {
+            true => "",
+            false => self.message,
+        };
+
+        match (self.filter_label, self.input.as_ref()) {
+            (Some(label), Some(input)) => {
+                backend.render_select_prompt(message, None, false, false)?;
+                backend.render_filter_input(
+                    label,
+                    input,
+                    self.config.filter_syntax_highlight,
+                    self.config.search_indicator,
+                )
+            }
+            (_, input) => backend.render_select_prompt(
+                message,
+                input,
+                self.config.filter_syntax_highlight,
+                self.config.search_indicator,
+            ),
+        }
+    }
+
+    fn toggle_pick(&mut self) {
+        if let Some(index) = self.scored_options.get(self.cursor_index).copied() {
+            if self.marked.remove(&index) {
+                self.picks.retain(|pick| *pick != index);
+            } else {
+                self.marked.insert(index);
+                self.picks.push(index);
+            }
+        }
+    }
+
+    fn get_picks(&mut self) -> Vec<ListOption<T>> {
+        let mut options = std::mem::take(&mut self.options)
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<_>>();
+
+        self.picks
+            .iter()
+            .filter_map(|index| {
+                let value = options.get_mut(*index)?.take()?;
+                Some(ListOption::new(*index, value))
+            })
+            .collect()
+    }
+
     fn get_final_answer(&mut self) -> ListOption<T> {
         // should only be called after current cursor index is validated
         // on has_answer_highlighted
@@ -122,40 +728,310 @@ where
         ListOption::new(index, value)
     }
 
-    fn run_scorer(&mut self) {
-        let content = match &self.input {
-            Some(input) => input.content(),
-            None => return,
-        };
+    /// Strings the filter input is matched against: the rendered text of
+    /// the options when filtering on it, their string values otherwise.
+    fn filter_strings(&self) -> &[String] {
+        self.rendered_options
+            .as_deref()
+            .unwrap_or(&self.string_options)
+    }
+
+    /// Filter input the options are matched against, trimmed if configured.
+    fn filter_query(&self) -> Option<&str> {
+        let content = self.input.as_ref()?.content();
 
-        let mut options = self
-            .options
+        match self.config.trim_filter {
+            true => Some(content.trim()),
+            false => Some(content),
+        }
+    }
+
+    /// Position, among the scored options, of the only option starting with
+    /// the filter input, if prefix auto-submit is enabled and enough
+    /// characters were typed.
+    fn unique_prefix_match(&self) -> Option<usize> {
+        if !self.config.prefix_autosubmit {
+            return None;
+        }
+
+        let prefix = self.filter_query()?.to_lowercase();
+        if prefix.chars().count() < Select::<T>::PREFIX_AUTOSUBMIT_MIN_LEN {
+            return None;
+        }
+
+        let mut matches = self
+            .string_options
             .iter()
             .enumerate()
-            .filter_map(|(i, opt)| {
-                (self.scorer)(content, opt, self.string_options.get(i).unwrap(), i)
-                    .map(|score| (i, score))
-            })
-            .collect::<Vec<(usize, i64)>>();
+            .filter(|(_, value)| value.to_lowercase().starts_with(&prefix));
 
-        options.sort_unstable_by_key(|(_idx, score)| Reverse(*score));
+        let (index, _) = matches.next()?;
+        if matches.next().is_some() {
+            return None;
+        }
 
-        let new_scored_options = options.iter().map(|(idx, _)| *idx).collect::<Vec<usize>>();
+        self.scored_options.iter().position(|i| *i == index)
+    }
+
+    /// Scores the options against the provided filter input, displaying all
+    /// of them while the input is shorter than the minimum query length.
+    fn score_query(&self, query: &str) -> Vec<usize> {
+        let min_query_len = self.config.min_query_len;
+        let show_all =
+            |input: &str| input.chars().count() < min_query_len || (self.show_all)(input);
+
+        score_options(
+            &self.options,
+            self.filter_strings(),
+            query,
+            self.scorer,
+            &show_all,
+            self.config.accesskeys,
+            self.weights,
+        )
+    }
+
+    fn run_scorer(&mut self) {
+        let query = match self.filter_query() {
+            Some(query) => query,
+            None => return,
+        };
+
+        let new_scored_options = self.score_query(query);
 
         if self.scored_options == new_scored_options {
             return;
         }
 
         self.scored_options = new_scored_options;
+        self.page_start = self.scroll_window_start();
+        self.page_pinned = false;
 
         if self.config.reset_cursor {
             let _ = self.update_cursor_position(0);
         } else if self.scored_options.len() <= self.cursor_index {
             let _ = self.update_cursor_position(self.scored_options.len().saturating_sub(1));
         }
+
+        self.notify_highlight();
+    }
+}
+
+/// Number of rows used by a select prompt besides the list of options, one
+/// for each line rendered, before wrapping. The optional lines are the ones
+/// rendered once when enabled, such as the title, the filter line and the
+/// footer.
+pub(super) fn reserved_rows<const N: usize>(
+    config: &SelectConfig,
+    help: bool,
+    optional_lines: [bool; N],
+) -> usize {
+    let prompt_rows = 1;
+    let optional_rows = optional_lines.iter().filter(|line| **line).count();
+    let help_rows = usize::from(help);
+    let separator_rows = help_rows * usize::from(config.separator.is_some());
+    let progress_rows = usize::from(config.progress.is_some());
+    let border_rows = 2 * usize::from(config.border.is_some());
+
+    prompt_rows + optional_rows + help_rows + separator_rows + progress_rows + border_rows
+}
+
+/// Rows left for the options by the configured row limit, if any, after the
+/// reserved ones. At least one row is always left.
+pub(super) fn option_rows(config: &SelectConfig, reserved_rows: usize) -> Option<usize> {
+    config
+        .max_rows
+        .map(|max_rows| max_rows.saturating_sub(reserved_rows).max(1))
+}
+
+/// Page size after applying the configured row limit, if any, to the rows
+/// left after the reserved ones.
+pub(super) fn page_size(config: &SelectConfig, reserved_rows: usize) -> usize {
+    match option_rows(config, reserved_rows) {
+        Some(available_rows) => config.page_size.min(available_rows),
+        None => config.page_size,
+    }
+}
+
+/// Number of lines an option is rendered on when multi-line options are
+/// enabled.
+pub(super) fn option_lines(option: &str) -> usize {
+    option.lines().count().max(1)
+}
+
+/// Opens the file set in the debug file environment variable, if any, or
+/// falls back to stderr when the debug environment variable enables it. The
+/// environment is only read once per prompt, so that rendering is not
+/// slowed down when debugging is disabled.
+fn open_debug_log() -> Option<DebugLog> {
+    if let Some(path) = env::var_os(DEBUG_FILE_ENV_VAR).filter(|path| !path.is_empty()) {
+        let file = OpenOptions::new().create(true).append(true).open(path);
+        return file.ok().map(DebugLog::File);
+    }
+
+    let value = env::var(DEBUG_ENV_VAR).ok()?;
+    (value == "1" || value.eq_ignore_ascii_case("true")).then_some(DebugLog::Stderr)
+}
+
+/// Scores the options against the provided input, returning the indexes of
+/// the matching options in the order they should be displayed.
+///
+/// All options are kept in their original order when the input satisfies the
+/// show-all predicate, sorted by descending weight if any. With access keys,
+/// options are matched by a case-insensitive prefix instead of the scorer.
+/// Options with the same score are sorted by descending weight.
+pub(super) fn score_options<T>(
+    options: &[T],
+    string_options: &[String],
+    input: &str,
+    scorer: Scorer<'_, T>,
+    show_all: ShowAllPredicate<'_>,
+    accesskeys: bool,
+    weights: &[f64],
+) -> Vec<usize> {
+    if show_all(input) {
+        return weighted_order(options.len(), weights);
+    }
+
+    let weight = |index: &usize| weights.get(*index).copied().unwrap_or_default();
+
+    let access_key_prefix = input.to_lowercase();
+    let score = |i: usize, opt: &T| {
+        let string_value = string_options.get(i).unwrap();
+
+        match accesskeys {
+            true => string_value
+                .to_lowercase()
+                .starts_with(&access_key_prefix)
+                .then_some(0),
+            false => scorer(input, opt, string_value, i),
+        }
+    };
+
+    let mut options = options
+        .iter()
+        .enumerate()
+        .filter_map(|(i, opt)| score(i, opt).map(|score| (i, score)))
+        .collect::<Vec<(usize, i64)>>();
+
+    options.sort_by(|(a, a_score), (b, b_score)| {
+        b_score
+            .cmp(a_score)
+            .then_with(|| weight(b).total_cmp(&weight(a)))
+    });
+
+    options.iter().map(|(idx, _)| *idx).collect()
+}
+
+/// Indexes of `len` options sorted by descending weight, keeping the original
+/// order of the options with the same weight.
+fn weighted_order(len: usize, weights: &[f64]) -> Vec<usize> {
+    let weight = |index: &usize| weights.get(*index).copied().unwrap_or_default();
+
+    let mut options = (0..len).collect::<Vec<usize>>();
+    options.sort_by(|a, b| weight(b).total_cmp(&weight(a)));
+
+    options
+}
+
+/// String values of the options, used to filter them. ANSI escape sequences
+/// of prestyled options are stripped, so that they are not matched.
+fn option_strings<T: Display>(options: &[T], prestyled: bool) -> Vec<String> {
+    options
+        .iter()
+        .map(|option| match prestyled {
+            true => option.to_string().ansi_stripped_chars().collect(),
+            false => option.to_string(),
+        })
+        .collect()
+}
+
+/// Returns the indexes of the first two options sharing the same string value.
+fn find_duplicate(string_options: &[String]) -> Option<(usize, usize)> {
+    let mut seen = HashMap::with_capacity(string_options.len());
+
+    string_options
+        .iter()
+        .enumerate()
+        .find_map(|(index, value)| seen.insert(value, index).map(|first| (first, index)))
+}
+
+/// Fails with an [InquireError::InvalidConfiguration] error naming the first
+/// two options sharing the same string value, if any.
+fn check_duplicates(string_options: &[String]) -> InquireResult<()> {
+    match find_duplicate(string_options) {
+        Some((first, second)) => Err(InquireError::InvalidConfiguration(format!(
+            "Options at indexes {first} and {second} are both displayed as {:?}",
+            string_options
+                .get(first)
+                .map(String::as_str)
+                .unwrap_or_default()
+        ))),
+        None => Ok(()),
     }
 }
 
+/// First row and number of rows of a page of `page_len` rows covered by the
+/// thumb of the scrollbar, whose size reflects the share of the `total` rows
+/// of the options displayed and position reflects the row of the cursor, or
+/// `None` when all the options fit the page.
+fn scrollbar_thumb(cursor_row: usize, total: usize, page_len: usize) -> Option<(usize, usize)> {
+    if total <= page_len {
+        return None;
+    }
+
+    let thumb_len = (page_len * page_len / total).max(1);
+    let thumb_start = (cursor_row * page_len / total).min(page_len - thumb_len);
+
+    Some((thumb_start, thumb_len))
+}
+
+/// Text of the options as rendered in the list, with their icon and
+/// annotation, computed once so that the options are not rendered again on
+/// every keystroke.
+fn rendered_strings(
+    string_options: &[String],
+    icons: Option<IconProvider<'_>>,
+    annotations: &[(&str, &str)],
+) -> Vec<String> {
+    string_options
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let icon = icons.and_then(|icons| icons(index, value));
+            let annotation = annotations
+                .iter()
+                .find(|(label, _)| label == value)
+                .map(|(_, annotation)| *annotation);
+
+            icon.as_deref()
+                .into_iter()
+                .chain(Some(value.as_str()))
+                .chain(annotation)
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect()
+}
+
+/// String values of the options, along with their rendered text when the
+/// filter input is matched against it. Shared by the prompt and
+/// [Select::filtered_indices](super::Select::filtered_indices), so that both
+/// match the same strings.
+pub(super) fn match_strings<T: Display>(
+    options: &[T],
+    prestyled: bool,
+    filter_on_rendered: bool,
+    icons: Option<IconProvider<'_>>,
+    annotations: &[(&str, &str)],
+) -> (Vec<String>, Option<Vec<String>>) {
+    let string_options = option_strings(options, prestyled);
+    let rendered_options =
+        filter_on_rendered.then(|| rendered_strings(&string_options, icons, annotations));
+
+    (string_options, rendered_options)
+}
+
 impl<'a, Backend, T> Prompt<Backend> for SelectPrompt<'a, T>
 where
     Backend: SelectBackend,
@@ -163,7 +1039,7 @@ where
 {
     type Config = SelectConfig;
     type InnerAction = SelectPromptAction;
-    type Output = ListOption<T>;
+    type Output = SelectOutput<T>;
 
     fn message(&self) -> &str {
         self.message
@@ -173,32 +1049,227 @@ where
         &self.config
     }
 
-    fn format_answer(&self, answer: &ListOption<T>) -> String {
-        (self.formatter)(answer.as_ref())
+    fn action_for_key(&self, key: Key) -> Option<Action<SelectPromptAction>> {
+        let key = match self.key_filter {
+            Some(key_filter) => key_filter(key)?,
+            None => key,
+        };
+
+        match self.config.cancel_key {
+            Some(cancel_key) if key == cancel_key => Some(Action::Cancel),
+            Some(_) if key == Key::Escape => {
+                SelectPromptAction::from_key(key, &self.config).map(Action::Inner)
+            }
+            _ => Action::from_key(key, &self.config),
+        }
+    }
+
+    fn format_answer(&self, answer: &SelectOutput<T>) -> String {
+        if self.config.hidden_answer {
+            return Select::<T>::HIDDEN_ANSWER_PLACEHOLDER.to_owned();
+        }
+
+        let formatted = match answer {
+            SelectOutput::Answer(SelectResult::Selected(option), _) => {
+                (self.formatter)(option.as_ref())
+            }
+            SelectOutput::Answer(SelectResult::New(text), _) => text.clone(),
+            SelectOutput::Range(start, end) => {
+                let format = |index: &usize| {
+                    (self.formatter)(ListOption::new(*index, self.options.get(*index).unwrap()))
+                };
+
+                match start == end {
+                    true => format(start),
+                    false => format!("{} - {}", format(start), format(end)),
+                }
+            }
+            SelectOutput::Picks(picks) => picks
+                .iter()
+                .map(|pick| (self.formatter)(pick.as_ref()))
+                .collect::<Vec<_>>()
+                .join(", "),
+        };
+
+        match self.input.as_ref().map(Input::content) {
+            Some(filter) if self.config.show_filter_in_answer && !filter.is_empty() => {
+                format!("[typed '{filter}'] → {formatted}")
+            }
+            _ => formatted,
+        }
     }
 
     fn setup(&mut self) -> InquireResult<()> {
+        if let Some(on_start) = self.on_start {
+            on_start();
+        }
+
+        let starting_option = self.scored_options.get(self.cursor_index).copied();
         self.run_scorer();
+
+        // with a starting filter input, the cursor stays on the starting
+        // option if it matches the filter
+        let starting_position = starting_option
+            .and_then(|option| self.scored_options.iter().position(|i| *i == option));
+        if let Some(position) = starting_position {
+            let _ = self.update_cursor_position(position);
+        }
+
+        // the first page is only scrolled once the starting filter input,
+        // if any, is applied, as scoring the options scrolls it back
+        if let Some(index) = self.ensure_visible {
+            self.scroll_to_option(index);
+        }
+
+        self.notify_highlight();
         Ok(())
     }
 
-    fn submit(&mut self) -> InquireResult<Option<ListOption<T>>> {
-        let answer = match self.has_answer_highlighted() {
-            true => Some(self.get_final_answer()),
-            false => None,
+    fn pre_cancel(&mut self) -> InquireResult<bool> {
+        // canceling a pending confirmation only dismisses it
+        Ok(self.confirming.take().is_none())
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<Self::Output>> {
+        // the done key only finishes the picks when handled right away, not
+        // on a later submission once the options are loaded
+        let finishing_picks = std::mem::take(&mut self.finishing_picks);
+
+        if self.loading.is_some() {
+            return Ok(None);
+        }
+
+        if self.config.done_key.is_some() {
+            if finishing_picks {
+                return Ok(Some(SelectOutput::Picks(self.get_picks())));
+            }
+
+            self.toggle_pick();
+            return Ok(None);
+        }
+
+        if let Some(index) = self.unconfirmed_highlighted() {
+            self.confirming = Some(index);
+            return Ok(None);
+        }
+
+        if self.config.range {
+            let (start, end) = self.range_span();
+
+            return Ok(self
+                .scored_options
+                .get(start)
+                .zip(self.scored_options.get(end))
+                .map(|(start, end)| SelectOutput::Range(*start, *end)));
+        }
+
+        let answer = match (self.has_answer_highlighted(), &self.input) {
+            (true, _) => Some(SelectResult::Selected(self.get_final_answer())),
+            (false, Some(input)) if self.create_new && !input.is_empty() => {
+                Some(SelectResult::New(input.content().to_string()))
+            }
+            (false, Some(_)) if self.config.error_on_empty_submit => {
+                return Err(InquireError::NoMatchingOption);
+            }
+            (false, _) => None,
         };
 
-        Ok(answer)
+        let marked = self.marked.iter().copied().collect();
+
+        Ok(answer.map(|answer| SelectOutput::Answer(answer, marked)))
+    }
+
+    fn persists_full_render(&self) -> bool {
+        // a hidden answer must not stay visible among the options
+        self.config.persist_full_render && !self.config.hidden_answer
+    }
+
+    fn tick_interval(&self) -> Option<Duration> {
+        if self.loading.is_some() {
+            return Some(LOADING_TICK_INTERVAL);
+        }
+
+        // the description is computed as soon as the placeholder is rendered
+        if self.pending_description().is_some() {
+            return Some(Duration::ZERO);
+        }
+
+        self.refresh.map(|(_, interval)| interval)
+    }
+
+    fn tick(&mut self) -> InquireResult<ActionResult> {
+        if self.loading.is_some() {
+            return self.poll_loading();
+        }
+
+        if let (Some(describe), Some(index)) = (self.lazy_description, self.pending_description()) {
+            let description = describe(ListOption::new(index, self.options.get(index).unwrap()));
+            self.descriptions.insert(index, description);
+
+            return Ok(ActionResult::NeedsRedraw);
+        }
+
+        match self.refresh {
+            Some((refresher, _)) => self.refresh_options(refresher),
+            None => Ok(ActionResult::Clean),
+        }
     }
 
     fn handle(&mut self, action: SelectPromptAction) -> InquireResult<ActionResult> {
+        if self.loading.is_some() {
+            return Ok(ActionResult::Clean);
+        }
+
+        // The copy confirmation is only displayed until the next key press,
+        // and so is the pending confirmation of a submission.
+        let had_copied = self.copied.take().is_some() | self.confirming.take().is_some();
+
+        // moving the cursor without extending the range collapses it
+        let collapsed_range = matches!(
+            action,
+            SelectPromptAction::MoveUp
+                | SelectPromptAction::MoveDown
+                | SelectPromptAction::PageUp
+                | SelectPromptAction::PageDown
+                | SelectPromptAction::MoveToStart
+                | SelectPromptAction::MoveToEnd
+        ) && self.range_anchor.take().is_some();
+
         let result = match action {
             SelectPromptAction::MoveUp => self.move_cursor_up(1, true),
             SelectPromptAction::MoveDown => self.move_cursor_down(1, true),
-            SelectPromptAction::PageUp => self.move_cursor_up(self.config.page_size, false),
-            SelectPromptAction::PageDown => self.move_cursor_down(self.config.page_size, false),
+            SelectPromptAction::PageUp => self.move_cursor_up(self.page_size(), false),
+            SelectPromptAction::PageDown => self.move_cursor_down(self.page_size(), false),
             SelectPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
             SelectPromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
+            SelectPromptAction::TypeAhead(c) => self.type_ahead(c),
+            SelectPromptAction::Descend => ActionResult::Submit,
+            SelectPromptAction::NavigateBack => return Err(InquireError::NavigateBack),
+            SelectPromptAction::CancelOnFocusLoss => return Err(InquireError::OperationCanceled),
+            SelectPromptAction::ToggleMark => self.toggle_mark(),
+            SelectPromptAction::FinishPicks => {
+                self.finishing_picks = true;
+                ActionResult::Submit
+            }
+            SelectPromptAction::AcceptSingle => match self.scored_options.len() {
+                1 => ActionResult::Submit,
+                _ if self.config.tab_paging => self.move_cursor_down(self.page_size(), false),
+                _ => ActionResult::Clean,
+            },
+            SelectPromptAction::ToggleHelp => {
+                self.show_help = !self.show_help;
+                ActionResult::NeedsRedraw
+            }
+            SelectPromptAction::CopyHighlighted => {
+                self.copied = self
+                    .scored_options
+                    .get(self.cursor_index)
+                    .and_then(|index| self.string_options.get(*index))
+                    .map(|value| clipboard::copy(value));
+                ActionResult::NeedsRedraw
+            }
+            SelectPromptAction::ExtendRangeUp => self.extend_range(true),
+            SelectPromptAction::ExtendRangeDown => self.extend_range(false),
 
             SelectPromptAction::FilterInput(input_action) => match self.input.as_mut() {
                 Some(input) => {
@@ -206,6 +1277,15 @@ where
 
                     if let InputActionResult::ContentChanged = result {
                         self.run_scorer();
+
+                        if self.config.accesskeys && self.scored_options.len() == 1 {
+                            return Ok(ActionResult::Submit);
+                        }
+
+                        if let Some(position) = self.unique_prefix_match() {
+                            let _ = self.update_cursor_position(position);
+                            return Ok(ActionResult::Submit);
+                        }
                     }
 
                     result.into()
@@ -214,27 +1294,251 @@ where
             },
         };
 
-        Ok(result)
+        match had_copied || collapsed_range {
+            true => Ok(result.merge(ActionResult::NeedsRedraw)),
+            false => Ok(result),
+        }
     }
 
     fn render(&self, backend: &mut Backend) -> InquireResult<()> {
         let prompt = &self.message;
 
-        backend.render_select_prompt(prompt, self.input.as_ref())?;
+        if let Some((current, total)) = self.config.progress {
+            backend.render_progress(current, total)?;
+        }
 
-        let choices = self
-            .scored_options
-            .iter()
-            .cloned()
-            .map(|i| ListOption::new(i, self.options.get(i).unwrap()))
-            .collect::<Vec<ListOption<&T>>>();
+        if let Some(title) = self.title {
+            backend.render_title(title)?;
+        }
+
+        if self.loading.is_some() {
+            let spinner = LOADING_SPINNER.get(self.loading_frame).unwrap();
+
+            backend.render_select_prompt(
+                prompt,
+                self.input.as_ref(),
+                self.config.filter_syntax_highlight,
+                self.config.search_indicator,
+            )?;
+            backend.render_extra_lines(&[format!("{spinner} Loading…")])?;
+
+            return Ok(());
+        }
 
-        let page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
+        // Only the options displayed on the current page are built, as the
+        // filtered list might contain thousands of options.
+        let to_option = |index: usize| ListOption::new(index, self.options.get(index).unwrap());
+
+        if self.config.layout == SelectLayout::Horizontal {
+            let highlighted = self
+                .scored_options
+                .get(self.cursor_index)
+                .map(|index| to_option(*index));
+
+            backend.render_horizontal_select_prompt(prompt, highlighted.as_ref())?;
+        } else {
+            if !self.config.reverse_layout {
+                self.render_prompt_line(backend)?;
+            }
+
+            let option_rows = option_rows(&self.config, self.reserved_rows())
+                .filter(|_| self.config.multiline_options);
+
+            let window = match (option_rows, self.config.scroll_margin) {
+                // multi-line options fill the rows left by the row limit
+                (Some(rows), _) => paginate_rows(
+                    self.page_size(),
+                    rows,
+                    &self.scored_options,
+                    self.cursor_index,
+                    |index| {
+                        self.string_options
+                            .get(*index)
+                            .map(|option| option_lines(option))
+                            .unwrap_or(1)
+                    },
+                ),
+                (None, Some(_)) => paginate_from(
+                    self.page_size(),
+                    &self.scored_options,
+                    self.scroll_window_start(),
+                    Some(self.cursor_index),
+                ),
+                (None, None) if self.page_pinned => paginate_from(
+                    self.page_size(),
+                    &self.scored_options,
+                    self.page_start,
+                    Some(self.cursor_index),
+                ),
+                (None, None) => paginate(
+                    self.page_size(),
+                    &self.scored_options,
+                    Some(self.cursor_index),
+                ),
+            };
+
+            let mut content = window
+                .content
+                .iter()
+                .map(|index| to_option(*index))
+                .collect::<Vec<ListOption<&T>>>();
+
+            // In the reverse layout, the first option is the closest to the
+            // prompt line, at the bottom of the page.
+            let page = if self.config.reverse_layout {
+                content.reverse();
+
+                Page {
+                    first: window.last,
+                    last: window.first,
+                    cursor: window
+                        .cursor
+                        .map(|cursor| content.len().saturating_sub(cursor + 1)),
+                    content: &content,
+                    total: window.total,
+                }
+            } else {
+                Page {
+                    first: window.first,
+                    last: window.last,
+                    content: &content,
+                    cursor: window.cursor,
+                    total: window.total,
+                }
+            };
+
+            if let Some(debug_log) = &self.debug_log {
+                let page_start = self.cursor_index.saturating_sub(window.cursor.unwrap_or(0));
+                debug_log.write_line(&format!(
+                    "select: filtered={} total={} cursor={} page={}..{}",
+                    self.scored_options.len(),
+                    self.options.len(),
+                    self.cursor_index,
+                    page_start,
+                    page_start + page.content.len(),
+                ));
+            }
+
+            let icons = self.icons.map(|icons| {
+                page.content
+                    .iter()
+                    .map(|option| {
+                        let value = self.string_options.get(option.index).unwrap();
+                        icons(option.index, value)
+                    })
+                    .collect::<Vec<_>>()
+            });
+
+            let statuses = self.status.map(|status| {
+                page.content
+                    .iter()
+                    .map(|option| status(option.index))
+                    .collect::<Vec<_>>()
+            });
+
+            let range = self.config.range.then(|| self.range_indexes());
+
+            let decorations = OptionDecorations {
+                underline_access_key: self.config.accesskeys,
+                recommended: self.config.recommended,
+                annotations: self.annotations,
+                marked: match &range {
+                    Some(range) => Some(range),
+                    None => (self.config.marking || self.config.done_key.is_some())
+                        .then_some(&self.marked),
+                },
+                icons: icons.as_deref(),
+                statuses: statuses.as_deref(),
+                prestyled: self.config.prestyled_options,
+                multiline: self.config.multiline_options,
+                scrollbar: self
+                    .config
+                    .scrollbar
+                    .then(|| self.scrollbar_thumb(&page))
+                    .flatten(),
+                border: self.config.border,
+            };
+
+            let pagination_lines = self
+                .pagination_renderer
+                .map(|pagination_renderer| pagination_renderer(&page));
+
+            if let Some(border) = self.config.border {
+                let title = self.config.border_title.then_some(self.message);
+                backend.render_border_top(border, title)?;
+            }
+
+            backend.render_options(page, &decorations)?;
+
+            if let Some(border) = self.config.border {
+                backend.render_border_bottom(border)?;
+            }
+
+            if let Some(lines) = pagination_lines {
+                backend.render_extra_lines(&lines)?;
+            }
+
+            if let Some(copied) = self.copied {
+                backend.render_clipboard_copy(copied)?;
+            }
+
+            let highlighted = self.scored_options.get(self.cursor_index);
+            if let (Some(_), Some(index)) = (self.lazy_description, highlighted) {
+                let description = self.descriptions.get(index).map(String::as_str);
+                backend.render_option_description(description.unwrap_or("…"))?;
+            }
+
+            if self.config.reverse_layout {
+                self.render_prompt_line(backend)?;
+            }
+        }
+
+        if let (Some(_), Some((_, message))) = (self.confirming, self.confirm_indices) {
+            backend.render_submit_confirmation(message)?;
+        }
+
+        if let Some(render_hook) = self.render_hook {
+            let choices = self
+                .scored_options
+                .iter()
+                .map(|index| to_option(*index))
+                .collect::<Vec<ListOption<&T>>>();
+
+            let context = RenderContext {
+                filtered_options: &choices,
+                cursor_index: self.cursor_index,
+                input: self.input.as_ref().map(Input::content).unwrap_or_default(),
+                terminal_size: backend.terminal_size(),
+            };
+
+            backend.render_extra_lines(&render_hook(&context))?;
+        }
+
+        if let (true, Some(help_message)) = (self.show_help, self.help_message) {
+            if let Some(separator) = self.config.separator {
+                backend.render_separator(separator)?;
+            }
+
+            let help_message = displayed_help_message(
+                help_message,
+                self.help_suffix,
+                &self.config,
+                self.input.is_some(),
+            );
+            backend.render_help_message(&help_message)?;
+        }
 
-        backend.render_options(page)?;
+        if let Some(footer) = self.footer {
+            let filtered_options = self.scored_options.len();
+            let context = FooterContext {
+                total_options: self.options.len(),
+                filtered_options,
+                hidden_options: self.options.len().saturating_sub(filtered_options),
+                cursor_index: self.cursor_index,
+                marked_options: self.marked.len(),
+            };
 
-        if let Some(help_message) = self.help_message {
-            backend.render_help_message(help_message)?;
+            backend.render_footer(&footer(&context))?;
         }
 
         Ok(())
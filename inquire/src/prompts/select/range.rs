@@ -0,0 +1,141 @@
+use std::fmt::Display;
+
+use crate::{
+    error::{InquireError, InquireResult},
+    formatter::OptionFormatter,
+    ui::RenderConfig,
+    Select, SelectLayout,
+};
+
+/// Prompt to pick a contiguous range of options, such as the lines of a log
+/// to extract, built on a [Select] prompt.
+///
+/// The user extends the range from the highlighted option, its anchor, with
+/// Shift+Up and Shift+Down, and submits it with Enter. The options of the
+/// range are flagged with the
+/// [marked option indicator](crate::ui::RenderConfig::marked_option_indicator).
+/// Moving the cursor without Shift collapses the range to the highlighted
+/// option.
+///
+/// The prompt returns the indexes of the first and last options of the
+/// range, both included.
+///
+/// Filtering, weights, marking and the horizontal layout are disabled, so
+/// that the range is contiguous in the original list. The other settings of
+/// [Select] are kept when creating a range prompt from one with
+/// [RangeSelect::from].
+///
+/// ```no_run
+/// use inquire::RangeSelect;
+///
+/// let lines = vec!["boot", "mount", "network up", "login"];
+///
+/// let (first, last) = RangeSelect::new("Lines to extract:", lines).prompt()?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Clone)]
+pub struct RangeSelect<'a, T> {
+    /// Select prompt whose settings are used to render the options.
+    pub select: Select<'a, T>,
+}
+
+impl<'a, T> RangeSelect<'a, T>
+where
+    T: Display,
+{
+    /// Creates a [RangeSelect] with the provided message and options, along
+    /// with the default configuration values of [Select].
+    pub fn new(message: &'a str, options: Vec<T>) -> Self {
+        Self::from(Select::new(message, options))
+    }
+
+    /// Sets the help message of the prompt.
+    pub fn with_help_message(mut self, message: &'a str) -> Self {
+        self.select.help_message = Some(message);
+        self
+    }
+
+    /// Removes the set help message.
+    pub fn without_help_message(mut self) -> Self {
+        self.select.help_message = None;
+        self
+    }
+
+    /// Sets the page size.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.select.page_size = page_size;
+        self
+    }
+
+    /// Sets the starting cursor index, which is also the anchor of the range
+    /// until the cursor moves.
+    pub fn with_starting_cursor(mut self, starting_cursor: usize) -> Self {
+        self.select.starting_cursor = starting_cursor;
+        self
+    }
+
+    /// Sets the formatter of the first and last options of the submitted
+    /// range, displayed as the answer.
+    pub fn with_formatter(mut self, formatter: OptionFormatter<'a, T>) -> Self {
+        self.select.formatter = formatter;
+        self
+    }
+
+    /// Sets the provided color theme to this prompt.
+    pub fn with_render_config(mut self, render_config: RenderConfig<'a>) -> Self {
+        self.select.render_config = render_config;
+        self
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns the indexes of the first and last options of the submitted
+    /// range, both included.
+    pub fn prompt(self) -> InquireResult<(usize, usize)> {
+        let mut backend = self.select.default_backend()?;
+        self.select.prompt_range_with_backend(&mut backend)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_skippable(self) -> InquireResult<Option<(usize, usize)>> {
+        match self.prompt() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn prompt_with_backend<B: crate::ui::SelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<(usize, usize)> {
+        self.select.prompt_range_with_backend(backend)
+    }
+}
+
+impl<'a, T> From<Select<'a, T>> for RangeSelect<'a, T>
+where
+    T: Display,
+{
+    /// Creates a range prompt with the settings of the provided [Select],
+    /// disabling the settings that would break the contiguity of the range.
+    fn from(mut select: Select<'a, T>) -> Self {
+        select.filter_input_enabled = false;
+        select.weights = &[];
+        select.marking = false;
+        select.create_new = false;
+        select.layout = SelectLayout::Vertical;
+
+        Self { select }
+    }
+}
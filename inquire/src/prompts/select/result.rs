@@ -0,0 +1,23 @@
+use crate::list_option::ListOption;
+
+/// Answer of a [Select](crate::Select) prompt that may create new options,
+/// returned by [Select::prompt_result](crate::Select::prompt_result).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SelectResult<T> {
+    /// The user selected one of the options.
+    Selected(ListOption<T>),
+
+    /// The user submitted filter input that matched none of the options,
+    /// which contains the typed text.
+    New(String),
+}
+
+impl<T> SelectResult<T> {
+    /// Returns the selected option, or `None` if the answer is new text.
+    pub fn selected(self) -> Option<ListOption<T>> {
+        match self {
+            SelectResult::Selected(option) => Some(option),
+            SelectResult::New(_) => None,
+        }
+    }
+}
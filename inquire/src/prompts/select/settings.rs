@@ -0,0 +1,256 @@
+use std::time::Duration;
+
+use crate::{ui::Key, BorderStyle, CursorOverflow, Select, SelectLayout};
+
+type Defaults = Select<'static, &'static str>;
+
+/// Plain-data settings of a [Select] prompt, to be assembled from a
+/// configuration file, for example, and applied at once with
+/// [Select::with_settings].
+///
+/// Only the settings that are neither closures nor borrowed from other data
+/// are available: the scorer, formatter, hooks, annotations and render config
+/// are set on the [Select] itself. The default value of each field is the
+/// default of the matching [Select] field.
+///
+/// With the `serde` feature, the settings can be serialized and deserialized,
+/// fields missing from the deserialized data taking their default value.
+///
+/// ```no_run
+/// use inquire::{Select, SelectSettings};
+///
+/// let settings = SelectSettings {
+///     page_size: 10,
+///     help_message: Some(String::from("Loaded from the config file")),
+///     ..Default::default()
+/// };
+///
+/// let env = Select::new("Environment:", vec!["staging", "production"])
+///     .with_settings(&settings)
+///     .with_formatter(&|env| env.value.to_uppercase())
+///     .prompt()?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct SelectSettings {
+    /// See [Select::help_message].
+    pub help_message: Option<String>,
+
+    /// See [Select::help_suffix].
+    pub help_suffix: Option<String>,
+
+    /// See [Select::help_on_demand].
+    pub help_on_demand: bool,
+
+    /// See [Select::page_size].
+    pub page_size: usize,
+
+    /// See [Select::max_rows].
+    pub max_rows: Option<usize>,
+
+    /// See [Select::scroll_margin].
+    pub scroll_margin: Option<usize>,
+
+    /// See [Select::ensure_visible].
+    pub ensure_visible: Option<usize>,
+
+    /// See [Select::vim_mode].
+    pub vim_mode: bool,
+
+    /// See [Select::tab_paging].
+    pub tab_paging: bool,
+
+    /// See [Select::hierarchical_nav].
+    pub hierarchical_nav: bool,
+
+    /// See [Select::accesskeys].
+    pub accesskeys: bool,
+
+    /// See [Select::prefix_autosubmit].
+    pub prefix_autosubmit: bool,
+
+    /// See [Select::separator].
+    pub separator: Option<char>,
+
+    /// See [Select::starting_cursor].
+    pub starting_cursor: usize,
+
+    /// See [Select::recommended].
+    pub recommended: Option<usize>,
+
+    /// See [Select::cursor_overflow].
+    pub cursor_overflow: CursorOverflow,
+
+    /// See [Select::starting_filter_input].
+    pub starting_filter_input: Option<String>,
+
+    /// See [Select::reset_cursor].
+    pub reset_cursor: bool,
+
+    /// See [Select::title].
+    pub title: Option<String>,
+
+    /// See [Select::progress].
+    pub progress: Option<(usize, usize)>,
+
+    /// See [Select::filter_label].
+    pub filter_label: Option<String>,
+
+    /// See [Select::filter_placeholder].
+    pub filter_placeholder: Option<String>,
+
+    /// See [Select::typeahead].
+    pub typeahead: bool,
+
+    /// See [Select::create_new].
+    pub create_new: bool,
+
+    /// See [Select::filter_input_enabled].
+    pub filter_input_enabled: bool,
+
+    /// See [Select::trim_filter].
+    pub trim_filter: bool,
+
+    /// See [Select::min_query_len].
+    pub min_query_len: usize,
+
+    /// See [Select::hidden_answer].
+    pub hidden_answer: bool,
+
+    /// See [Select::show_filter_in_answer].
+    pub show_filter_in_answer: bool,
+
+    /// See [Select::allow_duplicates].
+    pub allow_duplicates: bool,
+
+    /// See [Select::marking].
+    pub marking: bool,
+
+    /// See [Select::tab_accepts_single].
+    pub tab_accepts_single: bool,
+
+    /// See [Select::error_on_empty_submit].
+    pub error_on_empty_submit: bool,
+
+    /// See [Select::filter_syntax_highlight].
+    pub filter_syntax_highlight: bool,
+
+    /// See [Select::search_indicator].
+    pub search_indicator: bool,
+
+    /// See [Select::max_filter_len].
+    pub max_filter_len: Option<usize>,
+
+    /// See [Select::cancel_key].
+    pub cancel_key: Option<Key>,
+
+    /// See [Select::alternate_screen].
+    pub alternate_screen: bool,
+
+    /// See [Select::tty_input].
+    pub tty_input: bool,
+
+    /// See [Select::cancel_on_focus_loss].
+    pub cancel_on_focus_loss: bool,
+
+    /// See [Select::stdout_echo].
+    pub stdout_echo: bool,
+
+    /// See [Select::reverse_layout].
+    pub reverse_layout: bool,
+
+    /// See [Select::catch_closure_panics].
+    pub catch_closure_panics: bool,
+
+    /// See [Select::prestyled_options].
+    pub prestyled_options: bool,
+
+    /// See [Select::filter_on_rendered].
+    pub filter_on_rendered: bool,
+
+    /// See [Select::multiline_options].
+    pub multiline_options: bool,
+
+    /// See [Select::scrollbar].
+    pub scrollbar: bool,
+
+    /// See [Select::border].
+    pub border: Option<BorderStyle>,
+
+    /// See [Select::border_title].
+    pub border_title: bool,
+
+    /// See [Select::persist_full_render].
+    pub persist_full_render: bool,
+
+    /// See [Select::clipboard_copy].
+    pub clipboard_copy: bool,
+
+    /// See [Select::escape_timeout].
+    pub escape_timeout: Option<Duration>,
+
+    /// See [Select::layout].
+    pub layout: SelectLayout,
+}
+
+impl Default for SelectSettings {
+    fn default() -> Self {
+        Self {
+            help_message: Defaults::DEFAULT_HELP_MESSAGE.map(String::from),
+            help_suffix: None,
+            help_on_demand: Defaults::DEFAULT_HELP_ON_DEMAND,
+            page_size: Defaults::DEFAULT_PAGE_SIZE,
+            max_rows: None,
+            scroll_margin: None,
+            ensure_visible: None,
+            vim_mode: crate::config::get_vim_mode(),
+            tab_paging: Defaults::DEFAULT_TAB_PAGING,
+            hierarchical_nav: Defaults::DEFAULT_HIERARCHICAL_NAV,
+            accesskeys: Defaults::DEFAULT_ACCESSKEYS,
+            prefix_autosubmit: Defaults::DEFAULT_PREFIX_AUTOSUBMIT,
+            separator: None,
+            starting_cursor: Defaults::DEFAULT_STARTING_CURSOR,
+            recommended: None,
+            cursor_overflow: Defaults::DEFAULT_CURSOR_OVERFLOW,
+            starting_filter_input: None,
+            reset_cursor: Defaults::DEFAULT_RESET_CURSOR,
+            title: None,
+            progress: None,
+            filter_label: None,
+            filter_placeholder: None,
+            typeahead: Defaults::DEFAULT_TYPEAHEAD,
+            create_new: Defaults::DEFAULT_CREATE_NEW,
+            filter_input_enabled: Defaults::DEFAULT_FILTER_INPUT_ENABLED,
+            trim_filter: Defaults::DEFAULT_TRIM_FILTER,
+            min_query_len: Defaults::DEFAULT_MIN_QUERY_LEN,
+            hidden_answer: Defaults::DEFAULT_HIDDEN_ANSWER,
+            show_filter_in_answer: Defaults::DEFAULT_SHOW_FILTER_IN_ANSWER,
+            allow_duplicates: Defaults::DEFAULT_ALLOW_DUPLICATES,
+            marking: Defaults::DEFAULT_MARKING,
+            tab_accepts_single: Defaults::DEFAULT_TAB_ACCEPTS_SINGLE,
+            error_on_empty_submit: Defaults::DEFAULT_ERROR_ON_EMPTY_SUBMIT,
+            filter_syntax_highlight: Defaults::DEFAULT_FILTER_SYNTAX_HIGHLIGHT,
+            search_indicator: Defaults::DEFAULT_SEARCH_INDICATOR,
+            max_filter_len: None,
+            cancel_key: None,
+            alternate_screen: Defaults::DEFAULT_ALTERNATE_SCREEN,
+            tty_input: Defaults::DEFAULT_TTY_INPUT,
+            cancel_on_focus_loss: Defaults::DEFAULT_CANCEL_ON_FOCUS_LOSS,
+            stdout_echo: Defaults::DEFAULT_STDOUT_ECHO,
+            reverse_layout: Defaults::DEFAULT_REVERSE_LAYOUT,
+            catch_closure_panics: Defaults::DEFAULT_CATCH_CLOSURE_PANICS,
+            prestyled_options: Defaults::DEFAULT_PRESTYLED_OPTIONS,
+            filter_on_rendered: Defaults::DEFAULT_FILTER_ON_RENDERED,
+            multiline_options: Defaults::DEFAULT_MULTILINE_OPTIONS,
+            scrollbar: Defaults::DEFAULT_SCROLLBAR,
+            border: None,
+            border_title: Defaults::DEFAULT_BORDER_TITLE,
+            persist_full_render: Defaults::DEFAULT_PERSIST_FULL_RENDER,
+            clipboard_copy: Defaults::DEFAULT_CLIPBOARD_COPY,
+            escape_timeout: None,
+            layout: Defaults::DEFAULT_LAYOUT,
+        }
+    }
+}
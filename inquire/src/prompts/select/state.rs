@@ -0,0 +1,17 @@
+/// State of a [Select](crate::Select) prompt when it finished, whether it was
+/// submitted or canceled, returned by
+/// [Select::prompt_with_state](crate::Select::prompt_with_state).
+///
+/// The state can be restored in the next prompt with
+/// [Select::with_state](crate::Select::with_state), e.g. to resume where the
+/// user left off when re-prompting after a cancel.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SelectState {
+    /// Index, in the list of options, of the option highlighted when the
+    /// prompt finished, or `None` if no option matched the filter input.
+    pub highlighted: Option<usize>,
+
+    /// Content of the filter input when the prompt finished. Empty when
+    /// filtering is disabled.
+    pub filter_input: String,
+}
@@ -0,0 +1,23 @@
+/// Status of an option of a [Select](crate::Select) prompt, rendered as a
+/// colored indicator before the option, e.g. the state of a service.
+///
+/// The indicators of each status are set by
+/// [RenderConfig::with_option_status](crate::ui::RenderConfig::with_option_status).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum StatusKind {
+    /// The option is in a good state, such as a running service.
+    Success,
+
+    /// The option needs attention, without being failed.
+    Warning,
+
+    /// The option is in a failed state.
+    Failure,
+
+    /// The state of the option is being updated.
+    Pending,
+
+    /// The state of the option is not known.
+    #[default]
+    Unknown,
+}
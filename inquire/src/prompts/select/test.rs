@@ -1,9 +1,18 @@
+use std::{cell::RefCell, rc::Rc, sync::mpsc, time::Duration};
+
 use crate::{
+    clipboard::test::CLIPBOARD,
     formatter::OptionFormatter,
     list_option::ListOption,
-    test::fake_backend,
-    ui::{Key, KeyModifiers},
-    Select,
+    test::{fake_backend, fake_backend_with_timeouts, fake_backend_with_width},
+    type_aliases::{
+        DescriptionProvider, FinishHook, FooterRenderer, HighlightHook, IconProvider, KeyFilter,
+        LoadingSource, PaginationRenderer, Refresher, RenderHook, Scorer, StartHook,
+        StatusProvider,
+    },
+    ui::{Key, KeyModifiers, RenderConfig, StatusRenderConfig, Styled},
+    BorderStyle, CursorOverflow, InquireError, OwnedSelect, RangeSelect, Select, SelectLayout,
+    SelectResult, SelectSettings, StatusKind,
 };
 
 #[test]
@@ -142,3 +151,2576 @@ fn chars_do_not_affect_prompt_without_filtering() {
 
     assert_eq!(ListOption::new(0, "Banana"), ans);
 }
+
+#[test]
+fn max_rows_caps_page_size() {
+    let mut backend = fake_backend(vec![Key::PageDown(KeyModifiers::NONE), Key::Enter]);
+
+    let options = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+    let ans = Select::new("Question", options)
+        .with_page_size(5)
+        .with_max_rows(4)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    // prompt and help message take two rows, leaving two for the options.
+    assert_eq!(ListOption::new(2, 3), ans);
+}
+
+#[test]
+fn max_rows_does_not_increase_page_size() {
+    let mut backend = fake_backend(vec![Key::PageDown(KeyModifiers::NONE), Key::Enter]);
+
+    let options = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+    let ans = Select::new("Question", options)
+        .with_page_size(3)
+        .with_max_rows(20)
+        .without_help_message()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(3, 4), ans);
+}
+
+#[test]
+fn question_mark_toggles_help_instead_of_filtering_when_on_demand() {
+    let mut backend = fake_backend(vec![
+        Key::Char('?', KeyModifiers::SHIFT),
+        Key::Char('?', KeyModifiers::SHIFT),
+        Key::Enter,
+    ]);
+
+    let options = vec!["Why", "Why?", "Why??"];
+
+    let ans = Select::new("Question", options)
+        .with_help_on_demand(true)
+        .with_help_message("Pick one")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "Why"), ans);
+    // the filter input stays empty while the help message is toggled
+    assert_eq!(
+        &[
+            "? Question  \n> Why\n  Why?\n  Why??",
+            "? Question  \n> Why\n  Why?\n  Why??\n[Pick one]",
+            "? Question  \n> Why\n  Why?\n  Why??",
+        ],
+        backend.rendered_frames().get(..3).unwrap()
+    );
+}
+
+#[test]
+fn tab_and_back_tab_move_by_pages() {
+    let mut backend = fake_backend(vec![Key::Tab, Key::Tab, Key::BackTab, Key::Enter]);
+
+    let options = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+    let ans = Select::new("Question", options)
+        .with_page_size(3)
+        .with_tab_paging(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(3, 4), ans);
+}
+
+#[test]
+fn tab_does_nothing_by_default() {
+    let mut backend = fake_backend(vec![Key::Tab, Key::Enter]);
+
+    let options = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+    let ans = Select::new("Question", options)
+        .with_page_size(3)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, 1), ans);
+}
+
+#[test]
+fn render_hook_receives_current_state() {
+    let mut backend = fake_backend(vec![
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let calls = RefCell::new(vec![]);
+    let render_hook: RenderHook<'_, &str> = &|ctx| {
+        let indexes = ctx.filtered_options.iter().map(|o| o.index).collect();
+        calls
+            .borrow_mut()
+            .push((ctx.input.to_string(), indexes, ctx.cursor_index));
+        vec![]
+    };
+
+    let ans = Select::new("Question", vec!["Apple", "Kiwi", "Banana"])
+        .with_render_hook(render_hook)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "Banana"), ans);
+    assert_eq!(
+        vec![
+            (String::new(), vec![0, 1, 2], 0),
+            (String::from("a"), vec![0, 2], 0),
+            (String::from("a"), vec![0, 2], 1),
+        ],
+        *calls.borrow()
+    );
+}
+
+#[test]
+fn options_with_equal_scores_keep_original_order() {
+    let mut backend = fake_backend(vec![
+        Key::Char('x', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let scorer: Scorer<'_, &str> = &|input, _option, string_value, _idx| match (
+        string_value.starts_with(input),
+        string_value.contains(input),
+    ) {
+        (true, _) => Some(1),
+        (false, true) => Some(0),
+        (false, false) => None,
+    };
+
+    let options = vec!["xa", "ax", "bx", "xb", "ab"];
+
+    let ans = Select::new("Question", options)
+        .with_scorer(scorer)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "ax"), ans);
+}
+
+#[test]
+fn answer_borrows_from_original_options() {
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let fruits = [String::from("Banana"), String::from("Apple")];
+    let options = fruits.iter().map(String::as_str).collect();
+
+    let ans = Select::new("Question", options)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(1, ans.index);
+    assert!(std::ptr::eq(fruits.get(1).unwrap().as_str(), ans.value));
+}
+
+#[test]
+fn backspace_on_empty_filter_does_not_reset_cursor() {
+    let mut backend = fake_backend(vec![Key::Backspace, Key::Backspace, Key::Enter]);
+
+    let options = vec![1, 2, 3];
+
+    let ans = Select::new("Question", options)
+        .with_starting_cursor(2)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, 3), ans);
+}
+
+#[test]
+fn accesskeys_submit_unique_prefix_match() {
+    let mut backend = fake_backend(vec![Key::Char('A', KeyModifiers::SHIFT)]);
+
+    let ans = Select::new("Question", vec!["Apple", "Banana", "Blueberry"])
+        .with_accesskeys(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "Apple"), ans);
+}
+
+#[test]
+fn accesskeys_narrow_ambiguous_matches() {
+    let mut backend = fake_backend(vec![
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Char('l', KeyModifiers::NONE),
+    ]);
+
+    let ans = Select::new("Question", vec!["Apple", "Banana", "Blueberry"])
+        .with_accesskeys(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "Blueberry"), ans);
+}
+
+#[test]
+fn options_are_paginated_below_the_prompt() {
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let options = vec!["Banana", "Apple", "Strawberry", "Grapes", "Lemon"];
+
+    Select::new("Fruit?", options)
+        .with_page_size(3)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let frames = backend.rendered_frames();
+    assert_eq!(
+        &[
+            "? Fruit?  \n> Banana\n  Apple\nv Strawberry\n[↑↓ to move, enter to select, type to filter]",
+            "? Fruit?  \n  Banana\n> Apple\nv Strawberry\n[↑↓ to move, enter to select, type to filter]",
+            "> Fruit? Apple",
+        ],
+        frames
+    );
+}
+
+#[test]
+fn options_start_on_their_own_line_without_filtering() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    Select::new("Fruit?", vec!["Banana", "Apple"])
+        .without_filtering()
+        .without_help_message()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        &["? Fruit?\n> Banana\n  Apple", "> Fruit? Banana"],
+        backend.rendered_frames()
+    );
+}
+
+#[test]
+fn space_is_written_to_filter_instead_of_submitting() {
+    let mut backend = fake_backend(vec![
+        Key::Char('w', KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Char('y', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let scorer: Scorer<'_, &str> =
+        &|input, _option, string_value, _idx| string_value.contains(input).then_some(0);
+
+    let ans = Select::new("Question", vec!["Newark", "new york"])
+        .with_scorer(scorer)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "new york"), ans);
+}
+
+#[test]
+fn separator_is_drawn_between_options_and_help_message() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    Select::new("Fruit?", vec!["Banana", "Apple"])
+        .without_filtering()
+        .with_help_message("help")
+        .with_separator('-')
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    // the rule spans the terminal width, which depends on the environment
+    // running the tests
+    let frame = backend.rendered_frames().first().unwrap();
+    let lines = frame.lines().collect::<Vec<&str>>();
+
+    assert_eq!(5, lines.len());
+    assert_eq!(Some(&"  Apple"), lines.get(2));
+    assert!(lines
+        .get(3)
+        .is_some_and(|l| !l.is_empty() && l.chars().all(|c| c == '-')));
+    assert_eq!(Some(&"[help]"), lines.get(4));
+}
+
+#[test]
+fn separator_is_not_drawn_without_help_message() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    Select::new("Fruit?", vec!["Banana", "Apple"])
+        .without_filtering()
+        .without_help_message()
+        .with_separator('-')
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        Some(&String::from("? Fruit?\n> Banana\n  Apple")),
+        backend.rendered_frames().first()
+    );
+}
+
+#[test]
+fn scroll_margin_keeps_page_until_cursor_reaches_margin() {
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec!["a", "b", "c", "d", "e", "f", "g", "h"];
+
+    let ans = Select::new("Letter?", options)
+        .without_filtering()
+        .without_help_message()
+        .with_page_size(5)
+        .with_scroll_margin(1)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(3, "d"), ans);
+
+    let frames = backend.rendered_frames();
+    assert_eq!(
+        Some(&String::from("? Letter?\n  a\n  b\n  c\n> d\nv e")),
+        frames.get(3)
+    );
+    assert_eq!(
+        Some(&String::from("? Letter?\n^ b\n  c\n  d\n> e\nv f")),
+        frames.get(4)
+    );
+    assert_eq!(
+        Some(&String::from("? Letter?\n^ b\n  c\n> d\n  e\nv f")),
+        frames.get(5)
+    );
+}
+
+#[test]
+fn highlight_hook_is_not_called_on_no_op_moves() {
+    let mut backend = fake_backend(vec![
+        Key::PageUp(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::End,
+        Key::End,
+        Key::Enter,
+    ]);
+
+    let highlights = RefCell::new(vec![]);
+    let on_highlight: HighlightHook<'_, &str> =
+        &|option| highlights.borrow_mut().push(option.index);
+
+    Select::new("Question", vec!["a", "b", "c"])
+        .without_filtering()
+        .with_on_highlight(on_highlight)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![0, 1, 2], highlights.into_inner());
+}
+
+#[test]
+fn highlight_hook_is_called_when_filter_changes_highlighted_option() {
+    let mut backend = fake_backend(vec![
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Char('n', KeyModifiers::NONE),
+        Key::Backspace,
+        Key::Enter,
+    ]);
+
+    let scorer: Scorer<'_, &str> =
+        &|input, _option, string_value, _idx| string_value.contains(input).then_some(0);
+
+    let highlights = RefCell::new(vec![]);
+    let on_highlight: HighlightHook<'_, &str> =
+        &|option| highlights.borrow_mut().push(option.index);
+
+    let ans = Select::new("Question", vec!["apple", "banana", "cherry"])
+        .with_scorer(scorer)
+        .with_on_highlight(on_highlight)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "apple"), ans);
+    assert_eq!(vec![0, 1, 0], highlights.into_inner());
+}
+
+#[test]
+fn lifecycle_hooks_are_called_around_the_prompt() {
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let events = RefCell::new(vec![]);
+    let on_start: StartHook<'_> = &|| events.borrow_mut().push(String::from("start"));
+    let on_highlight: HighlightHook<'_, &str> = &|option| {
+        events
+            .borrow_mut()
+            .push(format!("highlight {}", option.index));
+    };
+    let on_finish: FinishHook<'_, &str> = &|result| {
+        let answer = result.unwrap().unwrap();
+        events.borrow_mut().push(format!("finish {}", answer.value));
+    };
+
+    Select::new("Question", vec!["a", "b", "c"])
+        .on_start(on_start)
+        .with_on_highlight(on_highlight)
+        .on_finish(on_finish)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec!["start", "highlight 0", "highlight 1", "finish b"],
+        events.into_inner()
+    );
+}
+
+#[test]
+fn finish_hook_receives_cancellation() {
+    let mut backend = fake_backend(vec![Key::Escape]);
+
+    let canceled = RefCell::new(false);
+    let on_finish: FinishHook<'_, &str> = &|result| {
+        *canceled.borrow_mut() = matches!(result, Err(InquireError::OperationCanceled));
+    };
+
+    let ans = Select::new("Question", vec!["a", "b", "c"])
+        .on_finish(on_finish)
+        .prompt_with_backend(&mut backend);
+
+    assert!(ans.is_err());
+    assert!(canceled.into_inner());
+}
+
+#[test]
+fn filter_input_is_rendered_on_its_own_line_with_label() {
+    let mut backend = fake_backend(vec![Key::Char('a', KeyModifiers::NONE), Key::Enter]);
+
+    Select::new("Fruit?", vec!["Banana", "Apple"])
+        .without_help_message()
+        .with_filter_label("Search:")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        Some(&String::from("? Fruit?\nSearch: a \n> Apple\n  Banana")),
+        backend.rendered_frames().get(1)
+    );
+}
+
+#[test]
+fn hierarchical_nav_submits_with_right_arrow() {
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+    ]);
+
+    let ans = Select::new("Menu", vec!["Files", "Settings"])
+        .with_hierarchical_nav(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "Settings"), ans);
+}
+
+#[test]
+fn hierarchical_nav_goes_back_with_left_arrow() {
+    let mut backend = fake_backend(vec![Key::Left(KeyModifiers::NONE)]);
+
+    let ans = Select::new("Menu", vec!["Files", "Settings"])
+        .with_hierarchical_nav(true)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::NavigateBack)));
+}
+
+#[test]
+fn hierarchical_nav_uses_hl_in_vim_mode() {
+    let mut backend = fake_backend(vec![
+        Key::Char('j', KeyModifiers::NONE),
+        Key::Char('l', KeyModifiers::NONE),
+    ]);
+
+    let ans = Select::new("Menu", vec!["Files", "Settings"])
+        .with_vim_mode(true)
+        .with_hierarchical_nav(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "Settings"), ans);
+
+    let mut backend = fake_backend(vec![Key::Char('h', KeyModifiers::NONE)]);
+
+    let ans = Select::new("Menu", vec!["Files", "Settings"])
+        .with_vim_mode(true)
+        .with_hierarchical_nav(true)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::NavigateBack)));
+}
+
+#[test]
+fn focus_loss_cancels_prompt_when_enabled() {
+    let mut backend = fake_backend(vec![Key::FocusLost]);
+
+    let ans = Select::new("Question", vec!["a", "b"])
+        .with_cancel_on_focus_loss(true)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+}
+
+#[test]
+fn focus_loss_is_ignored_by_default() {
+    let mut backend = fake_backend(vec![Key::FocusLost, Key::Enter]);
+
+    let ans = Select::new("Question", vec!["a", "b"])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "a"), ans);
+}
+
+#[test]
+fn horizontal_layout_renders_highlighted_option_on_prompt_line() {
+    let mut backend = fake_backend(vec![Key::Right(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = Select::new("Fruit?", vec!["Banana", "Apple"])
+        .with_layout(SelectLayout::Horizontal)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "Apple"), ans);
+    assert_eq!(
+        &[
+            "? Fruit? < Banana >\n[←→ to move, enter to select]",
+            "? Fruit? < Apple >\n[←→ to move, enter to select]",
+            "> Fruit? Apple",
+        ],
+        backend.rendered_frames()
+    );
+}
+
+#[test]
+fn horizontal_layout_wraps_around_and_disables_filtering() {
+    let mut backend = fake_backend(vec![
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Left(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Select::new("Fruit?", vec!["Banana", "Apple", "Cherry"])
+        .with_layout(SelectLayout::Horizontal)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "Cherry"), ans);
+}
+
+#[test]
+fn annotations_are_aligned_after_the_widest_option_of_the_page() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    Select::new("Command?", vec!["deploy", "rollback", "status"])
+        .with_aligned_annotations(&[
+            ("deploy", "Deploys the app"),
+            ("rollback", "Reverts the last deployment"),
+        ])
+        .without_filtering()
+        .without_help_message()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        "? Command?\n> deploy    Deploys the app\n  rollback  Reverts the last deployment\n  status",
+        backend.rendered_frames().first().unwrap()
+    );
+}
+
+#[test]
+fn annotation_alignment_is_display_width_aware() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    Select::new("Fruit?", vec!["梨", "apple"])
+        .with_aligned_annotations(&[("梨", "pear"), ("apple", "red")])
+        .without_filtering()
+        .without_help_message()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        "? Fruit?\n> 梨     pear\n  apple  red",
+        backend.rendered_frames().first().unwrap()
+    );
+}
+
+#[test]
+fn loading_indicator_is_displayed_until_options_are_loaded() {
+    let mut backend = fake_backend_with_timeouts(vec![Some(Key::Enter), None, Some(Key::Enter)]);
+
+    let (sender, receiver) = mpsc::channel();
+    let receiver = RefCell::new(Some(receiver));
+    let loading_source: LoadingSource<'_, &str> = Rc::new(move || receiver.take().unwrap());
+    sender.send(vec!["loaded"]).unwrap();
+
+    let mut select = Select::new("Question", vec![]).without_help_message();
+    select.loading_source = Some(loading_source);
+
+    let ans = select.prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!(ListOption::new(0, "loaded"), ans);
+    assert_eq!(
+        &[
+            "? Question  \n⠋ Loading…",
+            "? Question  \n⠋ Loading…",
+            "? Question  \n> loaded",
+            "> Question loaded",
+        ],
+        backend.rendered_frames()
+    );
+}
+
+#[test]
+fn starting_cursor_is_applied_to_the_loaded_options() {
+    let select_loaded = |starting_cursor| {
+        let mut backend = fake_backend_with_timeouts(vec![None, Some(Key::Enter)]);
+
+        let (sender, receiver) = mpsc::channel();
+        let receiver = RefCell::new(Some(receiver));
+        let loading_source: LoadingSource<'_, &str> = Rc::new(move || receiver.take().unwrap());
+        sender.send(vec!["a", "b", "c"]).unwrap();
+
+        let mut select = Select::new("Question", vec![])
+            .without_help_message()
+            .with_starting_cursor(starting_cursor);
+        select.loading_source = Some(loading_source);
+
+        let ans = select.prompt_with_backend(&mut backend).unwrap();
+        (ans.index, backend.rendered_frames().get(1).cloned())
+    };
+
+    assert_eq!(
+        (1, Some(String::from("? Question  \n  a\n> b\n  c"))),
+        select_loaded(1)
+    );
+    // out-of-bounds cursors are clamped to the last loaded option
+    assert_eq!(
+        (2, Some(String::from("? Question  \n  a\n  b\n> c"))),
+        select_loaded(5)
+    );
+}
+
+#[test]
+fn prompt_can_be_canceled_while_loading() {
+    let mut backend = fake_backend(vec![Key::Escape]);
+
+    let ans = Select::new("Question", Vec::<String>::new())
+        .with_loading_source(|| {
+            std::thread::sleep(Duration::from_secs(60));
+            vec![]
+        })
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+}
+
+#[test]
+fn whitespace_filter_input_shows_all_options() {
+    let mut backend = fake_backend(vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Select::new("Question", vec!["a", "b", "c"])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "b"), ans);
+}
+
+#[test]
+fn custom_show_all_predicate_is_used() {
+    let select = Select::new("Question", vec!["Banana", "Apple", "Cherry"])
+        .with_show_all_predicate(&|input| input == "*");
+
+    assert_eq!(vec![0, 1, 2], select.filtered_indices("*"));
+    assert_eq!(vec![1], select.filtered_indices("pp"));
+}
+
+#[test]
+fn custom_scorer_is_skipped_for_empty_input_unless_the_predicate_is_disabled() {
+    let scorer: Scorer<'_, &str> = &|input, _, _, idx| match input.is_empty() {
+        true => (idx != 1).then_some(0),
+        false => Some(0),
+    };
+
+    let select = Select::new("Question", vec!["Banana", "Apple", "Cherry"]).with_scorer(scorer);
+    assert_eq!(vec![0, 1, 2], select.filtered_indices(""));
+
+    let select = select.with_show_all_predicate(&|_| false);
+    assert_eq!(vec![0, 2], select.filtered_indices(""));
+}
+
+#[test]
+fn filter_input_is_trimmed_by_default() {
+    let mut backend = fake_backend(vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Char('c', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let scorer: Scorer<'_, &str> =
+        &|input, _, string_value, _| string_value.starts_with(input).then_some(0);
+
+    let ans = Select::new("Question", vec!["a", "b", "c"])
+        .with_scorer(scorer)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "c"), ans);
+
+    let select = Select::new("Question", vec!["a", "b", "c"])
+        .with_scorer(scorer)
+        .with_trimmed_filter(false);
+
+    assert!(select.filtered_indices(" c").is_empty());
+}
+
+#[test]
+fn hidden_answer_renders_placeholder() {
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = Select::new("Secret:", vec!["db-password", "api-token"])
+        .without_filtering()
+        .without_help_message()
+        .with_hidden_answer(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "api-token"), ans);
+    assert_eq!(
+        Some(&String::from("> Secret: <hidden>")),
+        backend.rendered_frames().last()
+    );
+}
+
+#[test]
+fn space_marks_options_returned_with_the_answer() {
+    let mut backend = fake_backend(vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let (ans, marked) = Select::new("Task:", vec!["docs", "tests", "release"])
+        .without_help_message()
+        .prompt_with_marks_and_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "tests"), ans);
+    assert_eq!(vec![0, 2], marked);
+    assert_eq!(
+        Some(&String::from("? Task:  \n  docs *\n> tests\n  release *")),
+        backend.rendered_frames().get(5)
+    );
+}
+
+#[test]
+fn space_unmarks_marked_option() {
+    let mut backend = fake_backend(vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let (ans, marked) = Select::new("Task:", vec!["docs", "tests"])
+        .prompt_with_marks_and_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "docs"), ans);
+    assert!(marked.is_empty());
+}
+
+#[test]
+fn space_is_typed_in_filter_without_marking() {
+    let mut backend = fake_backend(vec![
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Select::new("Question", vec!["a a", "a b"])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "a b"), ans);
+}
+
+#[test]
+fn pagination_renderer_receives_displayed_page() {
+    let mut backend = fake_backend(vec![Key::End, Key::Enter]);
+
+    let renderer: PaginationRenderer<'_, &str> = &|page| {
+        let cursor = page.cursor.unwrap_or_default();
+        let value = page.content.get(cursor).map(|o| o.value).unwrap();
+        vec![format!(
+            "{value} {}/{} last={}",
+            cursor, page.total, page.last
+        )]
+    };
+
+    Select::new("Question", vec!["a", "b", "c", "d"])
+        .with_page_size(2)
+        .without_help_message()
+        .without_filtering()
+        .with_pagination_renderer(renderer)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let frames = backend.rendered_frames();
+    assert_eq!(
+        Some(&String::from("? Question\n> a\nv b\na 0/4 last=false")),
+        frames.first()
+    );
+    assert_eq!(
+        Some(&String::from("? Question\n^ c\n> d\nd 1/4 last=true")),
+        frames.get(1)
+    );
+}
+
+#[test]
+fn owned_select_has_no_borrowed_lifetime() {
+    struct Step {
+        select: OwnedSelect<String>,
+    }
+
+    let step = {
+        let message = format!("Pick one of {}:", 2);
+        Step {
+            select: OwnedSelect::new(message, vec![String::from("a"), String::from("b")])
+                .with_help_message(String::from("owned help"))
+                .with_starting_cursor(1),
+        }
+    };
+
+    let mut backend = fake_backend(vec![Key::Enter]);
+    let ans = step.select.prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!(ListOption::new(1, String::from("b")), ans);
+    assert_eq!(
+        Some(&String::from("? Pick one of 2:  \n  a\n> b\n[owned help]")),
+        backend.rendered_frames().first()
+    );
+}
+
+#[test]
+fn owned_select_converts_to_select() {
+    let owned = OwnedSelect::new("Question", vec!["a", "b"]).without_filtering();
+    let select = owned.as_select();
+
+    assert_eq!("Question", select.message);
+    assert!(!select.filter_input_enabled);
+    assert_eq!(vec!["a", "b"], select.options);
+}
+
+#[test]
+fn icons_are_rendered_before_labels_and_aligned() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let icons: IconProvider<'_> = &|index, value| match (index, value) {
+        (0, _) => Some(String::from("ok")),
+        (_, "docs") => Some(String::from("!")),
+        _ => None,
+    };
+
+    Select::new("File:", vec!["main", "docs", "misc"])
+        .without_filtering()
+        .without_help_message()
+        .with_icons(icons)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        Some(&String::from("? File:\n> ok main\n  !  docs\n     misc")),
+        backend.rendered_frames().first()
+    );
+}
+
+#[test]
+fn events_drive_the_prompt_without_rendering() {
+    let ans = Select::new("Question", vec!["a", "b", "c"])
+        .prompt_with_events([Key::Char('c', KeyModifiers::NONE), Key::Enter])
+        .unwrap();
+
+    assert_eq!("c", ans);
+}
+
+#[test]
+fn exhausted_events_fail_with_eof() {
+    let err = Select::new("Question", vec!["a", "b"])
+        .prompt_with_events([Key::Down(KeyModifiers::NONE)])
+        .unwrap_err();
+
+    assert!(
+        matches!(err, InquireError::IO(ref io) if io.kind() == std::io::ErrorKind::UnexpectedEof)
+    );
+}
+
+#[test]
+fn starting_cursor_where_picks_first_match_or_falls_back() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = Select::new("Question", vec!["a", "bb", "cc"])
+        .with_starting_cursor_where(|_, value| value.len() == 2)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "bb"), ans);
+
+    let select =
+        Select::new("Question", vec!["a", "b"]).with_starting_cursor_where(|index, _| index > 5);
+
+    assert_eq!(0, select.starting_cursor);
+}
+
+#[test]
+fn render_hook_receives_terminal_size() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let sizes = RefCell::new(vec![]);
+    let render_hook: RenderHook<'_, &str> = &|ctx| {
+        sizes.borrow_mut().push(ctx.terminal_size);
+        vec![]
+    };
+
+    Select::new("Question", vec!["a", "b"])
+        .with_render_hook(render_hook)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let sizes = sizes.borrow();
+    assert_eq!(1, sizes.len());
+    assert!(sizes.iter().all(Option::is_some));
+}
+
+#[test]
+fn tab_accepts_single_filter_match() {
+    let mut backend = fake_backend(vec![
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Tab,
+        Key::Char('p', KeyModifiers::NONE),
+        Key::Tab,
+    ]);
+
+    let ans = Select::new("Question", vec!["Banana", "Apple", "Avocado"])
+        .with_tab_accepts_single(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "Apple"), ans);
+}
+
+#[test]
+fn tab_does_not_accept_single_match_by_default() {
+    let mut backend = fake_backend(vec![
+        Key::Char('p', KeyModifiers::NONE),
+        Key::Char('p', KeyModifiers::NONE),
+        Key::Tab,
+        Key::Backspace,
+        Key::Backspace,
+        Key::Enter,
+    ]);
+
+    let ans = Select::new("Question", vec!["Banana", "Apple"])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "Banana"), ans);
+}
+
+#[test]
+fn title_is_rendered_above_the_prompt_while_active() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    Select::new("Region:", vec!["eu", "us"])
+        .with_title("Step 2 of 5")
+        .without_filtering()
+        .without_help_message()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        &["Step 2 of 5\n? Region:\n> eu\n  us", "> Region: eu"],
+        backend.rendered_frames()
+    );
+}
+
+#[test]
+fn sequential_selection_returns_picks_in_order() {
+    let done = Key::Char('s', KeyModifiers::CONTROL);
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+        Key::Up(KeyModifiers::NONE),
+        Key::Enter,
+        Key::Up(KeyModifiers::NONE),
+        Key::Enter,
+        Key::Enter,
+        done,
+    ]);
+
+    let picks = Select::new("Toppings:", vec!["cheese", "olives", "basil"])
+        .without_filtering()
+        .without_help_message()
+        .prompt_multi_sequential_with_backend(done, &mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![ListOption::new(2, "basil"), ListOption::new(1, "olives")],
+        picks
+    );
+    assert_eq!(
+        Some(&String::from(
+            "? Toppings:\n  cheese\n> olives *\n  basil *"
+        )),
+        backend.rendered_frames().get(5)
+    );
+    assert_eq!(
+        Some(&String::from("> Toppings: basil, olives")),
+        backend.rendered_frames().last()
+    );
+}
+
+#[test]
+fn sequential_selection_ignores_the_done_key_while_loading() {
+    let done = Key::Char('s', KeyModifiers::CONTROL);
+    let mut backend = fake_backend_with_timeouts(vec![
+        Some(done),
+        Some(Key::Enter),
+        None,
+        Some(Key::Enter),
+        Some(done),
+    ]);
+
+    let (sender, receiver) = mpsc::channel();
+    let receiver = RefCell::new(Some(receiver));
+    let loading_source: LoadingSource<'_, &str> = Rc::new(move || receiver.take().unwrap());
+    sender.send(vec!["cheese", "olives"]).unwrap();
+
+    let mut select = Select::new("Toppings:", vec![]).without_help_message();
+    select.loading_source = Some(loading_source);
+
+    let picks = select
+        .prompt_multi_sequential_with_backend(done, &mut backend)
+        .unwrap();
+
+    // the first Enter after loading picks the option instead of finishing
+    assert_eq!(vec![ListOption::new(0, "cheese")], picks);
+}
+
+#[test]
+fn prompt_ref_keeps_the_prompt_for_the_next_iterations() {
+    let menu = Select::new("Action:", vec!["Status", "Sync", "Quit"]).without_help_message();
+
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+    assert_eq!("Sync", menu.prompt_ref_with_backend(&mut backend).unwrap());
+
+    // the cursor moved in the previous iteration starts over
+    let mut backend = fake_backend(vec![Key::Up(KeyModifiers::NONE), Key::Enter]);
+    assert_eq!("Quit", menu.prompt_ref_with_backend(&mut backend).unwrap());
+    assert_eq!(
+        Some(&String::from("? Action:  \n> Status\n  Sync\n  Quit")),
+        backend.rendered_frames().first()
+    );
+}
+
+#[test]
+fn stdout_echo_writes_the_plain_value_of_the_answer() {
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = Select::new("Fruit:", vec!["apple", "banana"])
+        .without_help_message()
+        .with_formatter(&|option| option.value.to_uppercase())
+        .with_stdout_echo(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let mut output = Vec::new();
+    super::echo_answer(&ans.value, &mut output).unwrap();
+
+    // the formatter only applies to the answer rendered on the terminal
+    assert_eq!(
+        Some(&String::from("> Fruit: BANANA")),
+        backend.rendered_frames().last()
+    );
+    assert_eq!(b"banana\n", output.as_slice());
+}
+
+#[test]
+fn sequential_selection_rejects_submit_key_as_done_key() {
+    let mut backend = fake_backend(vec![]);
+
+    let err = Select::new("Question", vec!["a"])
+        .prompt_multi_sequential_with_backend(Key::Enter, &mut backend)
+        .unwrap_err();
+
+    assert!(matches!(err, InquireError::InvalidConfiguration(_)));
+}
+
+#[test]
+fn filter_input_beyond_max_len_is_ignored() {
+    let mut backend = fake_backend(vec![
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Char('x', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Select::new("Question", vec!["bar", "baz"])
+        .with_max_filter_len(2)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "bar"), ans);
+}
+
+#[test]
+fn configured_cancel_key_cancels_the_prompt() {
+    let mut backend = fake_backend(vec![Key::Char('q', KeyModifiers::NONE)]);
+
+    let ans = Select::new("Question", vec!["a", "b", "c"])
+        .without_filtering()
+        .with_cancel_key(Key::Char('q', KeyModifiers::NONE))
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+}
+
+#[test]
+fn escape_is_ignored_when_cancel_key_is_configured() {
+    let mut backend = fake_backend(vec![Key::Escape, Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = Select::new("Question", vec!["a", "b", "c"])
+        .with_cancel_key(Key::Char('q', KeyModifiers::CONTROL))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "b"), ans);
+}
+
+#[test]
+fn keybindings_follow_the_prompt_settings() {
+    let bindings = Select::new("Question", vec!["a", "b", "c"])
+        .with_vim_mode(true)
+        .with_tab_paging(true)
+        .with_cancel_key(Key::Char('q', KeyModifiers::CONTROL))
+        .keybindings();
+
+    let keys_of = |action: &str| {
+        bindings
+            .iter()
+            .filter(|binding| binding.action == action)
+            .flat_map(|binding| binding.keys.iter().map(String::as_str))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        vec!["Ctrl+Q", "Ctrl+G", "Ctrl+D"],
+        keys_of("Cancel the prompt")
+    );
+    assert_eq!(vec!["k", "Up", "Ctrl+P"], keys_of("Move up"));
+    assert_eq!(vec!["PageDown", "Tab"], keys_of("Move one page down"));
+    assert_eq!(vec!["PageUp", "Shift+Tab"], keys_of("Move one page up"));
+    assert!(keys_of("Show or hide the help message").is_empty());
+}
+
+#[test]
+fn default_help_message_follows_the_key_bindings() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    Select::new("Question", vec!["a", "b"])
+        .with_marking(true)
+        .with_cancel_key(Key::Char('q', KeyModifiers::CONTROL))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert!(backend.rendered_frames().first().unwrap().ends_with(
+        "[↑↓ to move, enter to select, space to mark, type to filter, Ctrl+Q to cancel]"
+    ));
+}
+
+#[test]
+fn custom_help_message_is_not_changed_by_the_key_bindings() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    Select::new("Question", vec!["a", "b"])
+        .with_marking(true)
+        .with_help_message("pick one")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert!(backend
+        .rendered_frames()
+        .first()
+        .unwrap()
+        .ends_with("[pick one]"));
+}
+
+#[test]
+fn reverse_layout_renders_options_above_the_prompt() {
+    let mut backend = fake_backend(vec![Key::Up(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = Select::new(
+        "Fruit?",
+        vec!["Banana", "Apple", "Strawberry", "Grapefruit"],
+    )
+    .with_page_size(3)
+    .with_reverse_layout(true)
+    .without_help_message()
+    .prompt_with_backend(&mut backend)
+    .unwrap();
+
+    assert_eq!(ListOption::new(1, "Apple"), ans);
+    assert_eq!(
+        &[
+            "^ Strawberry\n  Apple\n> Banana\n? Fruit?  ",
+            "^ Strawberry\n> Apple\n  Banana\n? Fruit?  ",
+        ],
+        backend.rendered_frames().get(..2).unwrap()
+    );
+}
+
+#[test]
+fn closure_panics_can_be_returned_as_errors() {
+    let mut backend = fake_backend(vec![Key::Char('a', KeyModifiers::NONE), Key::Enter]);
+
+    let scorer = &|_: &str, _: &&str, _: &str, _: usize| -> Option<i64> {
+        panic!("scorer failed");
+    };
+
+    let ans = Select::new("Question", vec!["a", "b"])
+        .with_scorer(scorer)
+        .with_catch_closure_panics(true)
+        .prompt_with_backend(&mut backend);
+
+    match ans {
+        Err(InquireError::Custom(err)) => assert!(err.to_string().contains("scorer failed")),
+        _ => panic!("expected a custom error"),
+    }
+}
+
+#[test]
+fn settings_are_applied_at_once() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let settings = SelectSettings {
+        starting_cursor: 2,
+        help_message: Some(String::from("from settings")),
+        filter_input_enabled: false,
+        ..Default::default()
+    };
+
+    let ans = Select::new("Question", vec!["a", "b", "c"])
+        .with_settings(&settings)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "c"), ans);
+    assert_eq!(
+        "? Question\n  a\n  b\n> c\n[from settings]",
+        backend.rendered_frames().first().unwrap()
+    );
+}
+
+#[test]
+fn footer_is_rendered_below_the_help_message() {
+    let mut backend = fake_backend(vec![Key::Char('a', KeyModifiers::NONE), Key::Enter]);
+
+    let footer: FooterRenderer<'_> = &|ctx| {
+        format!(
+            "{} options • {} hidden by filter • cursor {}",
+            ctx.total_options, ctx.hidden_options, ctx.cursor_index
+        )
+    };
+
+    Select::new("Question", vec!["a", "b", "ab"])
+        .with_help_message("help")
+        .with_footer(footer)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        &[
+            "? Question  \n> a\n  b\n  ab\n[help]\n3 options • 0 hidden by filter • cursor 0",
+            "? Question a \n> a\n  ab\n[help]\n3 options • 1 hidden by filter • cursor 0",
+        ],
+        backend.rendered_frames().get(..2).unwrap()
+    );
+}
+
+#[test]
+fn prestyled_options_are_filtered_without_escape_sequences() {
+    let mut backend = fake_backend(vec![
+        Key::Char('m', KeyModifiers::NONE),
+        Key::Backspace,
+        Key::Char('e', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Select::new("Question", vec!["\x1b[31mred\x1b[0m", "blue"])
+        .with_prestyled_options(true)
+        .without_help_message()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "\x1b[31mred\x1b[0m"), ans);
+    assert_eq!("? Question m ", backend.rendered_frames().get(1).unwrap());
+}
+
+#[test]
+fn highlighted_option_can_be_copied_to_the_clipboard() {
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Char('y', KeyModifiers::CONTROL),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Select::new("Question", vec!["a", "foo", "c"])
+        .with_clipboard_copy(true)
+        .without_help_message()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "c"), ans);
+    assert_eq!(
+        Some(vec![String::from("foo")]),
+        CLIPBOARD.with(|clipboard| clipboard.take())
+    );
+    assert_eq!(
+        &[
+            "? Question  \n  a\n> foo\n  c\nCopied to clipboard",
+            "? Question  \n  a\n  foo\n> c",
+        ],
+        backend.rendered_frames().get(2..4).unwrap()
+    );
+}
+
+#[test]
+fn clipboard_copy_rings_the_bell_without_a_clipboard() {
+    let mut backend = fake_backend(vec![Key::Char('y', KeyModifiers::CONTROL), Key::Enter]);
+    CLIPBOARD.with(|clipboard| clipboard.replace(None));
+
+    Select::new("Question", vec!["a", "b"])
+        .with_clipboard_copy(true)
+        .without_help_message()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        Some(&String::from(
+            "? Question  \n> a\n  b\n\x07No clipboard available"
+        )),
+        backend.rendered_frames().get(1)
+    );
+}
+
+#[test]
+fn filter_input_is_shown_in_answer() {
+    let mut backend = fake_backend(vec![
+        Key::Char('w', KeyModifiers::NONE),
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Select::new("What's your fruit?", vec!["Banana", "Watermelon"])
+        .without_help_message()
+        .with_show_filter_in_answer(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "Watermelon"), ans);
+    assert_eq!(
+        Some(&String::from(
+            "> What's your fruit? [typed 'wa'] → Watermelon"
+        )),
+        backend.rendered_frames().last()
+    );
+}
+
+#[test]
+fn duplicate_options_fail_when_disallowed() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = Select::new("Question", vec!["a", "b", "a"])
+        .with_allow_duplicates(false)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(
+        ans,
+        Err(InquireError::InvalidConfiguration(message)) if message.contains("0 and 2")
+    ));
+}
+
+#[test]
+fn duplicate_refreshed_options_fail_when_disallowed() {
+    let mut backend = fake_backend_with_timeouts(vec![None, Some(Key::Enter)]);
+
+    let refresher: Refresher<'_, &str> = &|| vec!["a", "b", "b"];
+
+    let ans = Select::new("Question", vec!["a", "b"])
+        .with_allow_duplicates(false)
+        .with_refresh(refresher, Duration::from_millis(100))
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(
+        ans,
+        Err(InquireError::InvalidConfiguration(message)) if message.contains("1 and 2")
+    ));
+}
+
+#[test]
+fn multiline_options_are_rendered_as_blocks() {
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let options = vec![
+        "fix: typo\nSpotted in review",
+        "feat: search\nAdds a filter",
+    ];
+    let ans = Select::new("Commit:", options)
+        .without_help_message()
+        .with_multiline_options(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(1, ans.index);
+    assert_eq!(
+        Some(&String::from(
+            "? Commit:  \n  fix: typo\n  Spotted in review\n> feat: search\n  Adds a filter"
+        )),
+        backend.rendered_frames().get(1)
+    );
+}
+
+#[test]
+fn multiline_options_fill_the_rows_left_by_max_rows() {
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let select = || {
+        let options = vec![
+            "fix: typo\nSpotted in review",
+            "feat: search\nAdds a filter\nWith tests",
+            "chore: bump",
+            "docs: readme",
+        ];
+
+        Select::new("Commit:", options)
+            .without_filtering()
+            .without_help_message()
+            .with_multiline_options(true)
+            .with_max_rows(5)
+    };
+    let ans = select().prompt_with_backend(&mut backend).unwrap();
+
+    // the prompt line takes one row, leaving four for the options
+    assert_eq!(2, ans.index);
+    assert_eq!(
+        &[
+            "? Commit:\n> fix: typo\n  Spotted in review",
+            "? Commit:\n> feat: search\n  Adds a filter\n  With tests\nv chore: bump",
+            "? Commit:\n> chore: bump\n  docs: readme",
+        ],
+        backend.rendered_frames().get(..3).unwrap()
+    );
+    assert_eq!(3, select().estimated_height(80));
+}
+
+#[test]
+fn canceled_prompt_state_is_restored() {
+    let options = vec!["apple", "banana", "apricot", "avocado"];
+
+    let mut backend = fake_backend(vec![
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Escape,
+    ]);
+    let (ans, state) =
+        Select::new("Question", options.clone()).prompt_with_state_and_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+    assert_eq!(Some(2), state.highlighted);
+    assert_eq!("a", state.filter_input);
+
+    let mut backend = fake_backend(vec![Key::Enter]);
+    let (ans, _) = Select::new("Question", options)
+        .with_state(&state)
+        .prompt_with_state_and_backend(&mut backend);
+
+    assert_eq!(ListOption::new(2, "apricot"), ans.unwrap());
+}
+
+#[test]
+fn caret_moves_within_filter_input() {
+    let mut backend = fake_backend(vec![
+        Key::Char('c', KeyModifiers::NONE),
+        Key::Char('a', KeyModifiers::CONTROL),
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Char('e', KeyModifiers::CONTROL),
+        Key::Char('e', KeyModifiers::NONE),
+        Key::Left(KeyModifiers::NONE),
+        Key::Char('d', KeyModifiers::NONE),
+        Key::Escape,
+    ]);
+
+    let (_, state) =
+        Select::new("Question", vec!["aced", "acde"]).prompt_with_state_and_backend(&mut backend);
+
+    assert_eq!("acde", state.filter_input);
+}
+
+#[test]
+fn progress_is_rendered_above_the_prompt() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    Select::new("Region:", vec!["eu", "us"])
+        .without_help_message()
+        .with_progress(2, 4)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        Some(&String::from("●●○○ 2/4\n? Region:  \n> eu\n  us")),
+        backend.rendered_frames().first()
+    );
+}
+
+#[test]
+fn estimated_height_matches_rendered_rows() {
+    let select = || {
+        Select::new("Region:", vec!["eu", "us", "ap", "sa"])
+            .with_title("Step 2")
+            .with_page_size(3)
+            .with_help_message("Pick the closest region")
+    };
+
+    let mut backend = fake_backend(vec![Key::Enter]);
+    select().prompt_with_backend(&mut backend).unwrap();
+    let rendered_rows = backend.rendered_frames().first().unwrap().lines().count();
+
+    assert_eq!(6, rendered_rows);
+    assert_eq!(rendered_rows, select().estimated_height(200));
+    // the help message is wrapped in two rows
+    assert_eq!(rendered_rows + 1, select().estimated_height(20));
+}
+
+#[test]
+fn estimated_height_accounts_for_the_search_indicator_and_placeholder() {
+    let select = || {
+        Select::new("Region:", vec!["eu-west", "us-east"])
+            .without_help_message()
+            .with_render_config(RenderConfig::empty().with_input_scroll(false))
+    };
+
+    // "? Region: eu-w" fills the 14 columns, the search indicator wraps it
+    let with_input = || select().with_starting_filter_input("eu-w");
+    assert_eq!(3, with_input().estimated_height(14));
+    assert_eq!(
+        4,
+        with_input()
+            .with_search_indicator(true)
+            .estimated_height(14)
+    );
+
+    // "? Region: Type to filter" is wrapped in two rows
+    assert_eq!(3, select().estimated_height(14));
+    let with_placeholder = select().with_filter_placeholder("Type to filter");
+    assert_eq!(4, with_placeholder.estimated_height(14));
+
+    // scrolled inputs are not wrapped
+    let scrolled = with_input().with_render_config(RenderConfig::empty());
+    assert_eq!(3, scrolled.with_search_indicator(true).estimated_height(14));
+}
+
+#[test]
+fn prefix_autosubmit_submits_unique_prefix_match() {
+    let mut backend = fake_backend(vec![
+        Key::Char('d', KeyModifiers::NONE),
+        Key::Char('e', KeyModifiers::NONE),
+        Key::Char('p', KeyModifiers::NONE),
+    ]);
+
+    let ans = Select::new("Command", vec!["deploy", "delete", "build"])
+        .with_prefix_autosubmit(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "deploy"), ans);
+}
+
+#[test]
+fn prefix_autosubmit_requires_two_characters() {
+    let mut backend = fake_backend(vec![Key::Char('b', KeyModifiers::NONE), Key::Escape]);
+
+    let ans = Select::new("Command", vec!["deploy", "delete", "build"])
+        .with_prefix_autosubmit(true)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+}
+
+#[test]
+fn filter_on_rendered_matches_icons_and_annotations() {
+    let keys = |text: &str| {
+        text.chars()
+            .map(|c| Key::Char(c, KeyModifiers::NONE))
+            .chain([Key::Enter])
+            .collect::<Vec<_>>()
+    };
+    let icons: IconProvider<'_> = &|_, value| value.ends_with(".rs").then(|| String::from("rust"));
+    let annotations = [("Cargo.toml", "manifest")];
+    let select = Select::new("File", vec!["README.md", "main.rs", "Cargo.toml"])
+        .with_icons(icons)
+        .with_aligned_annotations(&annotations)
+        .with_filter_on_rendered(true);
+
+    let mut backend = fake_backend(keys("rust"));
+    let ans = select.clone().prompt_with_backend(&mut backend).unwrap();
+    assert_eq!(ListOption::new(1, "main.rs"), ans);
+
+    let mut backend = fake_backend(keys("manifest"));
+    let ans = select.prompt_with_backend(&mut backend).unwrap();
+    assert_eq!(ListOption::new(2, "Cargo.toml"), ans);
+}
+
+#[test]
+fn error_on_empty_submit_fails_without_matches() {
+    let mut backend = fake_backend(vec![Key::Char('z', KeyModifiers::NONE), Key::Enter]);
+
+    let ans = Select::new("Question", vec!["a", "b", "c"])
+        .with_error_on_empty_submit(true)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::NoMatchingOption)));
+}
+
+#[test]
+fn lazy_description_is_computed_once_per_highlighted_option() {
+    let mut backend = fake_backend_with_timeouts(vec![
+        None,
+        Some(Key::Down(KeyModifiers::NONE)),
+        None,
+        Some(Key::Up(KeyModifiers::NONE)),
+        Some(Key::Enter),
+    ]);
+
+    let calls = RefCell::new(vec![]);
+    let describe: DescriptionProvider<'_, &str> = &|option| {
+        calls.borrow_mut().push(option.index);
+        format!("about {}", option.value)
+    };
+
+    let ans = Select::new("Question", vec!["a", "b"])
+        .without_help_message()
+        .with_lazy_description(describe)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "a"), ans);
+    assert_eq!(vec![0, 1], calls.into_inner());
+    assert_eq!(
+        &[
+            "? Question  \n> a\n  b\n…",
+            "? Question  \n> a\n  b\nabout a",
+            "? Question  \n  a\n> b\n…",
+            "? Question  \n  a\n> b\nabout b",
+            "? Question  \n> a\n  b\nabout a",
+            "> Question a",
+        ],
+        backend.rendered_frames()
+    );
+}
+
+#[test]
+fn lazy_description_is_computed_before_the_next_refresh() {
+    let mut backend =
+        fake_backend_with_timeouts(vec![Some(Key::Down(KeyModifiers::NONE)), Some(Key::Enter)]);
+
+    let calls = RefCell::new(vec![]);
+    let describe: DescriptionProvider<'_, &str> = &|option| {
+        calls.borrow_mut().push(option.index);
+        format!("about {}", option.value)
+    };
+    let refresher: Refresher<'_, &str> = &|| vec!["a", "b"];
+
+    let ans = Select::new("Question", vec!["a", "b"])
+        .without_help_message()
+        .with_lazy_description(describe)
+        .with_refresh(refresher, Duration::from_secs(60))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "b"), ans);
+    assert_eq!(vec![0, 1], calls.into_inner());
+    assert_eq!(
+        Some(&String::from("? Question  \n  a\n> b\nabout b")),
+        backend.rendered_frames().iter().rev().nth(1)
+    );
+}
+
+#[test]
+fn scrollbar_follows_the_cursor_at_the_right_edge() {
+    let mut backend = fake_backend(vec![Key::End, Key::Enter]);
+
+    Select::new("Question", vec!["a", "b", "c", "d", "e", "f"])
+        .without_filtering()
+        .without_help_message()
+        .with_page_size(3)
+        .with_scrollbar(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    // the scrollbar is drawn at the terminal width, which depends on the
+    // environment running the tests
+    let scrollbar = |frame: &String| {
+        frame
+            .lines()
+            .skip(1)
+            .map(|line| line.chars().last().unwrap_or_default())
+            .collect::<String>()
+    };
+    let frames = backend.rendered_frames();
+
+    assert_eq!("█││", scrollbar(frames.first().unwrap()));
+    assert_eq!("││█", scrollbar(frames.get(1).unwrap()));
+}
+
+#[test]
+fn scrollbar_spans_the_rows_of_multiline_options() {
+    let mut backend = fake_backend_with_width(vec![Key::End, Key::Enter], 10);
+
+    Select::new("Question", vec!["a\n1", "b\n2", "c\n3", "d\n4"])
+        .without_filtering()
+        .without_help_message()
+        .with_page_size(2)
+        .with_multiline_options(true)
+        .with_scrollbar(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let frames = backend.rendered_frames();
+
+    assert_eq!(
+        "? Question\n> a      █\n  1      █\nv b      │\n  2      │",
+        frames.first().unwrap()
+    );
+    assert_eq!(
+        "? Question\n^ c      │\n  3      │\n> d      █\n  4      █",
+        frames.get(1).unwrap()
+    );
+}
+
+#[test]
+fn scrollbar_is_not_drawn_when_options_fit_the_page() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    Select::new("Question", vec!["a", "b"])
+        .without_filtering()
+        .without_help_message()
+        .with_scrollbar(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        "? Question\n> a\n  b",
+        backend.rendered_frames().first().unwrap()
+    );
+}
+
+#[test]
+fn key_filter_remaps_and_drops_keys() {
+    let mut backend = fake_backend(vec![Key::Tab, Key::Escape, Key::Enter]);
+
+    let key_filter: KeyFilter<'_> = &|key| match key {
+        Key::Tab => Some(Key::Down(KeyModifiers::NONE)),
+        Key::Escape => None,
+        key => Some(key),
+    };
+
+    let ans = Select::new("Question", vec!["a", "b", "c"])
+        .with_key_filter(key_filter)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "b"), ans);
+}
+
+#[test]
+fn persist_full_render_keeps_the_options_on_screen() {
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    Select::new("Question", vec!["a", "b"])
+        .without_filtering()
+        .without_help_message()
+        .with_persist_full_render(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        &[
+            "? Question\n> a\n  b",
+            "? Question\n  a\n> b",
+            "? Question\n  a\n> b",
+        ],
+        backend.rendered_frames()
+    );
+}
+
+#[test]
+fn persist_full_render_is_ignored_with_hidden_answer() {
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    Select::new("Secret:", vec!["db-password", "api-token"])
+        .without_filtering()
+        .without_help_message()
+        .with_hidden_answer(true)
+        .with_persist_full_render(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        Some(&String::from("> Secret: <hidden>")),
+        backend.rendered_frames().last()
+    );
+}
+
+#[test]
+fn weights_rank_options_before_and_while_filtering() {
+    let weights = [1.0, 12.0, 5.0, 7.0];
+    let select = Select::new("Command", vec!["init", "build", "test", "bench"])
+        .with_weights(&weights)
+        .with_scorer(&|input, _, value, _| value.contains(input).then_some(0));
+
+    assert_eq!(vec![1, 3, 2, 0], select.filtered_indices(""));
+    assert_eq!(vec![3, 2], select.filtered_indices("e"));
+
+    let mut backend = fake_backend(vec![Key::Enter]);
+    let ans = select.prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!(ListOption::new(1, "build"), ans);
+}
+
+#[test]
+fn confirm_indices_require_a_second_submit() {
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter, Key::Enter]);
+
+    let ans = Select::new("Action", vec!["keep", "delete"])
+        .without_help_message()
+        .with_confirm_indices(&[1], "Press enter again to delete")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "delete"), ans);
+    assert_eq!(
+        Some(&String::from(
+            "? Action  \n  keep\n> delete\nPress enter again to delete"
+        )),
+        backend.rendered_frames().get(2)
+    );
+}
+
+#[test]
+fn confirm_indices_are_dismissed_by_other_keys() {
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+        Key::Escape,
+        Key::Up(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Select::new("Action", vec!["keep", "delete"])
+        .with_confirm_indices(&[1], "Press enter again to delete")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "keep"), ans);
+}
+
+#[test]
+fn search_indicator_is_prepended_to_non_empty_filter() {
+    let mut backend = fake_backend(vec![Key::Char('b', KeyModifiers::NONE), Key::Enter]);
+
+    Select::new("Question", vec!["a", "b"])
+        .without_help_message()
+        .with_search_indicator(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let frames = backend.rendered_frames();
+    assert_eq!(
+        Some(&String::from("? Question  \n> a\n  b")),
+        frames.first()
+    );
+    assert_eq!(Some(&String::from("? Question /b \n> b")), frames.get(1));
+}
+
+#[test]
+fn border_frames_the_options() {
+    let mut backend = fake_backend_with_width(vec![Key::Enter], 20);
+
+    Select::new("Question", vec!["a", "b"])
+        .without_filtering()
+        .without_help_message()
+        .with_border(BorderStyle::Rounded)
+        .with_border_title(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        Some(&String::from(
+            "? \n╭─ Question ───────╮\n│ > a              │\n│   b              │\n╰──────────────────╯"
+        )),
+        backend.rendered_frames().first()
+    );
+}
+
+#[test]
+fn border_truncates_options_too_wide_for_it() {
+    let render = |scrollbar| {
+        let mut backend = fake_backend_with_width(vec![Key::Enter], 20);
+
+        Select::new("Question", vec!["a very long option", "b"])
+            .without_filtering()
+            .without_help_message()
+            .with_page_size(1)
+            .with_scrollbar(scrollbar)
+            .with_border(BorderStyle::Rounded)
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        backend.rendered_frames().first().cloned()
+    };
+
+    assert_eq!(
+        Some(String::from(
+            "? Question\n╭──────────────────╮\n│ > a very long o… │\n╰──────────────────╯"
+        )),
+        render(false)
+    );
+    assert_eq!(
+        Some(String::from(
+            "? Question\n╭──────────────────╮\n│ > a very long … █│\n╰──────────────────╯"
+        )),
+        render(true)
+    );
+}
+
+#[test]
+fn try_resolve_requires_a_single_or_exact_match() {
+    let select = Select::new("Question", vec!["dev", "dev-eu", "prod", "prod"]);
+    let resolve = |query| select.try_resolve(query).map(|option| option.index);
+
+    assert_eq!(Some(0), resolve(" dev "));
+    assert_eq!(None, resolve("prod"));
+    assert_eq!(None, resolve("d"));
+    assert_eq!(Some(1), resolve("eu"));
+}
+
+#[test]
+fn filter_placeholder_is_displayed_while_the_input_is_empty() {
+    let mut backend = fake_backend(vec![
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Backspace,
+        Key::Enter,
+    ]);
+
+    let answer = Select::new("Question", vec!["a", "b"])
+        .without_help_message()
+        .with_filter_placeholder("Type to filter…")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "a"), answer);
+
+    let frames = backend.rendered_frames();
+    assert_eq!(
+        "? Question Type to filter… \n> a\n  b",
+        frames.first().unwrap()
+    );
+    assert_eq!("? Question b \n> b", frames.get(1).unwrap());
+    assert_eq!(frames.first(), frames.get(2));
+}
+
+#[test]
+fn ensure_visible_scrolls_the_first_page_to_the_option() {
+    let mut backend = fake_backend(vec![Key::End, Key::Home, Key::Enter]);
+    let options = vec!["a", "b", "c", "d", "e", "f", "g", "h", "i", "j"];
+
+    Select::new("Question", options)
+        .without_filtering()
+        .without_help_message()
+        .with_page_size(3)
+        .with_starting_cursor(4)
+        .with_ensure_visible(6)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let frames = backend.rendered_frames();
+    assert_eq!("? Question\n> e\n  f\nv g", frames.first().unwrap());
+    assert_eq!("? Question\n^ h\n  i\n> j", frames.get(1).unwrap());
+    assert_eq!("? Question\n> a\n  b\nv c", frames.get(2).unwrap());
+}
+
+#[test]
+fn ensure_visible_keeps_the_paging_mode_once_the_cursor_moves() {
+    let mut backend = fake_backend(vec![Key::Up(KeyModifiers::NONE), Key::Enter]);
+    let options = vec!["a", "b", "c", "d", "e", "f", "g", "h", "i", "j"];
+
+    Select::new("Question", options.clone())
+        .without_filtering()
+        .without_help_message()
+        .with_page_size(3)
+        .with_starting_cursor(4)
+        .with_ensure_visible(6)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let frames = backend.rendered_frames();
+    assert_eq!("? Question\n> e\n  f\nv g", frames.first().unwrap());
+    assert_eq!("? Question\n^ c\n> d\nv e", frames.get(1).unwrap());
+
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    Select::new("Question", options)
+        .without_filtering()
+        .without_help_message()
+        .with_page_size(5)
+        .with_starting_cursor(4)
+        .with_ensure_visible(8)
+        .with_scroll_margin(1)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let frames = backend.rendered_frames();
+    assert_eq!(
+        "? Question\n^ d\n> e\n  f\n  g\nv h",
+        frames.first().unwrap()
+    );
+    assert_eq!(
+        "? Question\n^ d\n  e\n> f\n  g\nv h",
+        frames.get(1).unwrap()
+    );
+}
+
+#[test]
+fn ensure_visible_applies_to_the_options_matching_the_starting_filter_input() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+    let options = vec!["b", "a1", "a2", "a3", "a4", "a5", "a6", "a7"];
+
+    Select::new("Question", options)
+        .without_help_message()
+        .with_page_size(3)
+        .with_starting_cursor(3)
+        .with_starting_filter_input("a")
+        .with_ensure_visible(5)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        "? Question a \n> a3\n  a4\nv a5",
+        backend.rendered_frames().first().unwrap()
+    );
+}
+
+#[test]
+fn ensure_visible_keeps_the_cursor_visible() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+    let options = vec!["a", "b", "c", "d", "e", "f", "g", "h", "i", "j"];
+
+    Select::new("Question", options)
+        .without_filtering()
+        .without_help_message()
+        .with_page_size(3)
+        .with_ensure_visible(8)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        "? Question\n> a\n  b\nv c",
+        backend.rendered_frames().first().unwrap()
+    );
+}
+
+#[test]
+fn statuses_are_aligned_before_the_options() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+    let option_status =
+        StatusRenderConfig::empty().with_indicator(StatusKind::Success, Styled::new("✅"));
+    backend.set_render_config(RenderConfig::empty().with_option_status(option_status));
+
+    let status: StatusProvider<'_> = &|index| match index {
+        0 => StatusKind::Success,
+        1 => StatusKind::Failure,
+        _ => StatusKind::Unknown,
+    };
+    Select::new("Question", vec!["a", "b", "c"])
+        .without_filtering()
+        .without_help_message()
+        .with_status(status)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        "? Question\n> ✅ a\n  ✘  b\n  ?  c",
+        backend.rendered_frames().first().unwrap()
+    );
+}
+
+#[test]
+fn help_suffix_is_appended_to_the_help_message() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    Select::new("Question", vec!["a"])
+        .without_filtering()
+        .with_help_suffix("ctrl+r to reload")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        "? Question\n> a\n[↑↓ to move, enter to select, ctrl+r to reload]",
+        backend.rendered_frames().first().unwrap()
+    );
+
+    let select = Select::new("Question", vec!["a"])
+        .with_help_message("pick one")
+        .with_help_suffix("ctrl+r to reload");
+    assert_eq!(
+        Some(String::from("pick one, ctrl+r to reload")),
+        select.displayed_help_message()
+    );
+    assert_eq!(None, select.without_help_message().displayed_help_message());
+}
+
+#[test]
+fn range_is_extended_from_the_anchor_with_shift_arrows() {
+    let shift = KeyModifiers::SHIFT;
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(shift),
+        Key::Down(shift),
+        Key::Enter,
+    ]);
+
+    let range = RangeSelect::new("Question", vec!["a", "b", "c", "d", "e"])
+        .without_help_message()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!((1, 3), range);
+    assert_eq!(
+        "? Question\n  a\n  b *\n  c *\n> d *\n  e",
+        backend.rendered_frames().get(3).unwrap()
+    );
+}
+
+#[test]
+fn range_collapses_when_moving_without_shift() {
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::SHIFT),
+        Key::Up(KeyModifiers::SHIFT),
+        Key::Up(KeyModifiers::SHIFT),
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::SHIFT),
+        Key::Enter,
+    ]);
+
+    let range = RangeSelect::new("Question", vec!["a", "b", "c", "d"])
+        .with_starting_cursor(1)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!((1, 2), range);
+}
+
+#[test]
+fn short_queries_display_all_options_with_min_query_len() {
+    let mut backend = fake_backend(vec![
+        Key::Char('p', KeyModifiers::NONE),
+        Key::Char('p', KeyModifiers::NONE),
+        Key::Backspace,
+        Key::Enter,
+    ]);
+
+    let answer = Select::new("Fruit?", vec!["Banana", "Apple", "Grape"])
+        .without_help_message()
+        .with_min_query_len(2)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "Banana"), answer);
+
+    let all = "? Fruit? p \n> Banana\n  Apple\n  Grape";
+    let frames = backend.rendered_frames();
+    assert_eq!(all, frames.get(1).unwrap());
+    assert_eq!("? Fruit? pp \n> Apple", frames.get(2).unwrap());
+    assert_eq!(all, frames.get(3).unwrap());
+}
+
+#[test]
+fn injected_crossterm_events_render_every_frame() {
+    use crate::{terminal::crossterm::CrosstermKeyReader, ui::Backend};
+    use crossterm::event::{Event, KeyCode, KeyEvent};
+
+    let events = vec![KeyCode::Down, KeyCode::Down, KeyCode::Enter]
+        .into_iter()
+        .map(|code| Event::Key(KeyEvent::new(code, crossterm::event::KeyModifiers::NONE)));
+    let input_reader = CrosstermKeyReader::new().with_event_source(events);
+    let terminal = crate::terminal::crossterm::CrosstermTerminal::new_in_memory_output();
+    let mut backend = Backend::new(input_reader, terminal, RenderConfig::default()).unwrap();
+
+    let ans = Select::new("Question", vec!["a", "b", "c"])
+        .without_filtering()
+        .without_help_message()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "c"), ans);
+    assert_eq!(
+        &[
+            "? Question\n> a\n  b\n  c",
+            "? Question\n  a\n> b\n  c",
+            "? Question\n  a\n  b\n> c",
+            "> Question c",
+        ],
+        backend.rendered_frames()
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn settings_round_trip_through_serde() {
+    let settings = SelectSettings {
+        page_size: 3,
+        help_message: Some(String::from("Loaded from the config file")),
+        cancel_key: Some(Key::Char('q', KeyModifiers::CONTROL)),
+        border: Some(BorderStyle::Rounded),
+        escape_timeout: Some(Duration::from_millis(50)),
+        layout: SelectLayout::Horizontal,
+        ..Default::default()
+    };
+
+    let json = serde_json::to_string(&settings).unwrap();
+    assert_eq!(settings, serde_json::from_str(&json).unwrap());
+
+    let partial: SelectSettings = serde_json::from_str(r#"{"page_size": 3}"#).unwrap();
+    assert_eq!(
+        SelectSettings {
+            page_size: 3,
+            ..Default::default()
+        },
+        partial
+    );
+}
+
+#[test]
+fn out_of_bounds_starting_cursor_fails_by_default() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = Select::new("Question", vec!["a", "b", "c"])
+        .with_starting_cursor(5)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn out_of_bounds_starting_cursor_can_be_clamped() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = Select::new("Question", vec!["a", "b", "c"])
+        .with_starting_cursor(5)
+        .with_cursor_overflow(CursorOverflow::Clamp)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "c"), ans);
+}
+
+#[test]
+fn out_of_bounds_starting_cursor_can_be_wrapped() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = Select::new("Question", vec!["a", "b", "c"])
+        .with_starting_cursor(4)
+        .with_cursor_overflow(CursorOverflow::Wrap)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "b"), ans);
+}
+
+#[test]
+fn filtered_indices_match_prompt_filtering() {
+    let options = vec!["Banana", "Apple", "Blueberry", "Avocado"];
+
+    let select = Select::new("Fruit?", options.clone());
+    assert_eq!(vec![0, 1, 2, 3], select.filtered_indices(""));
+    assert_eq!(vec![2], select.filtered_indices("blue"));
+
+    let select = Select::new("Fruit?", options).with_accesskeys(true);
+    assert_eq!(vec![1, 3], select.filtered_indices("a"));
+    assert_eq!(vec![3], select.filtered_indices("av"));
+
+    let select =
+        Select::new("Color?", vec!["\x1b[31mred\x1b[0m", "blue"]).with_prestyled_options(true);
+    assert!(select.filtered_indices("m").is_empty());
+    assert_eq!(vec![0], select.filtered_indices("ed"));
+    assert_eq!(Some(0), select.try_resolve("red").map(|color| color.index));
+
+    let annotations = [("Cargo.toml", "manifest")];
+    let select = Select::new("File", vec!["README.md", "main.rs", "Cargo.toml"])
+        .with_aligned_annotations(&annotations)
+        .with_filter_on_rendered(true);
+    assert_eq!(vec![2], select.filtered_indices("manifest"));
+}
+
+#[test]
+fn typeahead_jumps_to_options_by_prefix() {
+    let mut backend = fake_backend(vec![
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Char('l', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Select::new("Fruit?", vec!["Apple", "Banana", "Blueberry", "Cherry"])
+        .without_filtering()
+        .with_typeahead(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "Blueberry"), ans);
+}
+
+#[test]
+fn typeahead_cycles_through_options_with_repeated_letter() {
+    let mut backend = fake_backend(vec![
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Select::new("Fruit?", vec!["Apple", "Banana", "Blueberry", "Cherry"])
+        .without_filtering()
+        .with_typeahead(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "Banana"), ans);
+}
+
+#[test]
+fn typeahead_is_ignored_when_filtering() {
+    let mut backend = fake_backend(vec![Key::Char('c', KeyModifiers::NONE), Key::Enter]);
+
+    let ans = Select::new("Fruit?", vec!["Apple", "Banana", "Cherry"])
+        .with_typeahead(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    // 'c' is written to the filter, which leaves Cherry as the only option
+    assert_eq!(ListOption::new(2, "Cherry"), ans);
+}
+
+#[test]
+fn payload_of_selected_option_is_returned() {
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let payloads = [10, 20, 30];
+
+    let ans = Select::new("Question", vec!["a", "b", "c"])
+        .prompt_with_payloads_and_backend(&payloads, &mut backend)
+        .unwrap();
+
+    assert_eq!(("b", &20), ans);
+}
+
+#[test]
+fn payloads_must_match_options_length() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = Select::new("Question", vec!["a", "b", "c"])
+        .prompt_with_payloads_and_backend(&[10, 20], &mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn payloads_are_rejected_with_refreshed_options() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let refresher: Refresher<'_, &str> = &|| vec!["c", "b", "a", "d"];
+
+    let ans = Select::new("Question", vec!["a", "b", "c"])
+        .with_refresh(refresher, Duration::from_millis(100))
+        .prompt_with_payloads_and_backend(&[10, 20, 30], &mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn refreshed_options_keep_highlighted_option() {
+    let mut backend = fake_backend_with_timeouts(vec![
+        Some(Key::Down(KeyModifiers::NONE)),
+        None,
+        Some(Key::Enter),
+    ]);
+
+    let refresher: Refresher<'_, &str> = &|| vec!["c", "b", "a"];
+
+    let ans = Select::new("Question", vec!["a", "b"])
+        .with_refresh(refresher, Duration::from_millis(100))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "b"), ans);
+}
+
+#[test]
+fn refreshed_options_keep_highlighted_duplicate() {
+    let mut backend = fake_backend_with_timeouts(vec![
+        Some(Key::Down(KeyModifiers::NONE)),
+        None,
+        Some(Key::Enter),
+    ]);
+
+    let refresher: Refresher<'_, &str> = &|| vec!["a", "a", "b"];
+
+    let ans = Select::new("Question", vec!["a", "a"])
+        .with_refresh(refresher, Duration::from_millis(100))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "a"), ans);
+}
+
+#[test]
+fn refreshed_options_are_filtered() {
+    let mut backend = fake_backend_with_timeouts(vec![
+        Some(Key::Char('b', KeyModifiers::NONE)),
+        None,
+        Some(Key::Down(KeyModifiers::NONE)),
+        Some(Key::Enter),
+    ]);
+
+    let scorer: Scorer<'_, &str> =
+        &|input, _option, string_value, _idx| string_value.contains(input).then_some(0);
+    let refresher: Refresher<'_, &str> = &|| vec!["bc", "a", "ab"];
+
+    let ans = Select::new("Question", vec!["a", "ab"])
+        .with_scorer(scorer)
+        .with_refresh(refresher, Duration::from_millis(100))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    // "ab" stays highlighted after the refresh, and "bc" is listed before it
+    assert_eq!(
+        Some(&String::from(
+            "? Question b \n  bc\n> ab\n[↑↓ to move, enter to select, type to filter]"
+        )),
+        backend.rendered_frames().get(2)
+    );
+    assert_eq!(ListOption::new(0, "bc"), ans);
+}
+
+#[test]
+fn cursor_is_clamped_when_highlighted_option_is_removed_on_refresh() {
+    let mut backend = fake_backend_with_timeouts(vec![Some(Key::End), None, Some(Key::Enter)]);
+
+    let refresher: Refresher<'_, &str> = &|| vec!["a", "b"];
+
+    let ans = Select::new("Question", vec!["a", "b", "c"])
+        .with_refresh(refresher, Duration::from_millis(100))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "b"), ans);
+}
+
+#[test]
+fn unmatched_filter_input_is_returned_as_new_answer() {
+    let mut backend = fake_backend(vec![
+        Key::Char('z', KeyModifiers::NONE),
+        Key::Char('z', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Select::new("Tag", vec!["bug", "feature"])
+        .with_create_new(true)
+        .prompt_result_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(SelectResult::New(String::from("zz")), ans);
+    assert_eq!(
+        Some(&String::from("> Tag zz")),
+        backend.rendered_frames().last()
+    );
+}
+
+#[test]
+fn matched_filter_input_selects_option_with_create_new() {
+    let mut backend = fake_backend(vec![Key::Char('f', KeyModifiers::NONE), Key::Enter]);
+
+    let ans = Select::new("Tag", vec!["bug", "feature"])
+        .with_create_new(true)
+        .prompt_result_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(SelectResult::Selected(ListOption::new(1, "feature")), ans);
+}
+
+#[test]
+fn unmatched_filter_input_is_not_submitted_without_create_new() {
+    let mut backend = fake_backend(vec![
+        Key::Char('z', KeyModifiers::NONE),
+        Key::Enter,
+        Key::Backspace,
+        Key::Enter,
+    ]);
+
+    let ans = Select::new("Tag", vec!["bug", "feature"])
+        .prompt_result_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(SelectResult::Selected(ListOption::new(0, "bug")), ans);
+}
+
+#[test]
+fn recommended_option_is_flagged_independently_from_cursor() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let ans = Select::new("Install?", vec!["Minimal", "Full", "Custom"])
+        .without_filtering()
+        .without_help_message()
+        .with_recommended(1)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "Minimal"), ans);
+    assert_eq!(
+        Some(&String::from(
+            "? Install?\n> Minimal\n  Full (recommended)\n  Custom"
+        )),
+        backend.rendered_frames().first()
+    );
+}
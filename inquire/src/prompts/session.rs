@@ -0,0 +1,66 @@
+use crate::{
+    config::get_configuration,
+    error::InquireResult,
+    terminal::{get_terminal, DefaultInputReader, DefaultTerminal, Terminal},
+    ui::Backend,
+};
+
+/// Terminal shared by a sequence of prompts, such as the steps of a wizard,
+/// set up once when the session is created and restored when it is dropped.
+///
+/// Prompting each step on its own takes control of the terminal again every
+/// time, entering raw mode and, if configured, the alternate screen, which
+/// can flicker between the steps. Prompts run in a session keep the same
+/// terminal instead, and are rendered one below the other.
+///
+/// Prompts are run in the session with
+/// [Select::prompt_with_session](crate::Select::prompt_with_session). The
+/// terminal settings of the prompts themselves, such as
+/// [Select::alternate_screen](crate::Select::alternate_screen), are ignored
+/// in favour of the ones of the session.
+///
+/// ```no_run
+/// use inquire::{PromptSession, Select};
+///
+/// let mut session = PromptSession::new()?;
+///
+/// let region = Select::new("Region:", vec!["eu-west", "us-east"])
+///     .with_progress(1, 2)
+///     .prompt_with_session(&mut session)?;
+/// let size = Select::new("Instance size:", vec!["small", "large"])
+///     .with_progress(2, 2)
+///     .prompt_with_session(&mut session)?;
+///
+/// drop(session);
+/// println!("Deploying a {size} instance to {region}");
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+pub struct PromptSession<'a> {
+    pub(crate) backend: Backend<'a, DefaultInputReader, DefaultTerminal>,
+}
+
+impl<'a> PromptSession<'a> {
+    /// Creates a session rendering the prompts to stderr, like standalone
+    /// prompts.
+    pub fn new() -> InquireResult<Self> {
+        Self::create(false)
+    }
+
+    /// Creates a session rendering the prompts on the alternate screen
+    /// buffer, restoring the main screen when the session is dropped.
+    pub fn in_alternate_screen() -> InquireResult<Self> {
+        Self::create(true)
+    }
+
+    fn create(alternate_screen: bool) -> InquireResult<Self> {
+        let (input_reader, mut terminal) = get_terminal(false)?;
+
+        if alternate_screen {
+            terminal.enter_alternate_screen()?;
+        }
+
+        Ok(Self {
+            backend: Backend::new(input_reader, terminal, get_configuration())?,
+        })
+    }
+}
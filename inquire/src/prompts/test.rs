@@ -1,5 +1,5 @@
 use crate::{
-    terminal::crossterm::CrosstermTerminal,
+    terminal::{crossterm::CrosstermTerminal, test::MockTerminal, TerminalSize},
     ui::{Backend, InputReader, Key, RenderConfig},
 };
 
@@ -21,3 +21,52 @@ pub fn fake_backend(input: Vec<Key>) -> Backend<'static, impl InputReader, Cross
     let output = CrosstermTerminal::new_in_memory_output();
     Backend::new(input.into_iter(), output, RenderConfig::default()).unwrap()
 }
+
+/// Backend rendering to a terminal of the provided width, for the tests
+/// whose frames depend on it.
+pub fn fake_backend_with_width(
+    input: Vec<Key>,
+    width: u16,
+) -> Backend<'static, impl InputReader, MockTerminal> {
+    let terminal = MockTerminal::new().with_size(TerminalSize::new(width, 40));
+    Backend::new(input.into_iter(), terminal, RenderConfig::default()).unwrap()
+}
+
+/// Input reader where `None` entries simulate the polling timeout elapsing
+/// without any key being pressed.
+pub struct TimedInput(std::vec::IntoIter<Option<Key>>);
+
+impl InputReader for TimedInput {
+    fn read_key(&mut self) -> crate::error::InquireResult<Key> {
+        match self.0.by_ref().flatten().next() {
+            Some(key) => Ok(key),
+            None => panic!("EOF"),
+        }
+    }
+
+    fn poll_key(
+        &mut self,
+        timeout: std::time::Duration,
+    ) -> crate::error::InquireResult<Option<Key>> {
+        if timeout.is_zero() {
+            return Ok(None);
+        }
+
+        match self.0.next() {
+            Some(key) => Ok(key),
+            None => panic!("EOF"),
+        }
+    }
+}
+
+pub fn fake_backend_with_timeouts(
+    input: Vec<Option<Key>>,
+) -> Backend<'static, TimedInput, CrosstermTerminal> {
+    let output = CrosstermTerminal::new_in_memory_output();
+    Backend::new(
+        TimedInput(input.into_iter()),
+        output,
+        RenderConfig::default(),
+    )
+    .unwrap()
+}
@@ -185,6 +185,10 @@ where
         self.update_suggestions()
     }
 
+    fn has_validators(&self) -> bool {
+        !self.validators.is_empty()
+    }
+
     fn submit(&mut self) -> InquireResult<Option<String>> {
         let result = match self.validate_current_answer()? {
             Validation::Valid => Some(self.get_current_answer().to_owned()),
@@ -9,9 +9,13 @@ use crate::{
 
 use super::Terminal;
 
+const ENTER_ALTERNATE_SCREEN: &str = "\x1b[?1049h";
+const LEAVE_ALTERNATE_SCREEN: &str = "\x1b[?1049l";
+
 #[derive(Clone)]
 pub struct ConsoleTerminal {
     term: Term,
+    alternate_screen: bool,
 }
 
 impl ConsoleTerminal {
@@ -19,6 +23,7 @@ impl ConsoleTerminal {
     pub fn new() -> Self {
         Self {
             term: Term::stderr(),
+            alternate_screen: false,
         }
     }
 }
@@ -101,10 +106,27 @@ impl Terminal for ConsoleTerminal {
     fn cursor_show(&mut self) -> Result<()> {
         self.term.show_cursor()
     }
+
+    fn enter_alternate_screen(&mut self) -> Result<()> {
+        // console does not support the alternate screen, so the escape
+        // sequences are written directly.
+        self.term.write_str(ENTER_ALTERNATE_SCREEN)?;
+        self.term.move_cursor_to(0, 0)?;
+        self.alternate_screen = true;
+        self.flush()
+    }
+
+    fn enable_focus_reporting(&mut self) -> Result<()> {
+        // console's key reader does not parse focus events.
+        Ok(())
+    }
 }
 
 impl Drop for ConsoleTerminal {
     fn drop(&mut self) {
+        if self.alternate_screen {
+            let _unused = self.term.write_str(LEAVE_ALTERNATE_SCREEN);
+        }
         let _unused = self.flush();
     }
 }
@@ -131,6 +153,10 @@ impl From<StyleSheet> for Style {
             style = style.attr(Attribute::Italic);
         }
 
+        if from.att.contains(Attributes::UNDERLINE) {
+            style = style.attr(Attribute::Underlined);
+        }
+
         style
     }
 }
@@ -161,6 +187,7 @@ impl From<Key> for crate::ui::Key {
             Key::Escape => Self::Escape,
             Key::Char('\n' | '\r') | Key::Enter => Self::Enter,
             Key::Char('\t') | Key::Tab => Self::Tab,
+            Key::BackTab => Self::BackTab,
             Key::Backspace => Self::Backspace,
             Key::Del => Self::Delete(KeyModifiers::empty()),
             Key::Home => Self::Home,
@@ -1,4 +1,9 @@
-use std::io::{stderr, Result, Stderr, Write};
+use std::{
+    collections::VecDeque,
+    fs::{File, OpenOptions},
+    io::{stderr, Result, Stderr, Write},
+    time::{Duration, Instant},
+};
 
 use crossterm::{
     cursor,
@@ -10,38 +15,189 @@ use crossterm::{
 };
 
 use crate::{
-    error::InquireResult,
+    error::{InquireError, InquireResult},
     ui::{Attributes, InputReader, Key, Styled},
 };
 
 use super::Terminal;
 
+/// Path of the controlling terminal, used instead of the standard streams
+/// when they are redirected.
+#[cfg(not(windows))]
+const TTY_PATH: &str = "/dev/tty";
+#[cfg(windows)]
+const TTY_PATH: &str = "CONOUT$";
+
 enum IO {
     Std(Stderr),
+    Tty(File),
     #[allow(unused)]
     Test(Vec<u8>),
 }
 
 pub struct CrosstermTerminal {
     io: IO,
+    alternate_screen: bool,
+    focus_reporting: bool,
 }
 
-pub struct CrosstermKeyReader;
+pub struct CrosstermKeyReader {
+    escape_timeout: Option<Duration>,
+    pending: VecDeque<Key>,
+    event_source: Option<Box<dyn Iterator<Item = event::Event>>>,
+}
 
 impl CrosstermKeyReader {
     pub fn new() -> Self {
-        Self
+        Self {
+            escape_timeout: None,
+            pending: VecDeque::new(),
+            event_source: None,
+        }
+    }
+
+    /// Reads the events from the provided source instead of the terminal,
+    /// failing with an [UnexpectedEof](std::io::ErrorKind::UnexpectedEof) IO
+    /// error once the events are exhausted.
+    pub fn with_event_source(
+        mut self,
+        events: impl Iterator<Item = event::Event> + 'static,
+    ) -> Self {
+        self.event_source = Some(Box::new(events));
+        self
+    }
+
+    /// Returns the next key of the event source, or `None` once exhausted.
+    fn next_injected_key(mut events: impl Iterator<Item = event::Event>) -> Option<Key> {
+        events.find_map(map_event)
+    }
+
+    fn poll_event(&mut self, timeout: Duration) -> InquireResult<Option<Key>> {
+        if let Some(events) = &mut self.event_source {
+            // injected events are paced as if typed one at a time, so that
+            // the prompt is rendered between them instead of handling them
+            // all as buffered keys
+            if timeout.is_zero() {
+                return Ok(None);
+            }
+
+            return Ok(Self::next_injected_key(events.as_mut()));
+        }
+
+        let deadline = Instant::now() + timeout;
+
+        while event::poll(deadline.saturating_duration_since(Instant::now()))? {
+            if let Some(key) = map_event(event::read()?) {
+                return Ok(Some(key));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn complete(&mut self, key: Key) -> InquireResult<Key> {
+        match (key, self.escape_timeout) {
+            (Key::Escape, Some(timeout)) => {
+                let mut pending = std::mem::take(&mut self.pending);
+                let key = complete_escape_sequence(|| self.poll_event(timeout), &mut pending);
+                self.pending = pending;
+                key
+            }
+            (key, _) => Ok(key),
+        }
+    }
+}
+
+/// Reassembles an escape sequence split across reads, which is decoded as a
+/// lone Esc followed by the characters of the sequence, such as `[A` for the
+/// up arrow. Called after reading an Esc, with a function returning the next
+/// key if it arrives in time.
+///
+/// Keys read that do not complete a sequence are queued in `pending`, and a
+/// standalone Esc is returned.
+fn complete_escape_sequence(
+    mut next_key: impl FnMut() -> InquireResult<Option<Key>>,
+    pending: &mut VecDeque<Key>,
+) -> InquireResult<Key> {
+    let introducer = match next_key()? {
+        Some(key @ Key::Char('[' | 'O', crate::ui::KeyModifiers::NONE)) => key,
+        Some(key) => {
+            pending.push_back(key);
+            return Ok(Key::Escape);
+        }
+        None => return Ok(Key::Escape),
+    };
+
+    let none = crate::ui::KeyModifiers::NONE;
+    let final_key = next_key()?;
+    let completed = match final_key {
+        Some(Key::Char('A', _)) => Some(Key::Up(none)),
+        Some(Key::Char('B', _)) => Some(Key::Down(none)),
+        Some(Key::Char('C', _)) => Some(Key::Right(none)),
+        Some(Key::Char('D', _)) => Some(Key::Left(none)),
+        Some(Key::Char('H', _)) => Some(Key::Home),
+        Some(Key::Char('F', _)) => Some(Key::End),
+        _ => None,
+    };
+
+    if let Some(key) = completed {
+        return Ok(key);
+    }
+
+    pending.push_back(introducer);
+    pending.extend(final_key);
+
+    Ok(Key::Escape)
+}
+
+fn exhausted_events() -> InquireError {
+    InquireError::IO(std::io::Error::new(
+        std::io::ErrorKind::UnexpectedEof,
+        "injected events are exhausted",
+    ))
+}
+
+fn map_event(event: event::Event) -> Option<Key> {
+    match event {
+        event::Event::Key(key_event) => Some(key_event.into()),
+        event::Event::FocusLost => Some(Key::FocusLost),
+        _ => None,
     }
 }
 
 impl InputReader for CrosstermKeyReader {
     fn read_key(&mut self) -> InquireResult<Key> {
+        if let Some(key) = self.pending.pop_front() {
+            return Ok(key);
+        }
+
+        if let Some(events) = &mut self.event_source {
+            let key = Self::next_injected_key(events.as_mut()).ok_or_else(exhausted_events)?;
+            return self.complete(key);
+        }
+
         loop {
-            if let event::Event::Key(key_event) = event::read()? {
-                return Ok(key_event.into());
+            if let Some(key) = map_event(event::read()?) {
+                return self.complete(key);
             }
         }
     }
+
+    fn poll_key(&mut self, timeout: Duration) -> InquireResult<Option<Key>> {
+        if let Some(key) = self.pending.pop_front() {
+            return Ok(Some(key));
+        }
+
+        match self.poll_event(timeout)? {
+            Some(key) => self.complete(key).map(Some),
+            None if self.event_source.is_some() && !timeout.is_zero() => Err(exhausted_events()),
+            None => Ok(None),
+        }
+    }
+
+    fn set_escape_timeout(&mut self, escape_timeout: Duration) {
+        self.escape_timeout = Some(escape_timeout);
+    }
 }
 
 impl CrosstermTerminal {
@@ -50,12 +206,32 @@ impl CrosstermTerminal {
 
         Ok(Self {
             io: IO::Std(stderr()),
+            alternate_screen: false,
+            focus_reporting: false,
+        })
+    }
+
+    /// Creates a terminal writing to the controlling terminal directly,
+    /// instead of stderr, for when the standard streams are redirected.
+    ///
+    /// Keys are always read from the controlling terminal when stdin is not
+    /// one, on Unix through `/dev/tty` and on Windows through `CONIN$`.
+    pub fn from_tty() -> InquireResult<Self> {
+        let tty = OpenOptions::new().read(true).write(true).open(TTY_PATH)?;
+
+        terminal::enable_raw_mode()?;
+
+        Ok(Self {
+            io: IO::Tty(tty),
+            alternate_screen: false,
+            focus_reporting: false,
         })
     }
 
     fn get_writer(&mut self) -> &mut dyn Write {
         match &mut self.io {
             IO::Std(w) => w,
+            IO::Tty(w) => w,
             IO::Test(w) => w,
         }
     }
@@ -71,6 +247,9 @@ impl CrosstermTerminal {
         if attributes.contains(Attributes::ITALIC) {
             self.write_command(SetAttribute(Attribute::Italic))?;
         }
+        if attributes.contains(Attributes::UNDERLINE) {
+            self.write_command(SetAttribute(Attribute::Underlined))?;
+        }
 
         Ok(())
     }
@@ -182,13 +361,32 @@ impl Terminal for CrosstermTerminal {
     fn cursor_show(&mut self) -> Result<()> {
         self.write_command(cursor::Show)
     }
+
+    fn enter_alternate_screen(&mut self) -> Result<()> {
+        self.write_command(terminal::EnterAlternateScreen)?;
+        self.write_command(cursor::MoveTo(0, 0))?;
+        self.alternate_screen = true;
+        self.flush()
+    }
+
+    fn enable_focus_reporting(&mut self) -> Result<()> {
+        self.write_command(event::EnableFocusChange)?;
+        self.focus_reporting = true;
+        self.flush()
+    }
 }
 
 impl Drop for CrosstermTerminal {
     fn drop(&mut self) {
+        if self.focus_reporting {
+            let _unused = self.write_command(event::DisableFocusChange);
+        }
+        if self.alternate_screen {
+            let _unused = self.write_command(terminal::LeaveAlternateScreen);
+        }
         let _unused = self.flush();
         let _unused = match self.io {
-            IO::Std(_) => terminal::disable_raw_mode(),
+            IO::Std(_) | IO::Tty(_) => terminal::disable_raw_mode(),
             IO::Test(_) => Ok(()),
         };
     }
@@ -264,6 +462,10 @@ impl From<KeyEvent> for Key {
                 code: KeyCode::Tab | KeyCode::Char('\t'),
                 ..
             } => Self::Tab,
+            KeyEvent {
+                code: KeyCode::BackTab,
+                ..
+            } => Self::BackTab,
             KeyEvent {
                 code: KeyCode::Backspace,
                 ..
@@ -323,10 +525,17 @@ impl From<KeyEvent> for Key {
 
 #[cfg(test)]
 mod test {
+    use std::{collections::VecDeque, time::Duration};
+
+    use crossterm::event::{self, Event, KeyCode, KeyEvent};
+
+    use crate::error::InquireError;
     use crate::terminal::Terminal;
-    use crate::ui::Color;
+    use crate::ui::{Color, InputReader, Key, KeyModifiers};
 
+    use super::complete_escape_sequence;
     use super::Attributes;
+    use super::CrosstermKeyReader;
     use super::CrosstermTerminal;
     use super::IO;
 
@@ -334,12 +543,15 @@ mod test {
         pub fn new_in_memory_output() -> Self {
             Self {
                 io: IO::Test(Vec::new()),
+                alternate_screen: false,
+                focus_reporting: false,
             }
         }
 
         pub fn get_buffer_content(&mut self) -> Vec<u8> {
             match &mut self.io {
                 IO::Std(_) => panic!("Cannot get write buffer from standard output"),
+                IO::Tty(_) => panic!("Cannot get write buffer from the terminal"),
                 IO::Test(w) => {
                     let mut buffer = Vec::new();
                     std::mem::swap(&mut buffer, w);
@@ -365,6 +577,19 @@ mod test {
         );
     }
 
+    #[test]
+    fn alternate_screen_is_entered_at_top_left_corner() {
+        let mut terminal = CrosstermTerminal::new_in_memory_output();
+
+        terminal.enter_alternate_screen().unwrap();
+
+        #[cfg(unix)]
+        assert_eq!(
+            "\x1B[?1049h\x1B[1;1H",
+            std::str::from_utf8(&terminal.get_buffer_content()).unwrap()
+        );
+    }
+
     #[test]
     fn style_management() {
         let mut terminal = CrosstermTerminal::new_in_memory_output();
@@ -428,4 +653,66 @@ mod test {
             std::str::from_utf8(&terminal.get_buffer_content()).unwrap()
         );
     }
+
+    fn complete(keys: Vec<Key>) -> (Key, Vec<Key>) {
+        let mut keys = keys.into_iter();
+        let mut pending = VecDeque::new();
+        let key = complete_escape_sequence(|| Ok(keys.next()), &mut pending).unwrap();
+
+        (key, pending.into_iter().collect())
+    }
+
+    #[test]
+    fn split_escape_sequence_is_reassembled() {
+        let none = KeyModifiers::NONE;
+
+        assert_eq!(
+            (Key::Up(none), vec![]),
+            complete(vec![Key::Char('[', none), Key::Char('A', none)])
+        );
+        assert_eq!(
+            (Key::End, vec![]),
+            complete(vec![Key::Char('O', none), Key::Char('F', none)])
+        );
+    }
+
+    #[test]
+    fn lone_escape_keeps_the_following_keys() {
+        let none = KeyModifiers::NONE;
+
+        assert_eq!((Key::Escape, vec![]), complete(vec![]));
+        assert_eq!(
+            (Key::Escape, vec![Key::Char('q', none)]),
+            complete(vec![Key::Char('q', none)])
+        );
+        assert_eq!(
+            (
+                Key::Escape,
+                vec![Key::Char('[', none), Key::Char('z', none)]
+            ),
+            complete(vec![Key::Char('[', none), Key::Char('z', none)])
+        );
+    }
+
+    #[test]
+    fn keys_are_read_from_the_event_source() {
+        let key_event = |code| Event::Key(KeyEvent::new(code, event::KeyModifiers::NONE));
+        let events = vec![
+            key_event(KeyCode::Down),
+            Event::FocusGained,
+            key_event(KeyCode::Esc),
+            key_event(KeyCode::Char('[')),
+            key_event(KeyCode::Char('A')),
+        ];
+
+        let mut reader = CrosstermKeyReader::new().with_event_source(events.into_iter());
+        reader.set_escape_timeout(Duration::from_millis(10));
+
+        assert_eq!(Key::Down(KeyModifiers::NONE), reader.read_key().unwrap());
+        assert_eq!(Key::Up(KeyModifiers::NONE), reader.read_key().unwrap());
+        assert!(matches!(
+            reader.read_key(),
+            Err(InquireError::IO(err)) if err.kind() == std::io::ErrorKind::UnexpectedEof
+        ));
+    }
 }
@@ -17,6 +17,8 @@ pub mod termion;
 #[cfg_attr(docsrs, doc(cfg(feature = "console")))]
 pub mod console;
 
+pub(crate) mod replay;
+
 #[cfg(test)]
 pub(crate) mod test;
 
@@ -40,14 +42,106 @@ pub trait Terminal: Sized {
     #[allow(unused)]
     fn cursor_move_to_column(&mut self, idx: u16) -> Result<()>;
 
+    /// Switches to the alternate screen buffer, with the cursor at its top-left
+    /// corner. The main screen buffer is restored when the terminal is dropped.
+    fn enter_alternate_screen(&mut self) -> Result<()>;
+
+    /// Asks the terminal to report focus changes, surfaced as
+    /// [`Key::FocusLost`](crate::ui::Key::FocusLost). Reporting is disabled
+    /// again when the terminal is dropped. Backends that cannot parse focus
+    /// events treat this as a no-op.
+    fn enable_focus_reporting(&mut self) -> Result<()>;
+
     fn flush(&mut self) -> Result<()>;
 }
 
+/// Size of the terminal the default backend renders to, queried without
+/// taking control of the terminal.
+pub fn get_default_terminal_size() -> Result<TerminalSize> {
+    #[cfg(feature = "crossterm")]
+    return ::crossterm::terminal::size().map(|(width, height)| TerminalSize::new(width, height));
+
+    #[cfg(all(feature = "termion", not(feature = "crossterm")))]
+    return ::termion::terminal_size().map(|(width, height)| TerminalSize::new(width, height));
+
+    #[cfg(all(
+        feature = "console",
+        not(feature = "termion"),
+        not(feature = "crossterm")
+    ))]
+    return ::console::Term::stderr()
+        .size_checked()
+        .map(|(height, width)| TerminalSize::new(width, height))
+        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::Other, "Not a terminal"));
+
+    #[cfg(all(
+        not(feature = "crossterm"),
+        not(feature = "termion"),
+        not(feature = "console")
+    ))]
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Other,
+        "Missing terminal backend",
+    ))
+}
+
+/// Input reader and terminal of the default backend, named so that they can
+/// be stored, e.g. by a [PromptSession](crate::PromptSession).
+#[cfg(feature = "crossterm")]
+pub type DefaultInputReader = crossterm::CrosstermKeyReader;
+#[cfg(feature = "crossterm")]
+pub type DefaultTerminal = crossterm::CrosstermTerminal;
+
+#[cfg(all(feature = "termion", not(feature = "crossterm")))]
+pub type DefaultInputReader = termion::TermionKeyReader;
+#[cfg(all(feature = "termion", not(feature = "crossterm")))]
+pub type DefaultTerminal = termion::TermionTerminal<'static>;
+
+#[cfg(all(
+    feature = "console",
+    not(feature = "termion"),
+    not(feature = "crossterm")
+))]
+pub type DefaultInputReader = console::ConsoleTerminal;
+#[cfg(all(
+    feature = "console",
+    not(feature = "termion"),
+    not(feature = "crossterm")
+))]
+pub type DefaultTerminal = console::ConsoleTerminal;
+
+// placeholders silencing additional errors when no terminals are enabled
+#[cfg(all(
+    not(feature = "crossterm"),
+    not(feature = "termion"),
+    not(feature = "console")
+))]
+pub type DefaultInputReader = replay::ReplayInput<std::iter::Empty<crate::ui::Key>>;
+#[cfg(all(
+    not(feature = "crossterm"),
+    not(feature = "termion"),
+    not(feature = "console")
+))]
+pub type DefaultTerminal = replay::NullTerminal;
+
 pub fn get_default_terminal() -> InquireResult<(impl InputReader, impl Terminal)> {
+    get_terminal(false)
+}
+
+/// Creates the terminal of the default backend, rendering to the
+/// controlling terminal instead of stderr when `use_tty` is set.
+///
+/// The termion backend always uses the controlling terminal, and the
+/// console backend always renders to stderr.
+#[allow(unused_variables)]
+pub fn get_terminal(use_tty: bool) -> InquireResult<(DefaultInputReader, DefaultTerminal)> {
     #[cfg(feature = "crossterm")]
     return Ok((
         crossterm::CrosstermKeyReader::new(),
-        crossterm::CrosstermTerminal::new()?,
+        match use_tty {
+            true => crossterm::CrosstermTerminal::from_tty()?,
+            false => crossterm::CrosstermTerminal::new()?,
+        },
     ));
 
     #[cfg(all(feature = "termion", not(feature = "crossterm")))]
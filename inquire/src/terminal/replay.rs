@@ -0,0 +1,95 @@
+use std::{
+    fmt::Display,
+    io::{Error, ErrorKind, Result},
+};
+
+use crate::{
+    error::{InquireError, InquireResult},
+    ui::{InputReader, Key, Styled},
+};
+
+use super::{Terminal, TerminalSize};
+
+/// Input reader replaying a predefined sequence of keys, failing with an
+/// [ErrorKind::UnexpectedEof] IO error once the keys are exhausted.
+pub(crate) struct ReplayInput<I>(pub I);
+
+impl<I> InputReader for ReplayInput<I>
+where
+    I: Iterator<Item = Key>,
+{
+    fn read_key(&mut self) -> InquireResult<Key> {
+        match self.0.next() {
+            Some(key) => Ok(key),
+            None => Err(InquireError::IO(Error::new(
+                ErrorKind::UnexpectedEof,
+                "replayed keys are exhausted",
+            ))),
+        }
+    }
+}
+
+/// Terminal discarding all output, used to drive prompts without rendering.
+pub(crate) struct NullTerminal;
+
+impl Terminal for NullTerminal {
+    fn get_size(&self) -> Result<TerminalSize> {
+        Ok(TerminalSize::new(80, 24))
+    }
+
+    fn write<T: Display>(&mut self, _val: T) -> Result<()> {
+        Ok(())
+    }
+
+    fn write_styled<T: Display>(&mut self, _val: &Styled<T>) -> Result<()> {
+        Ok(())
+    }
+
+    fn clear_line(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn clear_until_new_line(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn cursor_hide(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn cursor_show(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn cursor_up(&mut self, _cnt: u16) -> Result<()> {
+        Ok(())
+    }
+
+    fn cursor_down(&mut self, _cnt: u16) -> Result<()> {
+        Ok(())
+    }
+
+    fn cursor_left(&mut self, _cnt: u16) -> Result<()> {
+        Ok(())
+    }
+
+    fn cursor_right(&mut self, _cnt: u16) -> Result<()> {
+        Ok(())
+    }
+
+    fn cursor_move_to_column(&mut self, _idx: u16) -> Result<()> {
+        Ok(())
+    }
+
+    fn enter_alternate_screen(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn enable_focus_reporting(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
@@ -10,7 +10,7 @@ use termion::{
     event::Key,
     input::{Keys, TermRead},
     raw::{IntoRawMode, RawTerminal},
-    terminal_size,
+    screen, terminal_size,
 };
 
 use crate::{
@@ -53,6 +53,7 @@ impl InputReader for TermionKeyReader {
 
 pub struct TermionTerminal<'a> {
     io: IO<'a>,
+    alternate_screen: bool,
 }
 
 impl<'a> TermionTerminal<'a> {
@@ -64,6 +65,7 @@ impl<'a> TermionTerminal<'a> {
 
         Ok(Self {
             io: IO::TTY(raw_terminal),
+            alternate_screen: false,
         })
     }
 
@@ -74,6 +76,7 @@ impl<'a> TermionTerminal<'a> {
     pub fn new_with_writer<W: 'a + Write>(writer: &'a mut W) -> Self {
         Self {
             io: IO::Custom(writer),
+            alternate_screen: false,
         }
     }
 
@@ -91,6 +94,9 @@ impl<'a> TermionTerminal<'a> {
         if attributes.contains(Attributes::ITALIC) {
             write!(self.get_writer(), "{}", termion::style::Italic)?;
         }
+        if attributes.contains(Attributes::UNDERLINE) {
+            write!(self.get_writer(), "{}", termion::style::Underline)?;
+        }
 
         Ok(())
     }
@@ -202,10 +208,29 @@ impl<'a> Terminal for TermionTerminal<'a> {
     fn cursor_show(&mut self) -> Result<()> {
         write!(self.get_writer(), "{}", cursor::Show)
     }
+
+    fn enter_alternate_screen(&mut self) -> Result<()> {
+        write!(
+            self.get_writer(),
+            "{}{}",
+            screen::ToAlternateScreen,
+            cursor::Goto(1, 1)
+        )?;
+        self.alternate_screen = true;
+        self.flush()
+    }
+
+    fn enable_focus_reporting(&mut self) -> Result<()> {
+        // termion's key reader does not parse focus events.
+        Ok(())
+    }
 }
 
 impl<'a> Drop for TermionTerminal<'a> {
     fn drop(&mut self) {
+        if self.alternate_screen {
+            let _unused = write!(self.get_writer(), "{}", screen::ToMainScreen);
+        }
         let _unused = self.flush();
     }
 }
@@ -254,6 +279,7 @@ impl From<Key> for crate::ui::Key {
             Key::Esc => Self::Escape,
             Key::Char('\n' | '\r') => Self::Enter,
             Key::Char('\t') => Self::Tab,
+            Key::BackTab => Self::BackTab,
             Key::Backspace => Self::Backspace,
             Key::Delete => Self::Delete(KeyModifiers::empty()),
             Key::Home => Self::Home,
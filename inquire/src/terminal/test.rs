@@ -6,6 +6,7 @@ use super::{Terminal, TerminalSize};
 
 pub struct MockTerminal {
     pub size: TerminalSize,
+    #[allow(unused)]
     pub input: VecDeque<Key>,
     pub output: VecDeque<MockTerminalToken>,
 }
@@ -22,6 +23,8 @@ pub enum MockTerminalToken {
     CursorLeft(u16),
     CursorRight(u16),
     CursorMoveToColumn(u16),
+    EnterAlternateScreen,
+    EnableFocusReporting,
 }
 
 impl<T> From<T> for MockTerminalToken
@@ -101,6 +104,18 @@ impl Terminal for MockTerminal {
         Ok(())
     }
 
+    fn enter_alternate_screen(&mut self) -> std::io::Result<()> {
+        let token = MockTerminalToken::EnterAlternateScreen;
+        self.output.push_back(token);
+        Ok(())
+    }
+
+    fn enable_focus_reporting(&mut self) -> std::io::Result<()> {
+        let token = MockTerminalToken::EnableFocusReporting;
+        self.output.push_back(token);
+        Ok(())
+    }
+
     fn cursor_up(&mut self, cnt: u16) -> std::io::Result<()> {
         let token = MockTerminalToken::CursorUp(cnt);
         self.output.push_back(token);
@@ -1,6 +1,11 @@
 //! General type aliases.
 
-use crate::error::CustomUserError;
+use std::{rc::Rc, sync::mpsc::Receiver};
+
+use crate::{
+    error::CustomUserError, list_option::ListOption, ui::Key, FooterContext, InquireError, Page,
+    RenderContext, StatusKind,
+};
 
 /// Type alias to represent the function used to Score and filter options.
 ///
@@ -73,6 +78,14 @@ use crate::error::CustomUserError;
 /// ```
 pub type Scorer<'a, T> = &'a dyn Fn(&str, &T, &str, usize) -> Option<i64>;
 
+/// Type alias to represent the scorer registered with
+/// [set_global_scorer](crate::set_global_scorer).
+///
+/// It is a [Scorer] without the typed option, receiving the user input, the
+/// string value of the current option and its index, so that it applies to
+/// prompts of any option type.
+pub type GlobalScorer = fn(&str, &str, usize) -> Option<i64>;
+
 /// Type alias to represent the function used to retrieve text input suggestions.
 /// The function receives the current input and should return a collection of strings
 /// containing the suggestions to be made to the user.
@@ -82,3 +95,234 @@ pub type Suggester<'a> = &'a dyn Fn(&str) -> Result<Vec<String>, CustomUserError
 /// The function receives the current input and should return the suggestion (if any)
 /// that will replace the current input.
 pub type Completer<'a> = &'a dyn Fn(&str) -> Result<Option<String>, CustomUserError>;
+
+/// Type alias to represent the function used to render additional content in
+/// [Select](crate::Select) prompts.
+///
+/// The function is called on every frame with the current state of the prompt,
+/// and the returned lines are rendered right after the list of options.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::RenderHook;
+///
+/// let footer: RenderHook<&str> = &|ctx| {
+///     vec![format!("{} matching options", ctx.filtered_options.len())]
+/// };
+/// ```
+pub type RenderHook<'a, T> = &'a dyn Fn(&RenderContext<'_, T>) -> Vec<String>;
+
+/// Type alias to represent the function used to render the footer line of
+/// [Select](crate::Select) prompts, below the help message.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::FooterRenderer;
+///
+/// let footer: FooterRenderer = &|ctx| {
+///     format!(
+///         "{} options • {} hidden by filter • cursor {}",
+///         ctx.total_options, ctx.hidden_options, ctx.cursor_index
+///     )
+/// };
+/// ```
+pub type FooterRenderer<'a> = &'a dyn Fn(&FooterContext) -> String;
+
+/// Type alias to represent the function used to render pagination indicators
+/// in [Select](crate::Select) prompts.
+///
+/// The function is called on every frame with the page of options displayed
+/// to the user, and the returned lines are rendered right after the options.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::PaginationRenderer;
+///
+/// let scrollbar: PaginationRenderer<&str> = &|page| match (page.first, page.last) {
+///     (true, true) => vec![],
+///     (_, false) => vec![format!("more below ({} total)", page.total)],
+///     (false, true) => vec![String::from("end of list")],
+/// };
+/// ```
+pub type PaginationRenderer<'a, T> = &'a dyn Fn(&Page<'_, ListOption<&T>>) -> Vec<String>;
+
+/// Type alias to represent the function called when a new option is highlighted
+/// in [Select](crate::Select) prompts.
+///
+/// The function receives the highlighted option along with its index in the
+/// original list of options.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::HighlightHook;
+///
+/// let preview: HighlightHook<&str> = &|option| {
+///     eprintln!("previewing {}", option.value);
+/// };
+/// ```
+pub type HighlightHook<'a, T> = &'a dyn Fn(ListOption<&T>);
+
+/// Type alias to represent the function describing the highlighted option of
+/// [Select](crate::Select) prompts.
+///
+/// The function receives the highlighted option along with its index in the
+/// original list of options, and is only called once per option, when it is
+/// first highlighted.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::DescriptionProvider;
+///
+/// let describe: DescriptionProvider<&str> = &|option| format!("{} bytes", option.value.len());
+/// ```
+pub type DescriptionProvider<'a, T> = &'a dyn Fn(ListOption<&T>) -> String;
+
+/// Type alias to represent the function transforming the keys pressed in
+/// [Select](crate::Select) prompts before they are handled.
+///
+/// The function receives the pressed key and returns the key to handle in its
+/// place, or `None` to drop it.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{type_aliases::KeyFilter, ui::{Key, KeyModifiers}};
+///
+/// let filter: KeyFilter = &|key| match key {
+///     Key::Tab => Some(Key::Down(KeyModifiers::NONE)),
+///     Key::FocusLost => None,
+///     key => Some(key),
+/// };
+/// assert_eq!(Some(Key::Down(KeyModifiers::NONE)), filter(Key::Tab));
+/// assert_eq!(None, filter(Key::FocusLost));
+/// ```
+pub type KeyFilter<'a> = &'a dyn Fn(Key) -> Option<Key>;
+
+/// Type alias to represent the function called when [Select](crate::Select)
+/// prompts become interactive, right before they are first rendered.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::StartHook;
+///
+/// let log: StartHook = &|| eprintln!("prompt opened");
+/// ```
+pub type StartHook<'a> = &'a dyn Fn();
+
+/// Type alias to represent the function called when [Select](crate::Select)
+/// prompts are finished, whether they were submitted, canceled or failed.
+///
+/// The function receives the selected option along with its index in the
+/// original list of options, or `None` when the answer is not a single
+/// option, such as a created answer or the picks of a sequential selection.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::FinishHook;
+///
+/// let log: FinishHook<&str> = &|result| match result {
+///     Ok(Some(option)) => eprintln!("selected {}", option.value),
+///     Ok(None) => eprintln!("answered"),
+///     Err(err) => eprintln!("prompt failed: {err}"),
+/// };
+/// ```
+pub type FinishHook<'a, T> = &'a dyn Fn(Result<Option<ListOption<&T>>, &InquireError>);
+
+/// Type alias to represent the function used to refresh the options of
+/// [Select](crate::Select) prompts while they are displayed.
+///
+/// The function is called periodically and returns the new list of options.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::Refresher;
+///
+/// let refresher: Refresher<String> = &|| {
+///     std::env::vars().map(|(key, _)| key).collect()
+/// };
+/// ```
+pub type Refresher<'a, T> = &'a dyn Fn() -> Vec<T>;
+
+/// Type alias to represent the function starting to load the options of a
+/// prompt in the background.
+///
+/// The function is called once when the prompt starts, and returns the
+/// receiving end of a channel on which the options are sent once loaded.
+/// [Select::with_loading_source](crate::Select::with_loading_source) builds
+/// one that runs a closure on a worker thread.
+///
+/// # Examples
+///
+/// ```
+/// use std::{rc::Rc, sync::mpsc, thread};
+/// use inquire::type_aliases::LoadingSource;
+///
+/// let loading_source: LoadingSource<String> = Rc::new(|| {
+///     let (sender, receiver) = mpsc::channel();
+///     thread::spawn(move || sender.send(vec![String::from("loaded")]));
+///     receiver
+/// });
+/// ```
+pub type LoadingSource<'a, T> = Rc<dyn Fn() -> Receiver<Vec<T>> + 'a>;
+
+/// Type alias to represent the predicate deciding whether a filter input
+/// displays all options, without scoring them.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::ShowAllPredicate;
+///
+/// let show_all: ShowAllPredicate = &|input| input.trim().is_empty() || input == "*";
+/// assert!(show_all("  "));
+/// assert!(show_all("*"));
+/// assert!(!show_all("sa"));
+/// ```
+pub type ShowAllPredicate<'a> = &'a dyn Fn(&str) -> bool;
+
+/// Type alias to represent the function providing the icon rendered before
+/// an option in [Select](crate::Select) prompts.
+///
+/// The function receives the index of the option in the original list and
+/// its string value, and returns the icon of the option, if any.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::IconProvider;
+///
+/// let icons: IconProvider = &|_index, value| value.ends_with(".rs").then(|| String::from("🦀"));
+/// assert_eq!(Some(String::from("🦀")), icons(0, "main.rs"));
+/// assert_eq!(None, icons(1, "README.md"));
+/// ```
+pub type IconProvider<'a> = &'a dyn Fn(usize, &str) -> Option<String>;
+
+/// Type alias to represent the function providing the status rendered before
+/// an option in [Select](crate::Select) prompts.
+///
+/// The function receives the index of the option in the original list and
+/// returns its current status.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::StatusProvider;
+/// use inquire::StatusKind;
+///
+/// let running = [true, false];
+/// let status: StatusProvider = &|index| match running.get(index) {
+///     Some(true) => StatusKind::Success,
+///     Some(false) => StatusKind::Failure,
+///     None => StatusKind::Unknown,
+/// };
+/// assert_eq!(StatusKind::Failure, status(1));
+/// ```
+pub type StatusProvider<'a> = &'a dyn Fn(usize) -> StatusKind;
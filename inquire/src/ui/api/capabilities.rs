@@ -0,0 +1,79 @@
+use std::{
+    env,
+    io::{stderr, stdin, IsTerminal},
+};
+
+use crate::{terminal::get_default_terminal_size, ui::dimension::Dimension};
+
+/// Capabilities of the terminal prompts are rendered to.
+///
+/// Detecting them does not take control of the terminal, so they can be
+/// inspected before any prompt is created, e.g. to pick a page size that
+/// fits the terminal or to fall back to plain, non-interactive input.
+///
+/// # Example
+///
+/// ```no_run
+/// use inquire::ui::TerminalCapabilities;
+///
+/// let capabilities = TerminalCapabilities::detect();
+///
+/// if !capabilities.is_tty() {
+///     eprintln!("Not running in a terminal, reading answers from stdin");
+/// }
+///
+/// let page_size = capabilities
+///     .height()
+///     .map(|height| usize::from(height).saturating_sub(3))
+///     .unwrap_or(7);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TerminalCapabilities {
+    is_tty: bool,
+    supports_color: bool,
+    size: Option<Dimension>,
+}
+
+impl TerminalCapabilities {
+    /// Detects the capabilities of the current terminal.
+    pub fn detect() -> Self {
+        let is_tty = stdin().is_terminal() && stderr().is_terminal();
+
+        let supports_color = is_tty
+            && env::var_os("NO_COLOR").is_none()
+            && env::var("TERM").map(|term| term != "dumb").unwrap_or(true);
+
+        Self {
+            is_tty,
+            supports_color,
+            size: get_default_terminal_size().ok(),
+        }
+    }
+
+    /// Whether both stdin and stderr, from which prompts read keys and to
+    /// which they are rendered, are attached to a terminal.
+    ///
+    /// When this is false, prompts are likely to fail with
+    /// [InquireError::NotTTY](crate::InquireError::NotTTY).
+    pub fn is_tty(&self) -> bool {
+        self.is_tty
+    }
+
+    /// Whether colored output is expected to be displayed.
+    ///
+    /// This is false when the output is not a terminal, when the `NO_COLOR`
+    /// environment variable is set or when `TERM` is set to `dumb`.
+    pub fn supports_color(&self) -> bool {
+        self.supports_color
+    }
+
+    /// Width of the terminal in columns, if it could be determined.
+    pub fn width(&self) -> Option<u16> {
+        self.size.map(|size| size.width())
+    }
+
+    /// Height of the terminal in rows, if it could be determined.
+    pub fn height(&self) -> Option<u16> {
+        self.size.map(|size| size.height())
+    }
+}
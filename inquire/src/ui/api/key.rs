@@ -1,45 +1,133 @@
+use std::fmt::{self, Display};
+
 use bitflags::bitflags;
 
 // Using the same struct, but without importing, to cut prompts' direct dependencies to crossterm
 // https://github.com/crossterm-rs/crossterm/blob/e1260446e94e9a8f7809fef61dc1369b6f8d6e12/src/event.rs#L376-L385
 bitflags! {
+    /// Modifier keys held down along with a key press.
     #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct KeyModifiers: u8 {
+        /// Shift key.
         const SHIFT = 0b0000_0001;
+        /// Control key.
         const CONTROL = 0b0000_0010;
+        /// Alt key.
         const ALT = 0b0000_0100;
+        /// Super key.
         const SUPER = 0b0000_1000;
+        /// Hyper key.
         const HYPER = 0b0001_0000;
+        /// Meta key.
         const META = 0b0010_0000;
+        /// No modifier key.
         const NONE = 0b0000_0000;
     }
 }
 
+/// Key pressed by the user, as read by the prompts.
+///
+/// New keys may be added in minor releases, so matches on this type must
+/// include a wildcard arm.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum Key {
+    /// Escape key.
     Escape,
+    /// Enter key.
     Enter,
+    /// Backspace key.
     Backspace,
+    /// Tab key.
     Tab,
+    /// Tab key pressed along with Shift.
+    BackTab,
+    /// Delete key.
     Delete(KeyModifiers),
+    /// Home key.
     Home,
+    /// End key.
     End,
+    /// Page up key.
     PageUp(KeyModifiers),
+    /// Page down key.
     PageDown(KeyModifiers),
+    /// Up arrow key.
     Up(KeyModifiers),
+    /// Down arrow key.
     Down(KeyModifiers),
+    /// Left arrow key.
     Left(KeyModifiers),
+    /// Right arrow key.
     Right(KeyModifiers),
+    /// Character key.
     Char(char, KeyModifiers),
+    /// The terminal window lost focus. Only reported after focus reporting is
+    /// enabled, and only by backends that support it.
+    FocusLost,
+    /// Any other key.
     #[deprecated(note = "If the key you want isn't mapped, please open a PR.")]
     Any,
 }
 
+impl Display for Key {
+    /// Formats the key as a short human-readable label, such as `Ctrl+G` or
+    /// `Shift+Tab`, e.g. to list the key bindings of a prompt.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (name, modifiers) = match self {
+            Self::Escape => ("Esc", KeyModifiers::NONE),
+            Self::Enter => ("Enter", KeyModifiers::NONE),
+            Self::Backspace => ("Backspace", KeyModifiers::NONE),
+            Self::Tab => ("Tab", KeyModifiers::NONE),
+            Self::BackTab => ("Tab", KeyModifiers::SHIFT),
+            Self::Delete(modifiers) => ("Delete", *modifiers),
+            Self::Home => ("Home", KeyModifiers::NONE),
+            Self::End => ("End", KeyModifiers::NONE),
+            Self::PageUp(modifiers) => ("PageUp", *modifiers),
+            Self::PageDown(modifiers) => ("PageDown", *modifiers),
+            Self::Up(modifiers) => ("Up", *modifiers),
+            Self::Down(modifiers) => ("Down", *modifiers),
+            Self::Left(modifiers) => ("Left", *modifiers),
+            Self::Right(modifiers) => ("Right", *modifiers),
+            Self::Char(' ', modifiers) => ("Space", *modifiers),
+            Self::Char(_, modifiers) => ("", *modifiers),
+            Self::FocusLost => ("Focus lost", KeyModifiers::NONE),
+            #[allow(deprecated)]
+            Self::Any => ("Any", KeyModifiers::NONE),
+        };
+
+        for (modifier, label) in [
+            (KeyModifiers::CONTROL, "Ctrl+"),
+            (KeyModifiers::ALT, "Alt+"),
+            (KeyModifiers::SHIFT, "Shift+"),
+            (KeyModifiers::SUPER, "Super+"),
+            (KeyModifiers::HYPER, "Hyper+"),
+            (KeyModifiers::META, "Meta+"),
+        ] {
+            if modifiers.contains(modifier) {
+                f.write_str(label)?;
+            }
+        }
+
+        match self {
+            Self::Char(' ', _) => f.write_str(name),
+            Self::Char(c, modifiers) if modifiers.contains(KeyModifiers::CONTROL) => {
+                write!(f, "{}", c.to_ascii_uppercase())
+            }
+            Self::Char(c, _) => write!(f, "{c}"),
+            _ => f.write_str(name),
+        }
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod key_test {
     use super::{Key, KeyModifiers};
 
     impl Key {
+        /// Keys typing each character of the provided string.
         pub fn char_keys_from_str(s: &str) -> Vec<Self> {
             s.chars()
                 .map(|c| Key::Char(c, KeyModifiers::NONE))
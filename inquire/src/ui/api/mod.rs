@@ -1,9 +1,11 @@
+mod capabilities;
 mod color;
 mod key;
 mod render_config;
 mod style;
 
+pub use capabilities::*;
 pub use color::*;
-pub(crate) use key::*;
+pub use key::*;
 pub use render_config::*;
 pub use style::*;
@@ -1,6 +1,8 @@
 use std::env;
 
-use super::{Color, StyleSheet, Styled};
+use crate::StatusKind;
+
+use super::{Attributes, Color, StyleSheet, Styled};
 
 /// Rendering configuration that can be applied to a prompt.
 ///
@@ -36,6 +38,10 @@ pub struct RenderConfig<'a> {
     /// Style of the prompt message, applicable to all prompt types.
     pub prompt: StyleSheet,
 
+    /// Style of the title rendered above the prompt message, e.g. the step of
+    /// a multi-step flow.
+    pub title: StyleSheet,
+
     /// Render configuration of default values.
     ///
     /// Note: default values are displayed wrapped in parenthesis, e.g. (yes).
@@ -55,6 +61,14 @@ pub struct RenderConfig<'a> {
     /// Note: help messages are displayed wrapped in brackets, e.g. [Be careful!].
     pub help_message: StyleSheet,
 
+    /// Style of the footer line rendered below the help message, e.g. the
+    /// number of options hidden by the filter.
+    pub footer: StyleSheet,
+
+    /// Style of the description of the highlighted option, rendered below
+    /// the list of options.
+    pub option_description: StyleSheet,
+
     /// Character used to mask password text inputs when in mode
     /// [`Masked`](crate::prompts::PasswordDisplayMode).
     ///
@@ -68,6 +82,11 @@ pub struct RenderConfig<'a> {
     /// a separator from the prompt message (or default value display).
     pub text_input: StyleSheet,
 
+    /// Style sheet for the tokens of the filter syntax, such as exclusion
+    /// operators and quoted phrases, when highlighted in the filter input of
+    /// [Select](crate::Select) prompts.
+    pub filter_syntax: StyleSheet,
+
     /// Render configuration of final prompt answers (submissions).
     ///
     /// Note: a non-styled space character is added before the answer as
@@ -84,6 +103,10 @@ pub struct RenderConfig<'a> {
     /// Render configuration for error messages.
     pub error_message: ErrorMessageRenderConfig<'a>,
 
+    /// Render configuration of the status indicators rendered before the
+    /// options of a [Select](crate::Select) prompt with a status provider.
+    pub option_status: StatusRenderConfig<'a>,
+
     /// Prefix for the current highlighted option.
     ///
     /// Note: a space character will be added to separate the prefix
@@ -121,6 +144,11 @@ pub struct RenderConfig<'a> {
     /// Definition of index prefixes in option lists.
     pub option_index_prefix: IndexPrefix,
 
+    /// Whether text inputs longer than the terminal width scroll horizontally,
+    /// keeping the cursor visible and showing an ellipsis on the clipped
+    /// sides, instead of wrapping to the next lines. Enabled by default.
+    pub input_scroll: bool,
+
     /// Style sheet for options.
     ///
     /// Note: a non-styled space character is added before the option value as
@@ -134,6 +162,55 @@ pub struct RenderConfig<'a> {
     /// a separator from the prefix.
     pub selected_option: Option<StyleSheet>,
 
+    /// Indicator appended to the recommended option of a list.
+    ///
+    /// Note: a non-styled space character is added before the indicator as
+    /// a separator from the option value.
+    pub recommended_option_indicator: Styled<&'a str>,
+
+    /// Indicator appended to the options marked with Space in a
+    /// [Select](crate::Select) prompt with marking enabled.
+    ///
+    /// Note: a non-styled space character is added before the indicator as
+    /// a separator from the option value.
+    pub marked_option_indicator: Styled<&'a str>,
+
+    /// Indicator prepended to the non-empty filter input of a
+    /// [Select](crate::Select) prompt with the search indicator enabled, to
+    /// distinguish searching from browsing.
+    pub search_indicator: Styled<&'a str>,
+
+    /// Indicator repeated for each completed step of the progress rendered
+    /// above a [Select](crate::Select) prompt, the current step included.
+    pub progress_completed_indicator: Styled<&'a str>,
+
+    /// Indicator repeated for each remaining step of the progress rendered
+    /// above a [Select](crate::Select) prompt.
+    pub progress_remaining_indicator: Styled<&'a str>,
+
+    /// Character of the scrollbar rendered at the right edge of the options
+    /// of a [Select](crate::Select) prompt, for the rows covered by its thumb.
+    pub scrollbar_thumb: Styled<&'a str>,
+
+    /// Character of the scrollbar rendered at the right edge of the options
+    /// of a [Select](crate::Select) prompt, for the other rows.
+    pub scrollbar_track: Styled<&'a str>,
+
+    /// Style sheet of the annotations displayed next to options, aligned in
+    /// a column.
+    pub option_annotation: StyleSheet,
+
+    /// Style sheet of the border framing the options of a
+    /// [Select](crate::Select) prompt with a border enabled.
+    pub border: StyleSheet,
+
+    /// Indicator displayed below the prompt while the validators of a
+    /// submission are running, useful when validating is slow enough to be
+    /// noticed.
+    ///
+    /// When None, no frame is rendered before running the validators.
+    pub validating_indicator: Option<Styled<&'a str>>,
+
     /// Render configuration for calendar
 
     #[cfg(feature = "date")]
@@ -155,11 +232,16 @@ impl<'a> RenderConfig<'a> {
             prompt_prefix: Styled::new("?"),
             answered_prompt_prefix: Styled::new("?"),
             prompt: StyleSheet::empty(),
+            title: StyleSheet::empty(),
             default_value: StyleSheet::empty(),
             placeholder: StyleSheet::empty(),
             help_message: StyleSheet::empty(),
+            footer: StyleSheet::empty(),
+            option_description: StyleSheet::empty(),
             text_input: StyleSheet::empty(),
+            filter_syntax: StyleSheet::empty(),
             error_message: ErrorMessageRenderConfig::empty(),
+            option_status: StatusRenderConfig::empty(),
             answer: StyleSheet::empty(),
             canceled_prompt_indicator: Styled::new("<canceled>"),
             password_mask: '*',
@@ -169,8 +251,19 @@ impl<'a> RenderConfig<'a> {
             selected_checkbox: Styled::new("[x]"),
             unselected_checkbox: Styled::new("[ ]"),
             option_index_prefix: IndexPrefix::None,
+            input_scroll: true,
             option: StyleSheet::empty(),
             selected_option: None,
+            recommended_option_indicator: Styled::new("(recommended)"),
+            marked_option_indicator: Styled::new("*"),
+            search_indicator: Styled::new("/"),
+            progress_completed_indicator: Styled::new("●"),
+            progress_remaining_indicator: Styled::new("○"),
+            scrollbar_thumb: Styled::new("█"),
+            scrollbar_track: Styled::new("│"),
+            option_annotation: StyleSheet::empty(),
+            border: StyleSheet::empty(),
+            validating_indicator: None,
 
             #[cfg(feature = "date")]
             calendar: calendar::CalendarRenderConfig::empty(),
@@ -186,11 +279,18 @@ impl<'a> RenderConfig<'a> {
             prompt_prefix: Styled::new("?").with_fg(Color::LightGreen),
             answered_prompt_prefix: Styled::new(">").with_fg(Color::LightGreen),
             prompt: StyleSheet::empty(),
+            title: StyleSheet::new()
+                .with_fg(Color::LightCyan)
+                .with_attr(Attributes::BOLD),
             default_value: StyleSheet::empty(),
             placeholder: StyleSheet::new().with_fg(Color::DarkGrey),
             help_message: StyleSheet::empty().with_fg(Color::LightCyan),
+            footer: StyleSheet::new().with_fg(Color::DarkGrey),
+            option_description: StyleSheet::new().with_fg(Color::DarkGrey),
             text_input: StyleSheet::empty(),
+            filter_syntax: StyleSheet::new().with_fg(Color::LightMagenta),
             error_message: ErrorMessageRenderConfig::default_colored(),
+            option_status: StatusRenderConfig::default_colored(),
             password_mask: '*',
             answer: StyleSheet::empty().with_fg(Color::LightCyan),
             canceled_prompt_indicator: Styled::new("<canceled>").with_fg(Color::DarkRed),
@@ -200,8 +300,19 @@ impl<'a> RenderConfig<'a> {
             selected_checkbox: Styled::new("[x]").with_fg(Color::LightGreen),
             unselected_checkbox: Styled::new("[ ]"),
             option_index_prefix: IndexPrefix::None,
+            input_scroll: true,
             option: StyleSheet::empty(),
             selected_option: Some(StyleSheet::new().with_fg(Color::LightCyan)),
+            recommended_option_indicator: Styled::new("(recommended)").with_fg(Color::DarkGrey),
+            marked_option_indicator: Styled::new("*").with_fg(Color::LightYellow),
+            search_indicator: Styled::new("/").with_fg(Color::LightCyan),
+            progress_completed_indicator: Styled::new("●").with_fg(Color::LightGreen),
+            progress_remaining_indicator: Styled::new("○").with_fg(Color::DarkGrey),
+            scrollbar_thumb: Styled::new("█").with_fg(Color::LightCyan),
+            scrollbar_track: Styled::new("│").with_fg(Color::DarkGrey),
+            option_annotation: StyleSheet::new().with_fg(Color::DarkGrey),
+            border: StyleSheet::new().with_fg(Color::DarkGrey),
+            validating_indicator: None,
 
             #[cfg(feature = "date")]
             calendar: calendar::CalendarRenderConfig::default_colored(),
@@ -229,18 +340,42 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets the style sheet for the tokens of the filter syntax.
+    pub fn with_filter_syntax(mut self, filter_syntax: StyleSheet) -> Self {
+        self.filter_syntax = filter_syntax;
+        self
+    }
+
     /// Sets the style sheet for default values.
     pub fn with_default_value(mut self, default_value: StyleSheet) -> Self {
         self.default_value = default_value;
         self
     }
 
+    /// Sets the style sheet for titles rendered above the prompt message.
+    pub fn with_title(mut self, title: StyleSheet) -> Self {
+        self.title = title;
+        self
+    }
+
     /// Sets the style sheet for help messages.
     pub fn with_help_message(mut self, help_message: StyleSheet) -> Self {
         self.help_message = help_message;
         self
     }
 
+    /// Sets the style sheet for footers rendered below the help message.
+    pub fn with_footer(mut self, footer: StyleSheet) -> Self {
+        self.footer = footer;
+        self
+    }
+
+    /// Sets the style sheet for the description of the highlighted option.
+    pub fn with_option_description(mut self, option_description: StyleSheet) -> Self {
+        self.option_description = option_description;
+        self
+    }
+
     /// Sets the style sheet for answers.
     pub fn with_answer(mut self, answer: StyleSheet) -> Self {
         self.answer = answer;
@@ -253,6 +388,12 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets the render configuration of the status indicators of options.
+    pub fn with_option_status(mut self, option_status: StatusRenderConfig<'a>) -> Self {
+        self.option_status = option_status;
+        self
+    }
+
     /// Sets the styled component for prefixes in highlighted options.
     pub fn with_highlighted_option_prefix(
         mut self,
@@ -292,6 +433,13 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets whether text inputs longer than the terminal width scroll
+    /// horizontally instead of wrapping.
+    pub fn with_input_scroll(mut self, input_scroll: bool) -> Self {
+        self.input_scroll = input_scroll;
+        self
+    }
+
     /// Sets the style sheet for option values.
     pub fn with_option(mut self, option: StyleSheet) -> Self {
         self.option = option;
@@ -304,6 +452,73 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets the indicator appended to the recommended option of a list.
+    pub fn with_recommended_option_indicator(
+        mut self,
+        recommended_option_indicator: Styled<&'a str>,
+    ) -> Self {
+        self.recommended_option_indicator = recommended_option_indicator;
+        self
+    }
+
+    /// Sets the indicator appended to the marked options of a list.
+    pub fn with_marked_option_indicator(
+        mut self,
+        marked_option_indicator: Styled<&'a str>,
+    ) -> Self {
+        self.marked_option_indicator = marked_option_indicator;
+        self
+    }
+
+    /// Sets the indicator prepended to the non-empty filter input of a list,
+    /// such as a magnifier glyph.
+    pub fn with_search_indicator(mut self, search_indicator: Styled<&'a str>) -> Self {
+        self.search_indicator = search_indicator;
+        self
+    }
+
+    /// Sets the indicators of the completed and remaining steps of the
+    /// progress rendered above a prompt.
+    pub fn with_progress_indicators(
+        mut self,
+        completed: Styled<&'a str>,
+        remaining: Styled<&'a str>,
+    ) -> Self {
+        self.progress_completed_indicator = completed;
+        self.progress_remaining_indicator = remaining;
+        self
+    }
+
+    /// Sets the characters of the thumb and the track of the scrollbar
+    /// rendered next to the options of a prompt.
+    pub fn with_scrollbar(mut self, thumb: Styled<&'a str>, track: Styled<&'a str>) -> Self {
+        self.scrollbar_thumb = thumb;
+        self.scrollbar_track = track;
+        self
+    }
+
+    /// Sets the style sheet of the border framing the options of a prompt.
+    pub fn with_border(mut self, border: StyleSheet) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Sets the indicator displayed while the validators of a submission are
+    /// running, such as `Styled::new("checking…")`.
+    pub fn with_validating_indicator(
+        mut self,
+        validating_indicator: Option<Styled<&'a str>>,
+    ) -> Self {
+        self.validating_indicator = validating_indicator;
+        self
+    }
+
+    /// Sets the style sheet of the annotations displayed next to options.
+    pub fn with_option_annotation(mut self, option_annotation: StyleSheet) -> Self {
+        self.option_annotation = option_annotation;
+        self
+    }
+
     /// Sets the indicator for canceled prompts.
     pub fn with_canceled_prompt_indicator(
         mut self,
@@ -435,6 +650,72 @@ impl<'a> ErrorMessageRenderConfig<'a> {
     }
 }
 
+/// Render configuration of the indicators of each [StatusKind].
+#[derive(Copy, Clone, Debug)]
+pub struct StatusRenderConfig<'a> {
+    /// Indicator of [StatusKind::Success].
+    pub success: Styled<&'a str>,
+
+    /// Indicator of [StatusKind::Warning].
+    pub warning: Styled<&'a str>,
+
+    /// Indicator of [StatusKind::Failure].
+    pub failure: Styled<&'a str>,
+
+    /// Indicator of [StatusKind::Pending].
+    pub pending: Styled<&'a str>,
+
+    /// Indicator of [StatusKind::Unknown].
+    pub unknown: Styled<&'a str>,
+}
+
+impl<'a> StatusRenderConfig<'a> {
+    /// Render configuration in which no colors or attributes are applied.
+    pub fn empty() -> Self {
+        Self {
+            success: Styled::new("✔"),
+            warning: Styled::new("!"),
+            failure: Styled::new("✘"),
+            pending: Styled::new("…"),
+            unknown: Styled::new("?"),
+        }
+    }
+
+    /// Render configuration where default colors and attributes are applied.
+    pub fn default_colored() -> Self {
+        Self {
+            success: Styled::new("✔").with_fg(Color::LightGreen),
+            warning: Styled::new("!").with_fg(Color::LightYellow),
+            failure: Styled::new("✘").with_fg(Color::LightRed),
+            pending: Styled::new("…").with_fg(Color::LightCyan),
+            unknown: Styled::new("?").with_fg(Color::DarkGrey),
+        }
+    }
+
+    /// Sets the indicator of the provided status, e.g. an emoji.
+    pub fn with_indicator(mut self, status: StatusKind, indicator: Styled<&'a str>) -> Self {
+        match status {
+            StatusKind::Success => self.success = indicator,
+            StatusKind::Warning => self.warning = indicator,
+            StatusKind::Failure => self.failure = indicator,
+            StatusKind::Pending => self.pending = indicator,
+            StatusKind::Unknown => self.unknown = indicator,
+        }
+        self
+    }
+
+    /// Indicator of the provided status.
+    pub fn indicator(&self, status: StatusKind) -> Styled<&'a str> {
+        match status {
+            StatusKind::Success => self.success,
+            StatusKind::Warning => self.warning,
+            StatusKind::Failure => self.failure,
+            StatusKind::Pending => self.pending,
+            StatusKind::Unknown => self.unknown,
+        }
+    }
+}
+
 #[cfg(feature = "date")]
 pub mod calendar {
     //! Module containing additional render config for date prompts.
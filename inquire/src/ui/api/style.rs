@@ -28,6 +28,9 @@ bitflags! {
 
         /// Emphasises the text.
         const ITALIC = 0b10;
+
+        /// Underlines the text.
+        const UNDERLINE = 0b100;
     }
 }
 
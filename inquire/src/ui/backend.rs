@@ -1,19 +1,27 @@
-use std::{collections::BTreeSet, fmt::Display, io::Result};
+use std::{collections::BTreeSet, fmt::Display, io::Result, time::Duration};
 
-use unicode_width::UnicodeWidthStr;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 use crate::{
+    ansi::{AnsiAware, AnsiAwareChar, AnsiStrippable},
     error::InquireResult,
     input::Input,
     list_option::ListOption,
     terminal::Terminal,
-    ui::{IndexPrefix, Key, RenderConfig, Styled},
-    utils::{int_log10, Page},
+    ui::{Attributes, IndexPrefix, Key, RenderConfig, StyleSheet, Styled},
+    utils::{int_log10, wrap_words, Page},
     validator::ErrorMessage,
+    BorderStyle, StatusKind,
 };
 
 use super::{frame_renderer::FrameRenderer, InputReader};
 
+/// Escape sequence resetting all colors and attributes.
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// Character replacing the clipped portions of text too wide for the line.
+const ELLIPSIS: char = '…';
+
 pub trait CommonBackend: InputReader {
     fn frame_setup(&mut self) -> Result<()>;
     fn frame_finish(&mut self, is_last_frame: bool) -> Result<()>;
@@ -23,6 +31,15 @@ pub trait CommonBackend: InputReader {
 
     fn render_error_message(&mut self, error: &ErrorMessage) -> Result<()>;
     fn render_help_message(&mut self, help: &str) -> Result<()>;
+    fn render_validating_indicator(&mut self) -> Result<()>;
+
+    /// Whether a frame with the validating indicator is rendered before
+    /// running the validators of a submission.
+    fn has_validating_indicator(&self) -> bool;
+
+    /// Width and height of the terminal the current frame is rendered to,
+    /// if known.
+    fn terminal_size(&self) -> Option<(u16, u16)>;
 }
 
 pub trait TextBackend: CommonBackend {
@@ -40,9 +57,87 @@ pub trait EditorBackend: CommonBackend {
     fn render_prompt(&mut self, prompt: &str, editor_command: &str) -> Result<()>;
 }
 
+/// Rendering settings for the options of a [SelectBackend], on top of
+/// the ones defined by the render config.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OptionDecorations<'a> {
+    /// Whether the first character of each option is underlined, marking
+    /// it as the access key of the option.
+    pub underline_access_key: bool,
+
+    /// Index of the option flagged as recommended, in the original list of
+    /// options, if any.
+    pub recommended: Option<usize>,
+
+    /// Annotations displayed next to the options whose string value matches
+    /// the label, aligned in a column after the longest option of the page.
+    pub annotations: &'a [(&'a str, &'a str)],
+
+    /// Indexes of the options marked by the user, in the original list of
+    /// options, if marking is enabled.
+    pub marked: Option<&'a BTreeSet<usize>>,
+
+    /// Icons rendered before the options of the page, in the same order as
+    /// the page content, if icons are enabled.
+    pub icons: Option<&'a [Option<String>]>,
+
+    /// Statuses of the options of the page, in the same order as the page
+    /// content, if statuses are enabled.
+    pub statuses: Option<&'a [StatusKind]>,
+
+    /// Whether the options already contain ANSI escape sequences, in which
+    /// case the styles are reset after each option.
+    pub prestyled: bool,
+
+    /// Whether options spanning several lines are rendered as blocks, with
+    /// the following lines aligned below the first one.
+    pub multiline: bool,
+
+    /// First row and number of rows of the page covered by the thumb of the
+    /// scrollbar rendered at the right edge of the options, if the scrollbar
+    /// is displayed. Options spanning several lines cover several rows.
+    pub scrollbar: Option<(usize, usize)>,
+
+    /// Style of the border framing the options, whose sides are rendered on
+    /// each line of the options, if the border is displayed.
+    pub border: Option<BorderStyle>,
+}
+
 pub trait SelectBackend: CommonBackend {
-    fn render_select_prompt(&mut self, prompt: &str, cur_input: Option<&Input>) -> Result<()>;
-    fn render_options<D: Display>(&mut self, page: Page<'_, ListOption<D>>) -> Result<()>;
+    fn render_select_prompt(
+        &mut self,
+        prompt: &str,
+        cur_input: Option<&Input>,
+        highlight_syntax: bool,
+        search_indicator: bool,
+    ) -> Result<()>;
+    fn render_filter_input(
+        &mut self,
+        label: &str,
+        cur_input: &Input,
+        highlight_syntax: bool,
+        search_indicator: bool,
+    ) -> Result<()>;
+    fn render_horizontal_select_prompt<D: Display>(
+        &mut self,
+        prompt: &str,
+        option: Option<&ListOption<D>>,
+    ) -> Result<()>;
+    fn render_options<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        decorations: &OptionDecorations<'_>,
+    ) -> Result<()>;
+    fn render_extra_lines(&mut self, lines: &[String]) -> Result<()>;
+    fn render_separator(&mut self, separator: char) -> Result<()>;
+    fn render_title(&mut self, title: &str) -> Result<()>;
+    fn render_progress(&mut self, current: usize, total: usize) -> Result<()>;
+    fn render_footer(&mut self, footer: &str) -> Result<()>;
+    fn render_option_description(&mut self, description: &str) -> Result<()>;
+    fn render_border_top(&mut self, style: BorderStyle, title: Option<&str>) -> Result<()>;
+    fn render_border_bottom(&mut self, style: BorderStyle) -> Result<()>;
+    fn render_clipboard_copy(&mut self, copied: bool) -> Result<()>;
+    fn render_submit_confirmation(&mut self, message: &str) -> Result<()>;
 }
 
 pub trait MultiSelectBackend: CommonBackend {
@@ -101,14 +196,160 @@ where
         Ok(backend)
     }
 
+    /// Sets the render config of the next prompts rendered by the backend.
+    #[allow(clippy::large_types_passed_by_value)]
+    pub fn set_render_config(&mut self, render_config: RenderConfig<'a>) {
+        self.render_config = render_config;
+    }
+
+    /// Leaves the prompt rendered last on the terminal, so that the next
+    /// prompt is rendered below it.
+    pub fn detach_frames(&mut self) -> Result<()> {
+        self.frame_renderer.detach()
+    }
+
+    /// Plain content of every frame rendered so far, without any styling.
+    #[cfg(test)]
+    pub fn rendered_frames(&self) -> &[String] {
+        self.frame_renderer.rendered_frames()
+    }
+
+    /// Pads the current line up to the last column of the terminal, minus
+    /// the provided margin, and writes the cell there, unless the line
+    /// already goes past it.
+    fn print_right_edge_cell(&mut self, margin: u16, cell: Styled<impl Display>) -> Result<()> {
+        let (Some(width), Some(line_width)) = (
+            self.frame_renderer.terminal_width(),
+            self.frame_renderer.current_line_width(),
+        ) else {
+            return Ok(());
+        };
+
+        let column = width.saturating_sub(1 + margin);
+        if line_width > column {
+            return Ok(());
+        }
+
+        self.frame_renderer
+            .write(" ".repeat(usize::from(column - line_width)))?;
+        self.frame_renderer.write_styled(cell)
+    }
+
+    /// Writes the scrollbar character of the provided row at the right edge
+    /// of the options, inside the border if there is one.
+    fn print_scrollbar_cell(
+        &mut self,
+        row: usize,
+        (start, len): (usize, usize),
+        border: bool,
+    ) -> Result<()> {
+        let cell = match (start..start + len).contains(&row) {
+            true => self.render_config.scrollbar_thumb,
+            false => self.render_config.scrollbar_track,
+        };
+
+        self.print_right_edge_cell(u16::from(border), cell)
+    }
+
+    fn border_styled(&self, border_char: char) -> Styled<char> {
+        Styled::new(border_char).with_style_sheet(self.render_config.border)
+    }
+
+    /// Prints the left side of the border and the space separating it from
+    /// the content of the line.
+    fn print_left_border(&mut self, style: BorderStyle) -> Result<()> {
+        let [.., vertical] = style.chars();
+
+        self.frame_renderer
+            .write_styled(self.border_styled(vertical))?;
+        self.frame_renderer.write(" ")
+    }
+
+    /// Prints the right side of the border at the last column of the
+    /// terminal, unless the line already goes past it.
+    fn print_right_border(&mut self, style: BorderStyle) -> Result<()> {
+        let [.., vertical] = style.chars();
+
+        self.print_right_edge_cell(0, self.border_styled(vertical))
+    }
+
+    /// Width left on the current line for text inside the border, keeping a
+    /// space before the right border, or before the scrollbar if any, along
+    /// with the provided width for what is written after the text.
+    fn width_inside_border(&self, trailing: usize, scrollbar: bool) -> Option<usize> {
+        let width = usize::from(self.frame_renderer.terminal_width()?);
+        let line_width = usize::from(self.frame_renderer.current_line_width()?);
+
+        Some(width.saturating_sub(line_width + trailing + 2 + usize::from(scrollbar)))
+    }
+
+    /// Prints a horizontal line of the border spanning the terminal width,
+    /// between the provided corners, with the title after the left corner if
+    /// any. The title is cut to the width left by the corners and lines.
+    fn print_border_line(
+        &mut self,
+        (left, right): (char, char),
+        horizontal: char,
+        title: Option<&str>,
+    ) -> Result<()> {
+        let Some(width) = self.frame_renderer.terminal_width() else {
+            return self.new_line();
+        };
+        let width = usize::from(width);
+
+        self.frame_renderer.write_styled(self.border_styled(left))?;
+        let mut used = 1;
+
+        // the title is framed as in `┌─ Title ─┐`, needing 5 more columns
+        if let Some(title) = title.filter(|_| width > 5) {
+            let mut title_width = 0;
+            let title = title
+                .chars()
+                .take_while(|c| {
+                    title_width += UnicodeWidthChar::width(*c).unwrap_or(0);
+                    title_width <= width - 5
+                })
+                .collect::<String>();
+
+            self.frame_renderer
+                .write_styled(self.border_styled(horizontal))?;
+            self.frame_renderer.write(" ")?;
+            used += 2 + title.width();
+            self.frame_renderer
+                .write_styled(Styled::new(title).with_style_sheet(self.render_config.prompt))?;
+            self.frame_renderer.write(" ")?;
+            used += 1;
+        }
+
+        let fill = width.saturating_sub(used + 1);
+        self.frame_renderer.write_styled(
+            Styled::new(horizontal.to_string().repeat(fill))
+                .with_style_sheet(self.render_config.border),
+        )?;
+        self.frame_renderer
+            .write_styled(self.border_styled(right))?;
+
+        self.new_line()
+    }
+
     fn print_option_prefix<D: Display>(
         &mut self,
         option_relative_index: usize,
         page: &Page<'_, ListOption<D>>,
     ) -> Result<()> {
+        let x = self.option_prefix(option_relative_index, page);
+
+        self.frame_renderer.write_styled(x)
+    }
+
+    fn option_prefix<D: Display>(
+        &self,
+        option_relative_index: usize,
+        page: &Page<'_, ListOption<D>>,
+    ) -> Styled<&'a str> {
         let empty_prefix = Styled::new(" ");
 
-        let x = if page.cursor == Some(option_relative_index) {
+        if page.cursor == Some(option_relative_index) {
             self.render_config.highlighted_option_prefix
         } else if option_relative_index == 0 && !page.first {
             self.render_config.scroll_up_prefix
@@ -116,34 +357,66 @@ where
             self.render_config.scroll_down_prefix
         } else {
             empty_prefix
-        };
-
-        self.frame_renderer.write_styled(x)
+        }
     }
 
-    fn print_option_value<D: Display>(
-        &mut self,
+    fn option_style_sheet<D: Display>(
+        &self,
         option_relative_index: usize,
-        option: &ListOption<D>,
         page: &Page<'_, ListOption<D>>,
-    ) -> Result<()> {
-        let stylesheet = if let Some(selected_option_style) = self.render_config.selected_option {
+    ) -> StyleSheet {
+        if let Some(selected_option_style) = self.render_config.selected_option {
             match page.cursor {
                 Some(cursor) if cursor == option_relative_index => selected_option_style,
                 _ => self.render_config.option,
             }
         } else {
             self.render_config.option
-        };
+        }
+    }
+
+    fn print_option_value<D: Display>(
+        &mut self,
+        option_relative_index: usize,
+        option: &ListOption<D>,
+        page: &Page<'_, ListOption<D>>,
+    ) -> Result<()> {
+        let stylesheet = self.option_style_sheet(option_relative_index, page);
 
         self.frame_renderer
             .write_styled(Styled::new(&option.value).with_style_sheet(stylesheet))
     }
 
+    fn print_option_value_with_access_key<D: Display>(
+        &mut self,
+        option_relative_index: usize,
+        value: &str,
+        page: &Page<'_, ListOption<D>>,
+    ) -> Result<()> {
+        let stylesheet = self.option_style_sheet(option_relative_index, page);
+
+        let mut chars = value.chars();
+        if let Some(access_key) = chars.next() {
+            let access_key_style = stylesheet.with_attr(stylesheet.att | Attributes::UNDERLINE);
+            self.frame_renderer
+                .write_styled(Styled::new(access_key).with_style_sheet(access_key_style))?;
+        }
+
+        self.frame_renderer
+            .write_styled(Styled::new(chars.as_str()).with_style_sheet(stylesheet))
+    }
+
     fn print_option_index_prefix(&mut self, index: usize, max_index: usize) -> Option<Result<()>> {
+        self.option_index_prefix(index, max_index).map(|prefix| {
+            self.frame_renderer
+                .write_styled(Styled::new(prefix).with_style_sheet(self.render_config.option))
+        })
+    }
+
+    fn option_index_prefix(&self, index: usize, max_index: usize) -> Option<String> {
         let index = index.saturating_add(1);
 
-        let content = match self.render_config.option_index_prefix {
+        match self.render_config.option_index_prefix {
             IndexPrefix::None => None,
             IndexPrefix::Simple => Some(format!("{index})")),
             IndexPrefix::SpacePadded => {
@@ -154,12 +427,7 @@ where
                 let width = int_log10(max_index.saturating_add(1));
                 Some(format!("{index:0width$})"))
             }
-        };
-
-        content.map(|prefix| {
-            self.frame_renderer
-                .write_styled(Styled::new(prefix).with_style_sheet(self.render_config.option))
-        })
+        }
     }
 
     fn print_default_value(&mut self, value: &str) -> Result<()> {
@@ -185,22 +453,73 @@ where
     }
 
     fn print_input(&mut self, input: &Input) -> Result<()> {
+        self.print_input_with_syntax(input, false, false)
+    }
+
+    /// Prints the input, styling the tokens of the filter syntax, such as
+    /// exclusion operators and quoted phrases, when `highlight_syntax` is set,
+    /// after the search indicator when `search_indicator` is set and the
+    /// input is not empty.
+    fn print_input_with_syntax(
+        &mut self,
+        input: &Input,
+        highlight_syntax: bool,
+        search_indicator: bool,
+    ) -> Result<()> {
         self.frame_renderer.write(" ")?;
 
+        if search_indicator && !input.is_empty() {
+            self.frame_renderer
+                .write_styled(self.render_config.search_indicator)?;
+        }
+
+        let scrolled = match (
+            self.render_config.input_scroll,
+            self.frame_renderer.terminal_width(),
+            self.frame_renderer.current_line_width(),
+        ) {
+            (true, Some(width), Some(used)) => {
+                let pre_cursor = input.pre_cursor();
+                let post_cursor = input.content().get(pre_cursor.len()..).unwrap_or_default();
+                let available = usize::from(width.saturating_sub(used));
+
+                scroll_input(pre_cursor, post_cursor, available)
+            }
+            _ => None,
+        };
+
         // The cursor is at the beginning of the input line.
         // From here it's easier to mark the wanted cursor position
         // (based on the underlying input struct), as it's a simple
         // cur_pos + offset calculation.
+        let cursor_offset = match &scrolled {
+            Some((_, cursor_offset)) => *cursor_offset,
+            None => input.pre_cursor().width(),
+        };
         self.frame_renderer
-            .mark_cursor_position(input.pre_cursor().width() as isize);
+            .mark_cursor_position(cursor_offset as isize);
 
-        if input.is_empty() {
+        if let Some((visible, _)) = scrolled {
+            self.frame_renderer.write_styled(
+                Styled::new(visible).with_style_sheet(self.render_config.text_input),
+            )?;
+        } else if input.is_empty() {
             match input.placeholder() {
                 Some("") | None => {}
                 Some(p) => self.frame_renderer.write_styled(
                     Styled::new(p).with_style_sheet(self.render_config.placeholder),
                 )?,
             }
+        } else if highlight_syntax {
+            for (token, is_syntax) in filter_syntax_tokens(input.content()) {
+                let style = match is_syntax {
+                    true => self.render_config.filter_syntax,
+                    false => self.render_config.text_input,
+                };
+
+                self.frame_renderer
+                    .write_styled(Styled::new(token).with_style_sheet(style))?;
+            }
         } else {
             self.frame_renderer.write_styled(
                 Styled::new(input.content()).with_style_sheet(self.render_config.text_input),
@@ -243,6 +562,152 @@ where
     }
 }
 
+/// Splits a filter input into consecutive tokens, flagged when they belong to
+/// the filter syntax: quoted phrases, quotes included, and `!` exclusion
+/// operators at the start of a word. Unterminated quotes extend to the end
+/// of the input.
+fn filter_syntax_tokens(content: &str) -> Vec<(&str, bool)> {
+    let mut tokens = vec![];
+    let mut token_start = 0;
+    let mut token_is_syntax = false;
+    let mut in_quote = false;
+    let mut prev_char: Option<char> = None;
+
+    for (idx, c) in content.char_indices() {
+        let at_word_start = match prev_char {
+            Some(prev) => prev.is_whitespace(),
+            None => true,
+        };
+        let is_syntax = match c {
+            '"' => {
+                in_quote = !in_quote;
+                true
+            }
+            '!' if !in_quote && at_word_start => true,
+            _ => in_quote,
+        };
+
+        if is_syntax != token_is_syntax && idx > token_start {
+            tokens.extend(content.get(token_start..idx).map(|t| (t, token_is_syntax)));
+            token_start = idx;
+        }
+
+        token_is_syntax = is_syntax;
+        prev_char = Some(c);
+    }
+
+    tokens.extend(
+        content
+            .get(token_start..)
+            .filter(|t| !t.is_empty())
+            .map(|t| (t, token_is_syntax)),
+    );
+
+    tokens
+}
+
+/// Text cut to the available width of the line, with an ellipsis replacing
+/// the clipped end. ANSI escape sequences are kept and take no width.
+///
+/// Returns `None` when the whole text fits.
+fn truncate_to_width(text: &str, available: usize) -> Option<String> {
+    let width: usize = text
+        .ansi_stripped_chars()
+        .filter_map(UnicodeWidthChar::width)
+        .sum();
+    if width <= available {
+        return None;
+    }
+
+    let mut visible = String::new();
+    let mut visible_width = 0;
+
+    for c in text.ansi_aware_chars() {
+        match c {
+            AnsiAwareChar::AnsiEscapeSequence(sequence) => visible.push_str(sequence),
+            AnsiAwareChar::Char(c) => {
+                let char_width = UnicodeWidthChar::width(c).unwrap_or(0);
+                if visible_width + char_width >= available {
+                    break;
+                }
+
+                visible.push(c);
+                visible_width += char_width;
+            }
+        }
+    }
+
+    if available > 0 {
+        visible.push(ELLIPSIS);
+    }
+    Some(visible)
+}
+
+/// Portion of an input too wide for the available width of the line,
+/// scrolled to keep the cursor visible, with an ellipsis on the clipped sides.
+///
+/// Returns the visible text along with the width displayed before the cursor,
+/// or `None` when the whole input fits. When the cursor is at the end of the
+/// input, a column is kept free for the space written after it.
+fn scroll_input(pre_cursor: &str, post_cursor: &str, available: usize) -> Option<(String, usize)> {
+    let pre_width = pre_cursor.width();
+    let mut post_chars = post_cursor.chars();
+    let cursor_char = post_chars.next();
+    let cursor_width = cursor_char.and_then(UnicodeWidthChar::width).unwrap_or(1);
+    let needed = pre_width + post_cursor.width() + usize::from(cursor_char.is_none());
+
+    // below 4 columns, there is hardly any room left besides the ellipses
+    if needed <= available || available < 4 {
+        return None;
+    }
+
+    if pre_width + cursor_width < available {
+        let mut visible = String::new();
+        let mut visible_width = 0;
+
+        for c in pre_cursor.chars().chain(post_cursor.chars()) {
+            let char_width = UnicodeWidthChar::width(c).unwrap_or(0);
+            if visible_width + char_width >= available {
+                break;
+            }
+
+            visible.push(c);
+            visible_width += char_width;
+        }
+
+        visible.push(ELLIPSIS);
+        return Some((visible, pre_width));
+    }
+
+    let mut right = cursor_char.map(String::from).unwrap_or_default();
+    let mut right_width = cursor_width;
+    if post_chars.next().is_some() {
+        right.push(ELLIPSIS);
+        right_width += 1;
+    }
+
+    let budget = available.saturating_sub(right_width + 1);
+    let mut tail = Vec::new();
+    let mut tail_width = 0;
+
+    for c in pre_cursor.chars().rev() {
+        let char_width = UnicodeWidthChar::width(c).unwrap_or(0);
+        if tail_width + char_width > budget {
+            break;
+        }
+
+        tail.push(c);
+        tail_width += char_width;
+    }
+
+    let visible = std::iter::once(ELLIPSIS)
+        .chain(tail.into_iter().rev())
+        .chain(right.chars())
+        .collect();
+
+    Some((visible, tail_width + 1))
+}
+
 impl<'a, I, T> CommonBackend for Backend<'a, I, T>
 where
     I: InputReader,
@@ -304,17 +769,42 @@ where
         Ok(())
     }
 
-    fn render_help_message(&mut self, help: &str) -> Result<()> {
-        self.frame_renderer
-            .write_styled(Styled::new("[").with_style_sheet(self.render_config.help_message))?;
+    fn render_validating_indicator(&mut self) -> Result<()> {
+        match self.render_config.validating_indicator {
+            Some(indicator) => {
+                self.frame_renderer.write_styled(indicator)?;
+                self.new_line()
+            }
+            None => Ok(()),
+        }
+    }
 
-        self.frame_renderer
-            .write_styled(Styled::new(help).with_style_sheet(self.render_config.help_message))?;
+    fn has_validating_indicator(&self) -> bool {
+        self.render_config.validating_indicator.is_some()
+    }
 
+    fn terminal_size(&self) -> Option<(u16, u16)> {
         self.frame_renderer
-            .write_styled(Styled::new("]").with_style_sheet(self.render_config.help_message))?;
+            .terminal_size()
+            .map(|size| (size.width(), size.height()))
+    }
 
-        self.new_line()?;
+    fn render_help_message(&mut self, help: &str) -> Result<()> {
+        let help = format!("[{help}]");
+
+        // long messages are wrapped between words, instead of wherever
+        // the terminal line ends.
+        let lines = match self.frame_renderer.terminal_width() {
+            Some(width) => wrap_words(&help, usize::from(width)),
+            None => vec![help],
+        };
+
+        for line in lines {
+            self.frame_renderer.write_styled(
+                Styled::new(line).with_style_sheet(self.render_config.help_message),
+            )?;
+            self.new_line()?;
+        }
 
         Ok(())
     }
@@ -374,32 +864,378 @@ where
     I: InputReader,
     T: Terminal,
 {
-    fn render_select_prompt(&mut self, prompt: &str, cur_input: Option<&Input>) -> Result<()> {
+    fn render_select_prompt(
+        &mut self,
+        prompt: &str,
+        cur_input: Option<&Input>,
+        highlight_syntax: bool,
+        search_indicator: bool,
+    ) -> Result<()> {
+        self.print_prompt(prompt)?;
+
         if let Some(input) = cur_input {
-            self.print_prompt_with_input(prompt, None, input)
-        } else {
-            self.print_prompt(prompt)
+            self.print_input_with_syntax(input, highlight_syntax, search_indicator)?;
         }
+
+        self.new_line()
     }
 
-    fn render_options<D: Display>(&mut self, page: Page<'_, ListOption<D>>) -> Result<()> {
-        for (idx, option) in page.content.iter().enumerate() {
+    fn render_filter_input(
+        &mut self,
+        label: &str,
+        cur_input: &Input,
+        highlight_syntax: bool,
+        search_indicator: bool,
+    ) -> Result<()> {
+        self.frame_renderer.write(label)?;
+        self.print_input_with_syntax(cur_input, highlight_syntax, search_indicator)?;
+        self.new_line()
+    }
+
+    fn render_horizontal_select_prompt<D: Display>(
+        &mut self,
+        prompt: &str,
+        option: Option<&ListOption<D>>,
+    ) -> Result<()> {
+        self.print_prompt(prompt)?;
+
+        if let Some(option) = option {
+            let stylesheet = self
+                .render_config
+                .selected_option
+                .unwrap_or(self.render_config.option);
+
+            self.frame_renderer.write(" < ")?;
+            self.frame_renderer
+                .write_styled(Styled::new(&option.value).with_style_sheet(stylesheet))?;
+            self.frame_renderer.write(" >")?;
+        }
+
+        self.new_line()
+    }
+
+    fn render_options<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        decorations: &OptionDecorations<'_>,
+    ) -> Result<()> {
+        let labels = page
+            .content
+            .iter()
+            .map(|option| option.value.to_string())
+            .collect::<Vec<_>>();
+        let display_width = |label: &str| -> usize {
+            label
+                .ansi_stripped_chars()
+                .filter_map(UnicodeWidthChar::width)
+                .sum()
+        };
+        // annotations are aligned after the first line of multi-line options
+        let label_width = |label: &String| -> usize {
+            match decorations.multiline {
+                true => display_width(label.lines().next().unwrap_or_default()),
+                false => display_width(label),
+            }
+        };
+        let label_column_width = labels.iter().map(label_width).max().unwrap_or_default();
+        let icon_column_width = decorations
+            .icons
+            .unwrap_or_default()
+            .iter()
+            .flatten()
+            .map(|icon| display_width(icon))
+            .max()
+            .unwrap_or_default();
+
+        let status_column_width = decorations
+            .statuses
+            .unwrap_or_default()
+            .iter()
+            .map(|status| {
+                display_width(self.render_config.option_status.indicator(*status).content)
+            })
+            .max()
+            .unwrap_or_default();
+
+        // rows rendered so far, which the scrollbar is drawn along
+        let mut row = 0;
+
+        for ((idx, option), label) in page.content.iter().enumerate().zip(&labels) {
+            if let Some(border) = decorations.border {
+                self.print_left_border(border)?;
+            }
+
+            let mut indent = self.option_prefix(idx, &page).content.width() + 1;
             self.print_option_prefix(idx, &page)?;
 
             self.frame_renderer.write(" ")?;
 
-            if let Some(res) = self.print_option_index_prefix(option.index, page.total) {
-                res?;
+            if let Some(prefix) = self.option_index_prefix(option.index, page.total) {
+                indent += prefix.width() + 1;
+                self.frame_renderer.write_styled(
+                    Styled::new(prefix).with_style_sheet(self.render_config.option),
+                )?;
                 self.frame_renderer.write(" ")?;
             }
 
-            self.print_option_value(idx, option, &page)?;
+            if let Some(statuses) = decorations.statuses {
+                let status = statuses.get(idx).copied().unwrap_or_default();
+                let indicator = self.render_config.option_status.indicator(status);
+                let padding = status_column_width.saturating_sub(display_width(indicator.content));
+
+                indent += status_column_width + 1;
+                self.frame_renderer.write_styled(indicator)?;
+                self.frame_renderer.write(" ".repeat(padding + 1))?;
+            }
+
+            if let Some(icons) = decorations.icons {
+                let icon = icons.get(idx).cloned().flatten().unwrap_or_default();
+                let padding = icon_column_width.saturating_sub(display_width(&icon));
+
+                indent += icon_column_width + 1;
+                self.frame_renderer.write(icon)?;
+                self.frame_renderer.write(" ".repeat(padding + 1))?;
+            }
+
+            let annotation = decorations
+                .annotations
+                .iter()
+                .find(|(annotation_label, _)| annotation_label == label);
+
+            // inside a border, the value is cut to keep what follows it on
+            // the line
+            let value_width = match decorations.border {
+                Some(_) => {
+                    let mut trailing = annotation
+                        .map(|(_, annotation)| annotation.width() + 2)
+                        .unwrap_or_default();
+                    if decorations.recommended == Some(option.index) {
+                        trailing += self
+                            .render_config
+                            .recommended_option_indicator
+                            .content
+                            .width()
+                            + 1;
+                    }
+                    if decorations
+                        .marked
+                        .is_some_and(|marked| marked.contains(&option.index))
+                    {
+                        trailing += self.render_config.marked_option_indicator.content.width() + 1;
+                    }
+
+                    self.width_inside_border(trailing, decorations.scrollbar.is_some())
+                }
+                None => None,
+            };
+
+            let mut following_lines = None;
+
+            if decorations.multiline && label.contains('\n') {
+                let stylesheet = self.option_style_sheet(idx, &page);
+                let mut lines = label.lines();
+
+                let first_line = lines.next().unwrap_or_default();
+                let first_line = value_width
+                    .and_then(|available| truncate_to_width(first_line, available))
+                    .unwrap_or_else(|| first_line.to_string());
+                self.frame_renderer
+                    .write_styled(Styled::new(first_line).with_style_sheet(stylesheet))?;
+                if decorations.prestyled {
+                    self.frame_renderer.write(ANSI_RESET)?;
+                }
+
+                following_lines = Some((lines, stylesheet));
+            } else {
+                let truncated =
+                    value_width.and_then(|available| truncate_to_width(label, available));
+
+                if decorations.underline_access_key && !decorations.prestyled {
+                    let value = truncated.as_deref().unwrap_or(label);
+                    self.print_option_value_with_access_key(idx, value, &page)?;
+                } else if let Some(value) = truncated {
+                    let stylesheet = self.option_style_sheet(idx, &page);
+                    self.frame_renderer
+                        .write_styled(Styled::new(value).with_style_sheet(stylesheet))?;
+                } else {
+                    self.print_option_value(idx, option, &page)?;
+                }
+
+                if decorations.prestyled {
+                    self.frame_renderer.write(ANSI_RESET)?;
+                }
+            }
+
+            if let Some((_, annotation)) = annotation {
+                let mut padding = label_column_width.saturating_sub(label_width(label));
+                if let Some(available) = value_width {
+                    padding = padding.min(available.saturating_sub(label_width(label)));
+                }
+                self.frame_renderer.write(" ".repeat(padding + 2))?;
+                self.frame_renderer.write_styled(
+                    Styled::new(*annotation).with_style_sheet(self.render_config.option_annotation),
+                )?;
+            }
+
+            if decorations.recommended == Some(option.index) {
+                self.frame_renderer.write(" ")?;
+                self.frame_renderer
+                    .write_styled(self.render_config.recommended_option_indicator)?;
+            }
+
+            if decorations
+                .marked
+                .is_some_and(|marked| marked.contains(&option.index))
+            {
+                self.frame_renderer.write(" ")?;
+                self.frame_renderer
+                    .write_styled(self.render_config.marked_option_indicator)?;
+            }
+
+            if let Some(thumb) = decorations.scrollbar {
+                self.print_scrollbar_cell(row, thumb, decorations.border.is_some())?;
+            }
+
+            if let Some(border) = decorations.border {
+                self.print_right_border(border)?;
+            }
 
             self.new_line()?;
+            row += 1;
+
+            if let Some((lines, stylesheet)) = following_lines {
+                for line in lines {
+                    if let Some(border) = decorations.border {
+                        self.print_left_border(border)?;
+                    }
+                    if !line.is_empty() {
+                        self.frame_renderer.write(" ".repeat(indent))?;
+                    }
+                    let line = match decorations.border {
+                        Some(_) => self
+                            .width_inside_border(0, decorations.scrollbar.is_some())
+                            .and_then(|available| truncate_to_width(line, available))
+                            .unwrap_or_else(|| line.to_string()),
+                        None => line.to_string(),
+                    };
+                    self.frame_renderer
+                        .write_styled(Styled::new(line).with_style_sheet(stylesheet))?;
+                    if decorations.prestyled {
+                        self.frame_renderer.write(ANSI_RESET)?;
+                    }
+                    if let Some(thumb) = decorations.scrollbar {
+                        self.print_scrollbar_cell(row, thumb, decorations.border.is_some())?;
+                    }
+                    if let Some(border) = decorations.border {
+                        self.print_right_border(border)?;
+                    }
+                    self.new_line()?;
+                    row += 1;
+                }
+            }
         }
 
         Ok(())
     }
+
+    fn render_extra_lines(&mut self, lines: &[String]) -> Result<()> {
+        for line in lines {
+            self.frame_renderer.write(line)?;
+            self.new_line()?;
+        }
+
+        Ok(())
+    }
+
+    fn render_title(&mut self, title: &str) -> Result<()> {
+        self.frame_renderer
+            .write_styled(Styled::new(title).with_style_sheet(self.render_config.title))?;
+
+        self.new_line()
+    }
+
+    fn render_progress(&mut self, current: usize, total: usize) -> Result<()> {
+        let completed = current.min(total);
+
+        for _ in 0..completed {
+            self.frame_renderer
+                .write_styled(self.render_config.progress_completed_indicator)?;
+        }
+        for _ in completed..total {
+            self.frame_renderer
+                .write_styled(self.render_config.progress_remaining_indicator)?;
+        }
+
+        self.frame_renderer.write(" ")?;
+        self.frame_renderer.write_styled(
+            Styled::new(format!("{completed}/{total}"))
+                .with_style_sheet(self.render_config.help_message),
+        )?;
+
+        self.new_line()
+    }
+
+    fn render_clipboard_copy(&mut self, copied: bool) -> Result<()> {
+        // The bell is part of the frame, so that it is only rung again when
+        // the rendered line changes.
+        let message = if copied {
+            "Copied to clipboard"
+        } else {
+            self.frame_renderer.write("\x07")?;
+            "No clipboard available"
+        };
+
+        self.frame_renderer
+            .write_styled(Styled::new(message).with_style_sheet(self.render_config.help_message))?;
+
+        self.new_line()
+    }
+
+    fn render_submit_confirmation(&mut self, message: &str) -> Result<()> {
+        self.frame_renderer
+            .write_styled(Styled::new(message).with_style_sheet(self.render_config.help_message))?;
+
+        self.new_line()
+    }
+
+    fn render_footer(&mut self, footer: &str) -> Result<()> {
+        self.frame_renderer
+            .write_styled(Styled::new(footer).with_style_sheet(self.render_config.footer))?;
+
+        self.new_line()
+    }
+
+    fn render_option_description(&mut self, description: &str) -> Result<()> {
+        self.frame_renderer.write_styled(
+            Styled::new(description).with_style_sheet(self.render_config.option_description),
+        )?;
+
+        self.new_line()
+    }
+
+    fn render_border_top(&mut self, style: BorderStyle, title: Option<&str>) -> Result<()> {
+        let [top_left, top_right, _, _, horizontal, _] = style.chars();
+
+        self.print_border_line((top_left, top_right), horizontal, title)
+    }
+
+    fn render_border_bottom(&mut self, style: BorderStyle) -> Result<()> {
+        let [_, _, bottom_left, bottom_right, horizontal, _] = style.chars();
+
+        self.print_border_line((bottom_left, bottom_right), horizontal, None)
+    }
+
+    fn render_separator(&mut self, separator: char) -> Result<()> {
+        if let Some(width) = self.frame_renderer.terminal_width() {
+            let char_width = UnicodeWidthChar::width(separator).unwrap_or(1).max(1);
+            let count = usize::from(width) / char_width;
+
+            self.frame_renderer
+                .write(separator.to_string().repeat(count))?;
+        }
+
+        self.new_line()
+    }
 }
 
 impl<'a, I, T> MultiSelectBackend for Backend<'a, I, T>
@@ -411,7 +1247,8 @@ where
         if let Some(input) = cur_input {
             self.print_prompt_with_input(prompt, None, input)
         } else {
-            self.print_prompt(prompt)
+            self.print_prompt(prompt)?;
+            self.new_line()
         }
     }
 
@@ -660,6 +1497,10 @@ where
     fn read_key(&mut self) -> InquireResult<Key> {
         self.input_reader.read_key()
     }
+
+    fn poll_key(&mut self, timeout: Duration) -> InquireResult<Option<Key>> {
+        self.input_reader.poll_key(timeout)
+    }
 }
 
 #[cfg(test)]
@@ -685,6 +1526,8 @@ pub(crate) mod test {
         AnsweredPrompt(String, String),
         ErrorMessage(ErrorMessage),
         HelpMessage(String),
+        ValidatingIndicator,
+        #[allow(unused)]
         Calendar {
             month: Month,
             year: i32,
@@ -717,6 +1560,8 @@ pub(crate) mod test {
         pub input: VecDeque<Key>,
         pub frames: Vec<Frame>,
         pub cur_frame: Option<Frame>,
+        pub buffered_input: bool,
+        pub validating_indicator: bool,
     }
 
     impl FakeBackend {
@@ -725,9 +1570,23 @@ pub(crate) mod test {
                 input: input.into(),
                 frames: vec![],
                 cur_frame: None,
+                buffered_input: false,
+                validating_indicator: false,
             }
         }
 
+        /// Renders the validating indicator before running validators.
+        pub fn with_validating_indicator(mut self) -> Self {
+            self.validating_indicator = true;
+            self
+        }
+
+        /// Reports all remaining keys as immediately available when polled.
+        pub fn with_buffered_input(mut self) -> Self {
+            self.buffered_input = true;
+            self
+        }
+
         fn push_token(&mut self, token: Token) {
             if let Some(frame) = self.cur_frame.as_mut() {
                 frame.content.push(token);
@@ -749,6 +1608,16 @@ pub(crate) mod test {
                     "No more keys in input",
                 )))
         }
+
+        fn poll_key(
+            &mut self,
+            _timeout: std::time::Duration,
+        ) -> crate::error::InquireResult<Option<Key>> {
+            match self.buffered_input {
+                true => Ok(self.input.pop_front()),
+                false => Ok(None),
+            }
+        }
     }
 
     impl CommonBackend for FakeBackend {
@@ -792,6 +1661,19 @@ pub(crate) mod test {
             self.push_token(Token::HelpMessage(help.to_string()));
             Ok(())
         }
+
+        fn render_validating_indicator(&mut self) -> std::io::Result<()> {
+            self.push_token(Token::ValidatingIndicator);
+            Ok(())
+        }
+
+        fn has_validating_indicator(&self) -> bool {
+            self.validating_indicator
+        }
+
+        fn terminal_size(&self) -> Option<(u16, u16)> {
+            None
+        }
     }
 
     #[cfg(feature = "date")]
@@ -839,4 +1721,58 @@ pub(crate) mod test {
             Ok(())
         }
     }
+
+    #[test]
+    fn input_fitting_the_line_is_not_scrolled() {
+        assert_eq!(None, super::scroll_input("hello", "", 6));
+        assert_eq!(None, super::scroll_input("he", "llo", 5));
+    }
+
+    #[test]
+    fn input_is_clipped_on_the_right_when_cursor_is_near_the_start() {
+        assert_eq!(
+            Some((String::from("abcde…"), 1)),
+            super::scroll_input("a", "bcdefghij", 6)
+        );
+    }
+
+    #[test]
+    fn input_is_clipped_on_the_left_when_cursor_is_at_the_end() {
+        assert_eq!(
+            Some((String::from("…ghij"), 5)),
+            super::scroll_input("abcdefghij", "", 6)
+        );
+    }
+
+    #[test]
+    fn input_is_clipped_on_both_sides_around_the_cursor() {
+        assert_eq!(
+            Some((String::from("…defg…"), 4)),
+            super::scroll_input("abcdef", "ghij", 6)
+        );
+    }
+
+    #[test]
+    fn filter_syntax_tokens_flag_exclusions_and_quotes() {
+        assert_eq!(
+            vec![
+                ("app ", false),
+                ("!", true),
+                ("pie ", false),
+                ("\"green tea\"", true),
+                (" a!b", false),
+            ],
+            super::filter_syntax_tokens("app !pie \"green tea\" a!b")
+        );
+    }
+
+    #[test]
+    fn filter_syntax_tokens_handle_incomplete_tokens() {
+        assert_eq!(vec![("!", true)], super::filter_syntax_tokens("!"));
+        assert_eq!(
+            vec![("a ", false), ("\"b c", true)],
+            super::filter_syntax_tokens("a \"b c")
+        );
+        assert!(super::filter_syntax_tokens("").is_empty());
+    }
 }
@@ -119,6 +119,21 @@ impl FrameState {
         self.finish_line();
     }
 
+    /// Content of the frame without any styling, one line per row.
+    #[cfg(test)]
+    pub fn plain_content(&self) -> String {
+        self.finished_rows
+            .iter()
+            .map(|row| {
+                row.get_content()
+                    .iter()
+                    .map(|styled| styled.content.as_str())
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
     pub fn resize_if_needed(&mut self, new_size: TerminalSize) {
         if new_size == self.terminal_size {
             return;
@@ -190,6 +205,12 @@ where
     terminal: T,
     cursor_position: Position,
     state: RenderState,
+    /// Whether every row of the next frame is written, even the ones that did
+    /// not change, as the terminal might have reflowed the last frame
+    /// differently after a resize.
+    full_redraw: bool,
+    #[cfg(test)]
+    rendered_frames: Vec<String>,
 }
 
 impl<T> FrameRenderer<T>
@@ -201,6 +222,9 @@ where
             terminal,
             cursor_position: Position::default(),
             state: RenderState::Initial,
+            full_redraw: false,
+            #[cfg(test)]
+            rendered_frames: Vec::new(),
         })
     }
 
@@ -236,6 +260,34 @@ where
         }
     }
 
+    /// Width taken by the text written so far on the current line of the
+    /// frame being rendered, if any.
+    pub fn current_line_width(&self) -> Option<u16> {
+        match &self.state {
+            RenderState::Rendered(_) | RenderState::Initial => None,
+            RenderState::ActiveRender { current_frame, .. } => {
+                Some(current_frame.current_line_width)
+            }
+        }
+    }
+
+    pub fn terminal_size(&self) -> Option<TerminalSize> {
+        match &self.state {
+            RenderState::Rendered(_) | RenderState::Initial => None,
+            RenderState::ActiveRender { current_frame, .. } => Some(current_frame.terminal_size),
+        }
+    }
+
+    /// Width of the terminal the current frame is being rendered to, if any.
+    pub fn terminal_width(&self) -> Option<u16> {
+        match &self.state {
+            RenderState::Rendered(_) | RenderState::Initial => None,
+            RenderState::ActiveRender { current_frame, .. } => {
+                Some(current_frame.terminal_size.width())
+            }
+        }
+    }
+
     pub fn start_frame(&mut self) -> io::Result<()> {
         let terminal_size = self.refresh_terminal_size();
 
@@ -280,6 +332,8 @@ where
             current_frame.frame_size.height(),
         );
 
+        let full_redraw = std::mem::take(&mut self.full_redraw);
+
         self.terminal.cursor_hide()?;
         self.move_cursor_to(Position { row: 0, col: 0 })?;
 
@@ -289,7 +343,7 @@ where
 
             match (last_row, current_row) {
                 (Some(last_row), Some(current_row)) => {
-                    if last_row.hash() != current_row.hash() {
+                    if full_redraw || last_row.hash() != current_row.hash() {
                         for styled in current_row.get_content() {
                             self.terminal.write_styled(styled)?;
                         }
@@ -333,11 +387,34 @@ where
         self.terminal.cursor_show()?;
         self.terminal.flush()?;
 
+        #[cfg(test)]
+        self.rendered_frames.push(current_frame.plain_content());
+
         self.state = RenderState::Rendered(current_frame);
 
         Ok(())
     }
 
+    /// Leaves the frames rendered so far on the terminal, moving the cursor
+    /// below them, so that the next frame is rendered from there as if it
+    /// were the first one, e.g. by the next prompt of a session.
+    pub fn detach(&mut self) -> io::Result<()> {
+        self.move_cursor_to_end_position()?;
+        self.terminal.flush()?;
+
+        self.state = RenderState::Initial;
+        self.cursor_position = Position::default();
+
+        Ok(())
+    }
+
+    /// Plain content of every frame rendered so far, useful to assert
+    /// on the layout of prompts.
+    #[cfg(test)]
+    pub fn rendered_frames(&self) -> &[String] {
+        &self.rendered_frames
+    }
+
     fn move_cursor_to_end_position(&mut self) -> io::Result<()> {
         self.refresh_terminal_size();
 
@@ -416,10 +493,12 @@ where
                 current_frame,
                 last_rendered_frame,
             } => {
+                self.full_redraw |= last_rendered_frame.terminal_size != terminal_size;
                 last_rendered_frame.resize_if_needed(terminal_size);
                 current_frame.resize_if_needed(terminal_size);
             }
             RenderState::Rendered(last_rendered_frame) => {
+                self.full_redraw |= last_rendered_frame.terminal_size != terminal_size;
                 last_rendered_frame.resize_if_needed(terminal_size);
             }
         };
@@ -443,7 +522,11 @@ where
 mod test {
     use crate::{
         error::InquireResult,
-        terminal::{test::MockTerminal, TerminalSize},
+        terminal::{
+            test::{MockTerminal, MockTerminalToken as Token},
+            TerminalSize,
+        },
+        ui::{Color, Styled},
     };
 
     use super::FrameRenderer;
@@ -469,4 +552,104 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn rendered_frames_are_recorded_without_styling() -> InquireResult<()> {
+        let terminal = MockTerminal::new().with_size(TerminalSize::new(200, 200));
+        let mut renderer = FrameRenderer::new(terminal)?;
+
+        renderer.start_frame()?;
+        renderer.write_styled(Styled::new("Hello").with_fg(Color::LightRed))?;
+        renderer.write(" World\n")?;
+        renderer.write("Second line")?;
+        renderer.finish_current_frame(false)?;
+
+        renderer.start_frame()?;
+        renderer.write("Done")?;
+        renderer.finish_current_frame(true)?;
+
+        assert_eq!(
+            &["Hello World\nSecond line".to_string(), "Done".to_string()],
+            renderer.rendered_frames()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn detached_frames_are_kept_above_the_next_one() -> InquireResult<()> {
+        let terminal = MockTerminal::new().with_size(TerminalSize::new(200, 200));
+        let mut renderer = FrameRenderer::new(terminal)?;
+
+        renderer.start_frame()?;
+        renderer.write("First question\nOption")?;
+        renderer.finish_current_frame(false)?;
+        renderer.detach()?;
+        renderer.terminal.output.clear();
+
+        renderer.start_frame()?;
+        renderer.write("Second question")?;
+        renderer.finish_current_frame(false)?;
+
+        let output = &renderer.terminal.output;
+        assert!(!output
+            .iter()
+            .any(|token| matches!(token, Token::CursorUp(_))));
+        assert!(!output.contains(&Token::ClearLine));
+        assert!(output.contains(&Token::Text(Styled::new("Second question".into()))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn unchanged_rows_are_not_rewritten() -> InquireResult<()> {
+        let terminal = MockTerminal::new().with_size(TerminalSize::new(200, 200));
+        let mut renderer = FrameRenderer::new(terminal)?;
+
+        renderer.start_frame()?;
+        renderer.write("Question\nFirst")?;
+        renderer.finish_current_frame(false)?;
+        renderer.terminal.output.clear();
+
+        renderer.start_frame()?;
+        renderer.write("Question\nSecond")?;
+        renderer.finish_current_frame(false)?;
+
+        let output = &renderer.terminal.output;
+        assert!(!output.contains(&Token::Text(Styled::new("Question".into()))));
+        assert!(output.contains(&Token::Text(Styled::new("Second".into()))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn every_row_is_rewritten_after_a_resize() -> InquireResult<()> {
+        let terminal = MockTerminal::new().with_size(TerminalSize::new(200, 200));
+        let mut renderer = FrameRenderer::new(terminal)?;
+
+        renderer.start_frame()?;
+        renderer.write("Question\nFirst")?;
+        renderer.finish_current_frame(false)?;
+        renderer.terminal.output.clear();
+        renderer.terminal.size = TerminalSize::new(100, 200);
+
+        renderer.start_frame()?;
+        renderer.write("Question\nFirst")?;
+        renderer.finish_current_frame(false)?;
+
+        let output = &renderer.terminal.output;
+        assert!(output.contains(&Token::Text(Styled::new("Question".into()))));
+        assert!(output.contains(&Token::Text(Styled::new("First".into()))));
+
+        renderer.terminal.output.clear();
+
+        renderer.start_frame()?;
+        renderer.write("Question\nFirst")?;
+        renderer.finish_current_frame(false)?;
+
+        let output = &renderer.terminal.output;
+        assert!(!output.contains(&Token::Text(Styled::new("Question".into()))));
+
+        Ok(())
+    }
 }
@@ -1,7 +1,27 @@
+use std::time::Duration;
+
 use crate::error::InquireResult;
 
 use super::Key;
 
 pub trait InputReader: Sized {
     fn read_key(&mut self) -> InquireResult<Key>;
+
+    /// Returns the next key if one becomes available before the timeout elapses.
+    ///
+    /// Readers unable to poll for input never report pending keys when given
+    /// a zero timeout, and block until the next key otherwise.
+    fn poll_key(&mut self, timeout: Duration) -> InquireResult<Option<Key>> {
+        if timeout.is_zero() {
+            Ok(None)
+        } else {
+            self.read_key().map(Some)
+        }
+    }
+
+    /// Sets how long to wait for the rest of an escape sequence after a lone
+    /// Esc, for readers that may receive sequences split across reads.
+    ///
+    /// Readers decoding whole sequences ignore this setting.
+    fn set_escape_timeout(&mut self, _escape_timeout: Duration) {}
 }
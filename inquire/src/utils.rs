@@ -2,6 +2,14 @@
 
 use std::fmt::Debug;
 
+use unicode_width::UnicodeWidthChar;
+
+use crate::{
+    ansi::{AnsiAware, AnsiAwareChar},
+    error::InquireResult,
+};
+
+/// Page of a paginated list of choices, as displayed to the user.
 pub struct Page<'a, T> {
     /// Whether this is the first page.
     pub first: bool,
@@ -68,6 +76,181 @@ pub fn paginate<T>(page_size: usize, choices: &[T], sel: Option<usize>) -> Page<
     }
 }
 
+/// Returns the index of the first element of a window of `page_size` elements
+/// that keeps the selection at least `margin` elements away from the window
+/// edges, moving the window as little as possible from `previous_start`.
+///
+/// The margin is capped so that it always fits in the window, and it naturally
+/// shrinks when the selection is close to either end of the list.
+pub fn scroll_window_start(
+    page_size: usize,
+    total: usize,
+    sel: usize,
+    previous_start: usize,
+    margin: usize,
+) -> usize {
+    if total <= page_size {
+        return 0;
+    }
+
+    let margin = margin.min(page_size.saturating_sub(1) / 2);
+
+    let lowest_start = sel
+        .saturating_add(margin)
+        .saturating_add(1)
+        .saturating_sub(page_size);
+    let highest_start = sel.saturating_sub(margin);
+
+    previous_start
+        .clamp(lowest_start, highest_start)
+        .min(total - page_size)
+}
+
+/// Same as [paginate], but the page starts at the provided index (clamped to
+/// the list bounds) instead of being centered on the selection.
+pub fn paginate_from<T>(
+    page_size: usize,
+    choices: &[T],
+    start: usize,
+    sel: Option<usize>,
+) -> Page<'_, T> {
+    let start = start.min(choices.len().saturating_sub(page_size));
+    let end = start.saturating_add(page_size).min(choices.len());
+
+    Page {
+        first: start == 0,
+        last: end == choices.len(),
+        content: choices.get(start..end).unwrap(),
+        cursor: sel.and_then(|index| index.checked_sub(start)),
+        total: choices.len(),
+    }
+}
+
+/// Same as [paginate], but the page holds as many choices as fit in the
+/// provided number of rows, each choice taking the rows returned by
+/// `height`, up to `page_size` choices. Choices are added below and above
+/// the selection in turn, so that it stays centered when possible. The
+/// selection is always on the page, even if it does not fit.
+pub fn paginate_rows<T>(
+    page_size: usize,
+    rows: usize,
+    choices: &[T],
+    sel: usize,
+    height: impl Fn(&T) -> usize,
+) -> Page<'_, T> {
+    let Some(selected) = choices.get(sel) else {
+        return paginate(page_size, choices, Some(sel));
+    };
+
+    let (mut start, mut end) = (sel, sel + 1);
+    let mut used_rows = height(selected);
+
+    loop {
+        let mut grown = false;
+
+        if let Some(next) = choices.get(end).filter(|_| end - start < page_size) {
+            if used_rows + height(next) <= rows {
+                used_rows += height(next);
+                end += 1;
+                grown = true;
+            }
+        }
+
+        let previous = start.checked_sub(1).and_then(|index| choices.get(index));
+        if let Some(previous) = previous.filter(|_| end - start < page_size) {
+            if used_rows + height(previous) <= rows {
+                used_rows += height(previous);
+                start -= 1;
+                grown = true;
+            }
+        }
+
+        if !grown {
+            break;
+        }
+    }
+
+    Page {
+        first: start == 0,
+        last: end == choices.len(),
+        content: choices.get(start..end).unwrap(),
+        cursor: Some(sel - start),
+        total: choices.len(),
+    }
+}
+
+/// Calls `operation` until it succeeds, returns an error that is not
+/// retryable, or `max_attempts` attempts were made. At least one attempt is
+/// always made.
+pub fn retry<O>(
+    max_attempts: usize,
+    mut operation: impl FnMut() -> InquireResult<O>,
+) -> InquireResult<O> {
+    let mut attempt = 1;
+
+    loop {
+        match operation() {
+            Err(err) if err.is_retryable() && attempt < max_attempts => attempt += 1,
+            result => return result,
+        }
+    }
+}
+
+/// Splits the text into lines no wider than `width` columns, breaking lines
+/// between words. Words wider than `width` are broken wherever needed. Line
+/// breaks already present in the text are kept, and ANSI escape sequences
+/// do not count towards the width of the lines.
+pub fn wrap_words(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let char_width = |piece: &AnsiAwareChar<'_>| match piece {
+        AnsiAwareChar::Char(c) => UnicodeWidthChar::width(*c).unwrap_or(0),
+        AnsiAwareChar::AnsiEscapeSequence(_) => 0,
+    };
+
+    let mut lines = vec![];
+
+    for paragraph in text.split('\n') {
+        let mut line = String::new();
+        let mut line_width = 0;
+
+        for word in paragraph.split_whitespace() {
+            let word_width = word
+                .ansi_aware_chars()
+                .map(|p| char_width(&p))
+                .sum::<usize>();
+
+            if line_width > 0 && line_width + 1 + word_width > width {
+                lines.push(std::mem::take(&mut line));
+                line_width = 0;
+            }
+
+            if line_width > 0 {
+                line.push(' ');
+                line_width += 1;
+            }
+
+            for piece in word.ansi_aware_chars() {
+                let piece_width = char_width(&piece);
+
+                if line_width > 0 && line_width + piece_width > width {
+                    lines.push(std::mem::take(&mut line));
+                    line_width = 0;
+                }
+
+                match piece {
+                    AnsiAwareChar::Char(c) => line.push(c),
+                    AnsiAwareChar::AnsiEscapeSequence(seq) => line.push_str(seq),
+                }
+                line_width += piece_width;
+            }
+        }
+
+        lines.push(line);
+    }
+
+    lines
+}
+
 pub fn int_log10<T>(mut i: T) -> usize
 where
     T: std::ops::DivAssign + PartialOrd + From<u8> + Copy,
@@ -98,11 +281,17 @@ impl<'a, T> Debug for Page<'a, T> {
 
 #[cfg(test)]
 mod test {
-    #![allow(clippy::bool_assert_comparison)]
+    #![allow(clippy::bool_assert_comparison, clippy::indexing_slicing)]
+
+    use std::io;
 
     use crate::{
+        error::InquireError,
         list_option::ListOption,
-        utils::{int_log10, paginate},
+        utils::{
+            int_log10, paginate, paginate_from, paginate_rows, retry, scroll_window_start,
+            wrap_words,
+        },
     };
 
     impl<T> ListOption<T> {
@@ -260,4 +449,189 @@ mod test {
         assert_eq!(false, page.last);
         assert_eq!(6, page.total);
     }
+
+    #[test]
+    fn scroll_window_keeps_margin_from_edges() {
+        let (page_size, total, margin) = (5usize, 20usize, 2usize);
+
+        assert_eq!(0, scroll_window_start(page_size, total, 2, 0, margin));
+        assert_eq!(1, scroll_window_start(page_size, total, 3, 0, margin));
+        assert_eq!(2, scroll_window_start(page_size, total, 4, 1, margin));
+        assert_eq!(1, scroll_window_start(page_size, total, 3, 1, margin));
+        assert_eq!(0, scroll_window_start(page_size, total, 2, 1, margin));
+    }
+
+    #[test]
+    fn scroll_window_margin_shrinks_at_list_ends() {
+        let (page_size, total, margin) = (5usize, 20usize, 2usize);
+
+        assert_eq!(0, scroll_window_start(page_size, total, 0, 3, margin));
+        assert_eq!(15, scroll_window_start(page_size, total, 18, 15, margin));
+        assert_eq!(15, scroll_window_start(page_size, total, 19, 0, margin));
+    }
+
+    #[test]
+    fn scroll_window_without_margin_scrolls_at_edges() {
+        let (page_size, total) = (5usize, 20usize);
+
+        assert_eq!(0, scroll_window_start(page_size, total, 4, 0, 0));
+        assert_eq!(1, scroll_window_start(page_size, total, 5, 0, 0));
+        assert_eq!(0, scroll_window_start(page_size, total, 0, 1, 0));
+    }
+
+    #[test]
+    fn scroll_window_margin_is_capped_by_page_size() {
+        assert_eq!(3, scroll_window_start(5, 20, 5, 0, 100));
+        assert_eq!(0, scroll_window_start(5, 3, 2, 0, 100));
+    }
+
+    #[test]
+    fn paginate_from_start_index() {
+        let choices = ListOption::from_list(vec!["1", "2", "3", "4", "5", "6"]);
+
+        let page = paginate_from(3, &choices, 2, Some(3));
+
+        assert_eq!(choices[2..5], page.content[..]);
+        assert_eq!(Some(1), page.cursor);
+        assert_eq!(false, page.first);
+        assert_eq!(false, page.last);
+        assert_eq!(6, page.total);
+    }
+
+    #[test]
+    fn paginate_from_clamps_start_index() {
+        let choices = ListOption::from_list(vec!["1", "2", "3", "4", "5", "6"]);
+
+        let page = paginate_from(3, &choices, 5, Some(5));
+
+        assert_eq!(choices[3..6], page.content[..]);
+        assert_eq!(Some(2), page.cursor);
+        assert_eq!(true, page.last);
+    }
+
+    #[test]
+    fn paginate_rows_fills_the_rows_around_the_selection() {
+        let heights = [1, 3, 1, 2, 1, 1];
+        let choices = ListOption::from_list(vec!["1", "2", "3", "4", "5", "6"]);
+        let height = |choice: &ListOption<&str>| heights[choice.index];
+
+        let page = paginate_rows(5, 4, &choices, 1, height);
+
+        assert_eq!(choices[1..3], page.content[..]);
+        assert_eq!(Some(0), page.cursor);
+        assert_eq!(false, page.first);
+
+        let page = paginate_rows(5, 4, &choices, 4, height);
+
+        assert_eq!(choices[3..6], page.content[..]);
+        assert_eq!(Some(1), page.cursor);
+        assert_eq!(true, page.last);
+
+        let page = paginate_rows(2, 10, &choices, 4, height);
+
+        assert_eq!(choices[4..6], page.content[..]);
+    }
+
+    #[test]
+    fn paginate_rows_keeps_a_selection_taller_than_the_rows() {
+        let choices = ListOption::from_list(vec!["1", "2"]);
+
+        let page = paginate_rows(5, 2, &choices, 0, |_| 3);
+
+        assert_eq!(choices[0..1], page.content[..]);
+        assert_eq!(Some(0), page.cursor);
+    }
+
+    #[test]
+    fn retry_stops_on_success() {
+        let mut attempts = 0;
+
+        let result = retry(5, || {
+            attempts += 1;
+            match attempts {
+                3 => Ok(attempts),
+                _ => Err(InquireError::Custom("failed".into())),
+            }
+        });
+
+        assert_eq!(3, result.unwrap());
+        assert_eq!(3, attempts);
+    }
+
+    #[test]
+    fn retry_gives_up_after_max_attempts() {
+        let mut attempts = 0;
+
+        let result: Result<(), _> = retry(2, || {
+            attempts += 1;
+            Err(io::Error::from(io::ErrorKind::Interrupted).into())
+        });
+
+        assert!(matches!(result, Err(InquireError::IO(_))));
+        assert_eq!(2, attempts);
+    }
+
+    #[test]
+    fn retry_does_not_retry_cancellations() {
+        let mut attempts = 0;
+
+        let result: Result<(), _> = retry(5, || {
+            attempts += 1;
+            Err(InquireError::OperationCanceled)
+        });
+
+        assert!(matches!(result, Err(InquireError::OperationCanceled)));
+        assert_eq!(1, attempts);
+    }
+
+    #[test]
+    fn retry_attempts_at_least_once() {
+        let mut attempts = 0;
+
+        let result = retry(0, || {
+            attempts += 1;
+            Ok(())
+        });
+
+        assert!(result.is_ok());
+        assert_eq!(1, attempts);
+    }
+
+    #[test]
+    fn wrap_words_breaks_between_words() {
+        assert_eq!(
+            vec!["[↑↓ to move,", "enter to", "select]"],
+            wrap_words("[↑↓ to move, enter to select]", 12)
+        );
+    }
+
+    #[test]
+    fn wrap_words_keeps_short_text_in_one_line() {
+        assert_eq!(vec!["short help"], wrap_words("short help", 80));
+        assert_eq!(vec!["exact"], wrap_words("exact", 5));
+    }
+
+    #[test]
+    fn wrap_words_hard_breaks_long_words() {
+        assert_eq!(
+            vec!["see", "abcde", "fghij", "k"],
+            wrap_words("see abcdefghijk", 5)
+        );
+    }
+
+    #[test]
+    fn wrap_words_keeps_line_breaks() {
+        assert_eq!(
+            vec!["first", "second line"],
+            wrap_words("first\nsecond line", 20)
+        );
+    }
+
+    #[test]
+    fn wrap_words_ignores_ansi_escape_sequences() {
+        assert_eq!(
+            vec!["\x1b[1mbold\x1b[0m text"],
+            wrap_words("\x1b[1mbold\x1b[0m text", 9)
+        );
+    }
 }